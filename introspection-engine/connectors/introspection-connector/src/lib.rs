@@ -12,7 +12,11 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_metadata(&self) -> ConnectorResult<DatabaseMetadata>;
 
-    async fn introspect(&self) -> ConnectorResult<Datamodel>;
+    async fn introspect(&self) -> ConnectorResult<IntrospectionResult>;
+
+    /// Introspect the database and merge the result into an existing datamodel, keeping the
+    /// manual changes the user made to it.
+    async fn re_introspect(&self, existing: &Datamodel) -> ConnectorResult<ReIntrospectionResult>;
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -20,3 +24,57 @@ pub struct DatabaseMetadata {
     pub table_count: usize,
     pub size_in_bytes: usize,
 }
+
+#[derive(Debug)]
+pub struct IntrospectionResult {
+    pub datamodel: Datamodel,
+    pub warnings: Vec<Warning>,
+}
+
+#[derive(Debug)]
+pub struct ReIntrospectionResult {
+    pub datamodel: Datamodel,
+    pub changes: IntrospectionChanges,
+    pub warnings: Vec<Warning>,
+}
+
+/// Something in the database introspection could not translate faithfully.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Warning {
+    pub code: u32,
+    pub message: String,
+    pub affected: Vec<AffectedObject>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AffectedObject {
+    pub table: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+}
+
+/// What changed in the datamodel compared to the previous introspection.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionChanges {
+    pub added_models: Vec<String>,
+    pub removed_models: Vec<String>,
+    pub added_fields: Vec<ModelAndField>,
+    pub removed_fields: Vec<ModelAndField>,
+    pub added_enums: Vec<String>,
+    pub removed_enums: Vec<String>,
+    pub added_enum_values: Vec<EnumAndValue>,
+    pub removed_enum_values: Vec<EnumAndValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ModelAndField {
+    pub model: String,
+    pub field: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EnumAndValue {
+    pub r#enum: String,
+    pub value: String,
+}
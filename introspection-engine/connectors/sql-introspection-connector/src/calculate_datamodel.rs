@@ -1,19 +1,20 @@
+use crate::sanitize_datamodel_names::sanitize_datamodel_names;
 use crate::SqlIntrospectionResult;
 use datamodel::{
-    common::{names::NameNormalizer, ScalarType, ScalarValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, OnDeleteStrategy,
-    RelationInfo, WithDatabaseName,
+    common::{functions::DB_GENERATED, names::NameNormalizer, ScalarType, ScalarValue},
+    dml, CheckDefinition, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model,
+    OnDeleteStrategy, RelationInfo, WithDatabaseName,
 };
 use log::debug;
 use prisma_inflector;
 use regex::Regex;
 use sql_schema_describer::*;
 
-fn is_migration_table(table: &Table) -> bool {
+pub(crate) fn is_migration_table(table: &Table) -> bool {
     table.name == "_Migration"
 }
 
-fn is_prisma_join_table(table: &Table) -> bool {
+pub(crate) fn is_prisma_join_table(table: &Table) -> bool {
     table.columns.len() == 2
         && table.foreign_keys.len() == 2
         && table.foreign_keys[0].referenced_table < table.foreign_keys[1].referenced_table
@@ -33,17 +34,48 @@ fn is_prisma_join_table(table: &Table) -> bool {
         && table.indices[0].tpe == IndexType::Unique
 }
 
-fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is_self_relation: bool) -> Field {
+/// A join table with a custom name: two columns that are both foreign keys and together form the primary key.
+pub(crate) fn is_custom_join_table(table: &Table) -> bool {
+    let is_foreign_key_column = |column: &Column| {
+        table
+            .foreign_keys
+            .iter()
+            .any(|fk| fk.columns.len() == 1 && fk.columns[0] == column.name)
+    };
+
+    table.columns.len() == 2
+        && table.foreign_keys.len() == 2
+        && table.columns.iter().all(is_foreign_key_column)
+        && table.primary_key.as_ref().map(|pk| pk.columns.len()) == Some(2)
+}
+
+pub(crate) fn is_join_table(table: &Table) -> bool {
+    is_prisma_join_table(table) || is_custom_join_table(table)
+}
+
+/// Creates the many-to-many relation field pointing to the model referenced by `foreign_key`.
+/// For custom join tables, `join_column` is the column of the join table referencing the model holding the field.
+fn create_many_to_many_field(
+    schema: &SqlSchema,
+    foreign_key: &ForeignKey,
+    relation_name: String,
+    is_self_relation: bool,
+    join_table: Option<(&str, &str)>,
+) -> Field {
     let inflector = prisma_inflector::default();
+    let referenced_model = referenced_model_name(schema, foreign_key);
 
     let field_type = FieldType::Relation(RelationInfo {
         name: relation_name,
-        to: foreign_key.referenced_table.clone(),
+        to: referenced_model.clone(),
+        fields: Vec::new(),
         to_fields: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        table: join_table.map(|(table, _)| table.to_string()),
+        column: join_table.map(|(_, column)| column.to_string()),
     });
 
-    let basename = inflector.pluralize(&foreign_key.referenced_table).camel_case();
+    let basename = inflector.pluralize(&referenced_model).camel_case();
 
     let name = match is_self_relation {
         true => format!("{}_{}", basename, foreign_key.columns[0]),
@@ -66,6 +98,15 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
 
 /// Calculate a data model from a database schema.
 pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel> {
+    calculate_model_with_naming_conventions(schema, false)
+}
+
+/// Calculate a data model from a database schema. With `naming_conventions`, all models and enums
+/// are named in PascalCase and all fields in camelCase, mapped to their names in the database.
+pub fn calculate_model_with_naming_conventions(
+    schema: &SqlSchema,
+    naming_conventions: bool,
+) -> SqlIntrospectionResult<Datamodel> {
     debug!("Calculating data model");
 
     let mut data_model = Datamodel::new();
@@ -73,10 +114,10 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         .tables
         .iter()
         .filter(|table| !is_migration_table(&table))
-        .filter(|table| !is_prisma_join_table(&table))
+        .filter(|table| !is_join_table(table))
     {
-        let mut model = Model::new(&table.name);
-        //Todo: This needs to filter out composite Foreign Key columns, they are merged into one new field
+        let mut model = Model::new(&model_name(schema, table.namespace.as_deref(), &table.name));
+        model.documentation = table.comment.clone();
         for column in table.columns.iter() {
             debug!("Handling column {:?}", column);
             let field_type = calculate_field_type(&schema, &column, &table);
@@ -89,10 +130,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             let default_value = match field_type {
                 FieldType::Relation(_) => None,
                 _ if arity == FieldArity::List => None,
-                _ => column
-                    .default
-                    .as_ref()
-                    .and_then(|default| calculate_default(default, &column.tpe.family)),
+                _ => calculate_default(column, &field_type),
             };
 
             let is_unique = match field_type {
@@ -101,7 +139,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                     if id_info.is_some() {
                         false
                     } else {
-                        table.is_column_unique(&column.name)
+                        is_plain_unique_column(table, &column.name)
                     }
                 }
             };
@@ -114,7 +152,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 default_value,
                 is_unique,
                 id_info,
-                documentation: None,
+                documentation: column.comment.clone(),
                 is_generated: false,
                 is_updated_at: false,
             };
@@ -122,44 +160,67 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             model.add_field(field);
         }
 
-        for index in table.indices.iter() {
-            if index.columns.len() > 1 {
-                let tpe = if index.tpe == IndexType::Unique {
-                    datamodel::dml::IndexType::Unique
-                } else {
-                    datamodel::dml::IndexType::Normal
-                };
+        for foreign_key in table
+            .foreign_keys
+            .iter()
+            .filter(|fk| fk.columns.len() > 1 && !references_excluded_table(schema, fk))
+        {
+            let field = calculate_composite_relation_field(schema, table, foreign_key, &model);
+            model.add_field(field);
+        }
 
-                let index_definition: IndexDefinition = IndexDefinition {
-                    name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
-                    tpe,
-                };
-                model.add_index(index_definition)
-            }
-            if index.columns.len() == 1 && index.tpe != IndexType::Unique {
-                let index_definition: IndexDefinition = IndexDefinition {
-                    name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
-                    tpe: datamodel::dml::IndexType::Normal,
-                };
-                model.add_index(index_definition)
+        for index in table.indices.iter() {
+            // Plain unique indexes on a single column become `@unique` fields.
+            if index.tpe == IndexType::Unique && index.columns.len() == 1 && index.is_plain() {
+                continue;
             }
+
+            let tpe = if index.tpe == IndexType::Unique {
+                datamodel::dml::IndexType::Unique
+            } else {
+                datamodel::dml::IndexType::Normal
+            };
+
+            let index_definition: IndexDefinition = IndexDefinition {
+                name: Some(index.name.clone()),
+                fields: index.columns.clone(),
+                tpe,
+                sort_orders: index.sort_orders.iter().map(calculate_sort_order).collect(),
+                expression: index.expression.clone(),
+                predicate: index.predicate.clone(),
+                algorithm: index.algorithm.as_ref().map(calculate_index_algorithm),
+            };
+            model.add_index(index_definition)
         }
 
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
         }
 
+        for check in table.checks.iter() {
+            model.checks.push(CheckDefinition {
+                name: check.name.clone(),
+                expression: check.expression.clone(),
+            });
+        }
+
+        model.schema = table.namespace.clone();
+
+        if model.name != table.name {
+            model.database_name = Some(table.name.clone());
+        }
+
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        data_model.add_model(calculate_view_model(schema, view));
+    }
+
     for e in schema.enums.iter() {
-        let mut values: Vec<String> = e.values.iter().cloned().collect();
-        values.sort_unstable();
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
-            values,
+            values: e.values.clone(),
             database_name: None,
             documentation: None,
         });
@@ -183,8 +244,21 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                     {
                         let other_model = data_model.find_model(&relation_info.to).unwrap();
 
-                        let table = schema.table_bang(model.name.as_str());
-                        let fk = table.foreign_key_for_column(relation_field.name.as_str());
+                        let table = schema
+                            .get_table_in_namespace(model.schema.as_deref(), model_db_name(model))
+                            .expect("Relation fields are only calculated for the models of tables.");
+                        let fk = if relation_info.fields.is_empty() {
+                            table.foreign_key_for_column(relation_field.name.as_str())
+                        } else {
+                            table.foreign_keys.iter().find(|fk| fk.columns == relation_info.fields)
+                        };
+                        let is_unique = if relation_info.fields.is_empty() {
+                            table.is_column_unique(
+                                &relation_field.database_name().as_ref().unwrap_or(&relation_field.name),
+                            )
+                        } else {
+                            are_columns_unique(table, &relation_info.fields)
+                        };
                         let on_delete = match fk {
                             None => OnDeleteStrategy::None,
                             Some(fk) => match fk.on_delete_action {
@@ -196,18 +270,15 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                         let field_type = FieldType::Relation(RelationInfo {
                             name: relation_info.name.clone(),
                             to: model.name.clone(),
+                            fields: Vec::new(),
                             to_fields: vec![relation_field.name.clone()],
                             on_delete,
+                            table: None,
+                            column: None,
                         });
 
                         let arity = match relation_field.arity {
-                            FieldArity::Required | FieldArity::Optional
-                                if schema.table_bang(&model.name).is_column_unique(
-                                    &relation_field.database_name().as_ref().unwrap_or(&relation_field.name),
-                                ) =>
-                            {
-                                FieldArity::Optional
-                            }
+                            FieldArity::Required | FieldArity::Optional if is_unique => FieldArity::Optional,
                             FieldArity::Required | FieldArity::Optional => FieldArity::List,
                             FieldArity::List => FieldArity::Optional,
                         };
@@ -242,21 +313,32 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     }
 
     // add prisma many to many relation fields
-    for table in schema.tables.iter().filter(|table| is_prisma_join_table(&table)) {
+    for table in schema.tables.iter().filter(|table| is_join_table(table)) {
         let first = table.foreign_keys.get(0);
         let second = table.foreign_keys.get(1);
 
         match (first, second) {
-            (Some(f), Some(s)) => {
-                let is_self_relation = f.referenced_table == s.referenced_table;
+            (Some(f), Some(s)) if !references_excluded_table(schema, f) && !references_excluded_table(schema, s) => {
+                let is_self_relation =
+                    f.referenced_table == s.referenced_table && f.referenced_schema == s.referenced_schema;
+                let is_custom = !is_prisma_join_table(table);
+                let relation_name = if is_custom {
+                    table.name.clone()
+                } else {
+                    table.name[1..].to_string()
+                };
+                let join_table = |holder: &ForeignKey| match is_custom {
+                    true => Some((table.name.as_str(), holder.columns[0].as_str())),
+                    false => None,
+                };
 
                 fields_to_be_added.push((
-                    s.referenced_table.clone(),
-                    create_many_to_many_field(f, table.name[1..].to_string(), is_self_relation),
+                    referenced_model_name(schema, s),
+                    create_many_to_many_field(schema, f, relation_name.clone(), is_self_relation, join_table(s)),
                 ));
                 fields_to_be_added.push((
-                    f.referenced_table.clone(),
-                    create_many_to_many_field(s, table.name[1..].to_string(), is_self_relation),
+                    referenced_model_name(schema, f),
+                    create_many_to_many_field(schema, s, relation_name, is_self_relation, join_table(f)),
                 ));
             }
             (_, _) => (),
@@ -296,9 +378,56 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         model.add_field(field);
     }
 
+    sanitize_datamodel_names(&mut data_model, schema, naming_conventions);
+
     Ok(data_model)
 }
 
+/// Views are introspected into read-only models. They have no primary key, so a column named
+/// `id` is used as the id field when present. Views without one can not be queried.
+fn calculate_view_model(schema: &SqlSchema, view: &View) -> Model {
+    let mut model = Model::new(&model_name(schema, view.namespace.as_deref(), &view.name));
+    model.is_view = true;
+    model.view_definition = view.definition.clone();
+    model.schema = view.namespace.clone();
+
+    if model.name != view.name {
+        model.database_name = Some(view.name.clone());
+    }
+
+    for column in view.columns.iter() {
+        let arity = match column.tpe.arity {
+            ColumnArity::Required => FieldArity::Required,
+            ColumnArity::Nullable => FieldArity::Optional,
+            ColumnArity::List => FieldArity::List,
+        };
+        let id_info = if column.name.to_lowercase() == "id" {
+            Some(IdInfo {
+                strategy: IdStrategy::None,
+                sequence: None,
+            })
+        } else {
+            None
+        };
+
+        model.add_field(Field {
+            name: column.name.clone(),
+            // A view column can only be used as an id if it is always present.
+            arity: if id_info.is_some() { FieldArity::Required } else { arity },
+            field_type: calculate_scalar_field_type(column),
+            database_name: None,
+            default_value: None,
+            is_unique: false,
+            id_info,
+            documentation: column.comment.clone(),
+            is_generated: false,
+            is_updated_at: false,
+        });
+    }
+
+    model
+}
+
 fn parse_int(value: &str) -> Option<i32> {
     debug!("Parsing int '{}'", value);
     let re_num = Regex::new(r"^'?(\d+)'?$").expect("compile regex");
@@ -346,17 +475,70 @@ fn parse_float(value: &str) -> Option<f32> {
     }
 }
 
-fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<ScalarValue> {
-    match tpe {
+/// Calculates the default value of a field from its column.
+///
+/// Auto incrementing columns and sequence defaults become `autoincrement()`, the current timestamp becomes `now()`.
+/// Defaults that can't be parsed into a value are kept as raw `dbgenerated(...)` expressions.
+fn calculate_default(column: &Column, field_type: &FieldType) -> Option<ScalarValue> {
+    let scalar_type = match field_type {
+        FieldType::Enum(_) => {
+            return column.default.as_ref().map(|default| {
+                ScalarValue::ConstantLiteral(default.trim_start_matches('\'').trim_end_matches('\'').to_string())
+            })
+        }
+        FieldType::Base(scalar_type) => *scalar_type,
+        _ => return None,
+    };
+
+    let is_auto_increment = column.auto_increment || column.default.as_deref().map(is_sequence).unwrap_or(false);
+    if scalar_type == ScalarType::Int && is_auto_increment {
+        return Some(function("autoincrement", scalar_type, vec![]));
+    }
+
+    let default = column.default.as_ref()?;
+
+    let value = match &column.tpe.family {
         ColumnTypeFamily::Boolean => match parse_int(default) {
             Some(x) => Some(ScalarValue::Boolean(x != 0)),
             None => parse_bool(default).map(|b| ScalarValue::Boolean(b)),
         },
         ColumnTypeFamily::Int => parse_int(default).map(|x| ScalarValue::Int(x)),
         ColumnTypeFamily::Float => parse_float(default).map(|x| ScalarValue::Float(x)),
-        ColumnTypeFamily::String => Some(ScalarValue::String(default.to_string())),
+        ColumnTypeFamily::DateTime if is_now(default) => Some(function("now", scalar_type, vec![])),
+        // The describers strip the quotes from string literals, function calls are the only thing we can recognize.
+        ColumnTypeFamily::String | ColumnTypeFamily::Uuid if !is_function_call(default) => {
+            Some(ScalarValue::String(default.to_string()))
+        }
         _ => None,
-    }
+    };
+
+    value.or_else(|| {
+        Some(function(
+            DB_GENERATED,
+            scalar_type,
+            vec![ScalarValue::String(default.to_string())],
+        ))
+    })
+}
+
+fn function(name: &str, return_type: ScalarType, args: Vec<ScalarValue>) -> ScalarValue {
+    ScalarValue::Expression(name.to_string(), return_type, args)
+}
+
+fn is_sequence(default: &str) -> bool {
+    default.starts_with("nextval(")
+}
+
+fn is_now(default: &str) -> bool {
+    let default = default.to_lowercase();
+
+    default == "now()" || default == "current_timestamp" || default.starts_with("current_timestamp(")
+}
+
+fn is_function_call(default: &str) -> bool {
+    let re = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*\(.*\)$").expect("compile regex");
+
+    re.is_match(default)
 }
 
 fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
@@ -382,25 +564,30 @@ fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
 
 fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -> String {
     //this is not called for prisma many to many relations. for them the name is just the name of the join table.
-    let referenced_model = &fk.referenced_table;
-    let model_with_fk = &table.name;
+    let referenced_model = &referenced_model_name(schema, fk);
+    let model_with_fk = &model_name(schema, table.namespace.as_deref(), &table.name);
     let fk_column_name = fk.columns.get(0).unwrap();
 
     let fk_to_same_model: Vec<&ForeignKey> = table
         .foreign_keys
         .iter()
-        .filter(|fk| fk.referenced_table == referenced_model.clone())
+        .filter(|other| {
+            other.referenced_table == fk.referenced_table && other.referenced_schema == fk.referenced_schema
+        })
         .collect();
 
-    let fk_from_other_model_to_this: Vec<&ForeignKey> = schema
-        .table_bang(referenced_model)
-        .foreign_keys
-        .iter()
-        .filter(|fk| fk.referenced_table == model_with_fk.clone())
-        .collect();
+    let fk_from_other_model_to_this: Vec<&ForeignKey> = referenced_table(schema, fk)
+        .map(|referenced_table| {
+            referenced_table
+                .foreign_keys
+                .iter()
+                .filter(|other| other.referenced_table == table.name && other.referenced_schema == table.namespace)
+                .collect()
+        })
+        .unwrap_or_default();
 
     //unambiguous
-    if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.len() == 0 {
+    if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.is_empty() {
         if model_with_fk < referenced_model {
             format!("{}To{}", model_with_fk, referenced_model)
         } else {
@@ -418,8 +605,14 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
 
 fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
-    // Look for a foreign key referencing this column
-    match table.foreign_keys.iter().find(|fk| fk.columns.contains(&column.name)) {
+    // Look for a foreign key referencing this column. Columns of foreign keys over several columns
+    // stay scalar fields, the relation field for them is added separately.
+    match table
+        .foreign_keys
+        .iter()
+        .find(|fk| fk.columns.len() == 1 && fk.columns.contains(&column.name))
+        .filter(|fk| !references_excluded_table(schema, fk))
+    {
         Some(fk) if calc_id_info(column, table).is_none() => {
             debug!("Found corresponding foreign key");
             let idx = fk
@@ -431,23 +624,171 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
 
             FieldType::Relation(RelationInfo {
                 name: calculate_relation_name(schema, fk, table),
-                to: fk.referenced_table.clone(),
+                to: referenced_model_name(schema, fk),
+                fields: Vec::new(),
                 to_fields: vec![referenced_col.clone()],
                 on_delete: OnDeleteStrategy::None,
+                table: None,
+                column: None,
             })
         }
         _ => {
             debug!("Found no corresponding foreign key");
-            match column.tpe.family {
-                ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean),
-                ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
-                ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
-                ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
-                ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
-                // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
-                // variants that don't yet have corresponding PrismaType variants
-                _ => FieldType::Base(ScalarType::String),
-            }
+            calculate_scalar_field_type(column)
         }
     }
 }
+
+/// Whether the column has a unique index that can be expressed as `@unique` on its field.
+fn is_plain_unique_column(table: &Table, column_name: &str) -> bool {
+    table
+        .indices
+        .iter()
+        .any(|index| index.tpe == IndexType::Unique && index.columns == [column_name] && index.is_plain())
+}
+
+fn calculate_sort_order(sort_order: &SortOrder) -> dml::SortOrder {
+    match sort_order {
+        SortOrder::Asc => dml::SortOrder::Asc,
+        SortOrder::Desc => dml::SortOrder::Desc,
+    }
+}
+
+fn calculate_index_algorithm(algorithm: &IndexAlgorithm) -> dml::IndexAlgorithm {
+    match algorithm {
+        IndexAlgorithm::BTree => dml::IndexAlgorithm::BTree,
+        IndexAlgorithm::Hash => dml::IndexAlgorithm::Hash,
+        IndexAlgorithm::Gist => dml::IndexAlgorithm::Gist,
+        IndexAlgorithm::Gin => dml::IndexAlgorithm::Gin,
+        IndexAlgorithm::SpGist => dml::IndexAlgorithm::SpGist,
+        IndexAlgorithm::Brin => dml::IndexAlgorithm::Brin,
+    }
+}
+
+/// Whether the table referenced by the foreign key was filtered out of the introspected schema.
+/// The columns of such foreign keys stay scalar fields.
+pub(crate) fn references_excluded_table(schema: &SqlSchema, foreign_key: &ForeignKey) -> bool {
+    referenced_table(schema, foreign_key).is_none()
+}
+
+/// The table referenced by the foreign key, looked up in the schema it lives in.
+fn referenced_table<'a>(schema: &'a SqlSchema, foreign_key: &ForeignKey) -> Option<&'a Table> {
+    schema.get_table_in_namespace(foreign_key.referenced_schema.as_deref(), &foreign_key.referenced_table)
+}
+
+/// The name of the model for a table or view. Tables and views of other schemas get the name of
+/// their schema as prefix when their name is taken in another schema, as model names must be unique.
+fn model_name(schema: &SqlSchema, namespace: Option<&str>, name: &str) -> String {
+    let is_taken_elsewhere = schema
+        .tables
+        .iter()
+        .map(|table| (table.namespace.as_deref(), &table.name))
+        .chain(schema.views.iter().map(|view| (view.namespace.as_deref(), &view.name)))
+        .any(|(other_namespace, other_name)| other_name == name && other_namespace != namespace);
+
+    match namespace {
+        Some(namespace) if is_taken_elsewhere => format!("{}_{}", namespace, name),
+        _ => name.to_owned(),
+    }
+}
+
+fn referenced_model_name(schema: &SqlSchema, foreign_key: &ForeignKey) -> String {
+    model_name(
+        schema,
+        foreign_key.referenced_schema.as_deref(),
+        &foreign_key.referenced_table,
+    )
+}
+
+fn model_db_name(model: &Model) -> &str {
+    model.database_name.as_deref().unwrap_or(&model.name)
+}
+
+/// Columns of these types are introspected as commented out `Unsupported` fields. JSON and UUID
+/// columns are not among them, see `calculate_scalar_field_type`.
+pub(crate) fn is_unsupported_type(family: &ColumnTypeFamily) -> bool {
+    matches!(
+        family,
+        ColumnTypeFamily::Binary
+            | ColumnTypeFamily::Geometric
+            | ColumnTypeFamily::LogSequenceNumber
+            | ColumnTypeFamily::TextSearch
+            | ColumnTypeFamily::TransactionId
+            | ColumnTypeFamily::Unknown
+    )
+}
+
+/// A foreign key over several columns becomes a relation field named after the referenced table,
+/// listing the columns in `fields`.
+fn calculate_composite_relation_field(
+    schema: &SqlSchema,
+    table: &Table,
+    foreign_key: &ForeignKey,
+    model: &Model,
+) -> Field {
+    let is_optional = foreign_key.columns.iter().any(|column_name| {
+        table
+            .column(column_name)
+            .map(|column| column.tpe.arity == ColumnArity::Nullable)
+            .unwrap_or(false)
+    });
+
+    let referenced_model = referenced_model_name(schema, foreign_key);
+    let basename = referenced_model.clone().camel_case();
+    let name = match model.find_field(&basename) {
+        Some(_) => format!("{}_{}", basename, foreign_key.columns.join("_")),
+        None => basename,
+    };
+
+    Field {
+        name,
+        arity: if is_optional {
+            FieldArity::Optional
+        } else {
+            FieldArity::Required
+        },
+        field_type: FieldType::Relation(RelationInfo {
+            name: calculate_relation_name(schema, foreign_key, table),
+            to: referenced_model,
+            fields: foreign_key.columns.clone(),
+            to_fields: foreign_key.referenced_columns.clone(),
+            on_delete: OnDeleteStrategy::None,
+            table: None,
+            column: None,
+        }),
+        database_name: None,
+        default_value: None,
+        is_unique: false,
+        id_info: None,
+        documentation: None,
+        is_generated: false,
+        is_updated_at: false,
+    }
+}
+
+fn are_columns_unique(table: &Table, column_names: &[String]) -> bool {
+    table.indices.iter().any(|index| {
+        index.tpe == IndexType::Unique
+            && index.columns.len() == column_names.len()
+            && column_names.iter().all(|name| index.columns.contains(name))
+    })
+}
+
+fn calculate_scalar_field_type(column: &Column) -> FieldType {
+    if is_unsupported_type(&column.tpe.family) {
+        return FieldType::Unsupported(column.tpe.raw.clone());
+    }
+
+    match &column.tpe.family {
+        ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
+        ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean),
+        ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
+        ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
+        ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
+        ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+        // Prisma has no JSON or UUID type yet. Their values are read and written in their text
+        // form, so the columns are usable as `String` fields.
+        ColumnTypeFamily::Json | ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String),
+        _ => FieldType::Unsupported(column.tpe.raw.clone()),
+    }
+}
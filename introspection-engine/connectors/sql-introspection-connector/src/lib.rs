@@ -1,22 +1,32 @@
 pub mod calculate_datamodel; // only exported to be able to unit test it
+pub mod merge_datamodel; // only exported to be able to unit test it
+pub mod warnings; // only exported to be able to unit test it
 
 mod error;
+mod sanitize_datamodel_names;
 mod schema_describer_loading;
 
 use datamodel::Datamodel;
-use introspection_connector::{ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector};
+use introspection_connector::{
+    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
+    ReIntrospectionResult,
+};
 use quaint::prelude::ConnectionInfo;
-use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
+use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, TableFilter};
 use std::future::Future;
 use tracing_futures::Instrument;
 
 pub use error::*;
+pub use sql_schema_describer::TableFilter;
 
 pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlIntrospectionError>;
 
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    schemas: Vec<String>,
+    table_filter: TableFilter,
+    naming_conventions: bool,
 }
 
 impl SqlIntrospectionConnector {
@@ -37,9 +47,31 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            schemas: Vec::new(),
+            table_filter: TableFilter::default(),
+            naming_conventions: false,
         })
     }
 
+    /// Additionally introspect the given database schemas. Their models get a `@@schema` directive.
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
+    /// Only introspect the tables and views matching the filter.
+    pub fn with_table_filter(mut self, table_filter: TableFilter) -> Self {
+        self.table_filter = table_filter;
+        self
+    }
+
+    /// Name all models and enums in PascalCase and all fields in camelCase, not only the ones
+    /// that are no valid identifiers. The database names are kept with `@map` and `@@map`.
+    pub fn with_naming_conventions(mut self, naming_conventions: bool) -> Self {
+        self.naming_conventions = naming_conventions;
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlIntrospectionError>>) -> ConnectorResult<O> {
         fut.await
             .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))
@@ -59,7 +91,14 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        self.describe_namespaces(&self.schemas).await
+    }
+
+    async fn describe_namespaces(&self, schemas: &[String]) -> SqlIntrospectionResult<SqlSchema> {
+        Ok(self
+            .describer
+            .describe_with_filter(self.connection_info.schema_name(), schemas, &self.table_filter)
+            .await?)
     }
 }
 
@@ -73,9 +112,33 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(self.catch(self.get_metadata_internal()).await?)
     }
 
-    async fn introspect(&self) -> ConnectorResult<Datamodel> {
+    async fn introspect(&self) -> ConnectorResult<IntrospectionResult> {
         let sql_schema = self.catch(self.describe()).await?;
-        let data_model = calculate_datamodel::calculate_model(&sql_schema).unwrap();
-        Ok(data_model)
+        let datamodel =
+            calculate_datamodel::calculate_model_with_naming_conventions(&sql_schema, self.naming_conventions).unwrap();
+        let warnings = warnings::calculate_warnings(&sql_schema, &self.schemas, &self.table_filter);
+        Ok(IntrospectionResult { datamodel, warnings })
+    }
+
+    async fn re_introspect(&self, existing: &Datamodel) -> ConnectorResult<ReIntrospectionResult> {
+        // Models the user placed in other schemas must not show up as removed.
+        let mut schemas = self.schemas.clone();
+        for schema in existing.models().filter_map(|model| model.schema.as_ref()) {
+            if !schemas.contains(schema) {
+                schemas.push(schema.clone());
+            }
+        }
+
+        let sql_schema = self.catch(self.describe_namespaces(&schemas)).await?;
+        let introspected =
+            calculate_datamodel::calculate_model_with_naming_conventions(&sql_schema, self.naming_conventions).unwrap();
+        let (datamodel, changes) = merge_datamodel::merge_datamodels(existing, &introspected);
+        let warnings = warnings::calculate_warnings(&sql_schema, &schemas, &self.table_filter);
+
+        Ok(ReIntrospectionResult {
+            datamodel,
+            changes,
+            warnings,
+        })
     }
 }
@@ -0,0 +1,404 @@
+use datamodel::{dml, Datamodel, Field, FieldType, Model};
+use introspection_connector::{EnumAndValue, IntrospectionChanges, ModelAndField};
+use std::collections::HashMap;
+
+/// Merge a freshly introspected datamodel into the datamodel the user already has.
+///
+/// Models, enums and fields are matched by their names in the database. For everything that is
+/// unchanged, the existing definition is kept, including user-chosen names, `@map`s, documentation
+/// and defaults. Everything that is new is taken from the introspected datamodel, renamed and mapped
+/// when its name is already taken, and everything that disappeared from the database is dropped.
+/// The returned changes list both.
+pub fn merge_datamodels(existing: &Datamodel, introspected: &Datamodel) -> (Datamodel, IntrospectionChanges) {
+    let mut changes = IntrospectionChanges::default();
+    let mut merged = Datamodel::new();
+
+    // Introspected name -> final name.
+    let mut model_names: HashMap<&str, String> = HashMap::new();
+    let mut enum_names: HashMap<&str, String> = HashMap::new();
+    // (introspected model name, introspected field name) -> final field name.
+    let mut field_names: HashMap<(&str, &str), String> = HashMap::new();
+    // Fields taken over from the introspected datamodel, by introspected model name and final field
+    // name. Their references have to be renamed.
+    let mut introspected_fields: Vec<(String, String)> = Vec::new();
+
+    for introspected_enum in introspected.enums() {
+        let merged_enum = match find_existing_enum(existing, introspected_enum) {
+            Some(existing_enum) => {
+                let enum_and_value = |value: &String| EnumAndValue {
+                    r#enum: existing_enum.name.clone(),
+                    value: value.clone(),
+                };
+
+                for value in introspected_enum.values.iter() {
+                    if !existing_enum.values.contains(value) {
+                        changes.added_enum_values.push(enum_and_value(value));
+                    }
+                }
+
+                for value in existing_enum.values.iter() {
+                    if !introspected_enum.values.contains(value) {
+                        changes.removed_enum_values.push(enum_and_value(value));
+                    }
+                }
+
+                dml::Enum {
+                    values: introspected_enum.values.clone(),
+                    ..existing_enum.clone()
+                }
+            }
+            None => {
+                let mut enm = introspected_enum.clone();
+                enm.name = unused_name(&introspected_enum.name, |name| {
+                    is_top_name_taken(existing, &merged, name)
+                });
+
+                if enm.name != introspected_enum.name {
+                    enm.database_name = Some(enum_db_name(introspected_enum).to_owned());
+                }
+
+                changes.added_enums.push(enm.name.clone());
+                enm
+            }
+        };
+
+        enum_names.insert(&introspected_enum.name, merged_enum.name.clone());
+        merged.add_enum(merged_enum);
+    }
+
+    for existing_enum in existing.enums() {
+        let was_introspected = introspected
+            .enums()
+            .any(|introspected_enum| enum_db_name(introspected_enum) == enum_db_name(existing_enum));
+
+        if !was_introspected {
+            changes.removed_enums.push(existing_enum.name.clone());
+        }
+    }
+
+    for introspected_model in introspected.models() {
+        let existing_model = match find_existing_model(existing, introspected_model) {
+            Some(existing_model) => existing_model,
+            None => {
+                let mut model = introspected_model.clone();
+                model.name = unused_name(&introspected_model.name, |name| {
+                    is_top_name_taken(existing, &merged, name)
+                });
+
+                if model.name != introspected_model.name {
+                    model.database_name = Some(model_db_name(introspected_model).to_owned());
+                }
+
+                changes.added_models.push(model.name.clone());
+                model_names.insert(&introspected_model.name, model.name.clone());
+
+                for field in introspected_model.fields() {
+                    field_names.insert((&introspected_model.name, &field.name), field.name.clone());
+                    introspected_fields.push((introspected_model.name.clone(), field.name.clone()));
+                }
+
+                merged.add_model(model);
+                continue;
+            }
+        };
+
+        model_names.insert(&introspected_model.name, existing_model.name.clone());
+
+        let mut matches: Vec<(&Field, &Field)> = Vec::new();
+        for introspected_field in introspected_model.fields() {
+            let matching_field = find_existing_field(
+                existing,
+                existing_model,
+                introspected,
+                introspected_model,
+                introspected_field,
+                &matches,
+            );
+
+            if let Some(existing_field) = matching_field {
+                matches.push((existing_field, introspected_field));
+            }
+        }
+
+        let mut model = Model {
+            fields: Vec::new(),
+            indexes: introspected_model.indexes.clone(),
+            id_fields: introspected_model.id_fields.clone(),
+            is_view: introspected_model.is_view,
+            view_definition: introspected_model.view_definition.clone(),
+            checks: introspected_model.checks.clone(),
+            schema: introspected_model.schema.clone(),
+            ..existing_model.clone()
+        };
+
+        for existing_field in existing_model.fields() {
+            match matches.iter().find(|(matched, _)| matched.name == existing_field.name) {
+                Some((_, introspected_field)) => {
+                    field_names.insert(
+                        (&introspected_model.name, &introspected_field.name),
+                        existing_field.name.clone(),
+                    );
+                    model.add_field(merge_field(existing_field, introspected_field, &enum_names));
+                }
+                None => changes.removed_fields.push(ModelAndField {
+                    model: existing_model.name.clone(),
+                    field: existing_field.name.clone(),
+                }),
+            }
+        }
+
+        for introspected_field in introspected_model.fields() {
+            let is_matched = matches
+                .iter()
+                .any(|(_, matched)| matched.name == introspected_field.name);
+
+            if !is_matched {
+                let mut field = introspected_field.clone();
+                field.name = unused_name(&introspected_field.name, |name| model.find_field(name).is_some());
+
+                // Back relation fields have no column, so there is nothing to map them to.
+                if field.name != introspected_field.name && !field.field_type.is_relation() {
+                    field.database_name = Some(field_db_name(introspected_field).to_owned());
+                }
+
+                changes.added_fields.push(ModelAndField {
+                    model: existing_model.name.clone(),
+                    field: field.name.clone(),
+                });
+                field_names.insert((&introspected_model.name, &introspected_field.name), field.name.clone());
+                introspected_fields.push((introspected_model.name.clone(), field.name.clone()));
+                model.add_field(field);
+            }
+        }
+
+        merged.add_model(model);
+    }
+
+    for existing_model in existing.models() {
+        let was_introspected = introspected
+            .models()
+            .any(|introspected_model| model_key(introspected_model) == model_key(existing_model));
+
+        if !was_introspected {
+            changes.removed_models.push(existing_model.name.clone());
+        }
+    }
+
+    // Everything taken from the introspected datamodel still refers to introspected names.
+    for introspected_model in introspected.models() {
+        // Model names can be taken by other models, the database names are unique.
+        let model = match merged
+            .models
+            .iter_mut()
+            .find(|model| model_key(model) == model_key(introspected_model))
+        {
+            Some(model) => model,
+            None => continue,
+        };
+        let rename_field = |name: &String| {
+            field_names
+                .get(&(introspected_model.name.as_str(), name.as_str()))
+                .cloned()
+                .unwrap_or_else(|| name.clone())
+        };
+
+        model.id_fields = model.id_fields.iter().map(rename_field).collect();
+        for index in model.indexes.iter_mut() {
+            index.fields = index.fields.iter().map(rename_field).collect();
+        }
+
+        for field in model.fields_mut() {
+            let is_introspected = introspected_fields
+                .iter()
+                .any(|(model, name)| model == &introspected_model.name && name == &field.name);
+
+            if !is_introspected {
+                continue;
+            }
+
+            match &mut field.field_type {
+                FieldType::Relation(relation_info) => {
+                    let introspected_target = relation_info.to.clone();
+
+                    relation_info.fields = relation_info.fields.iter().map(rename_field).collect();
+
+                    relation_info.to_fields = relation_info
+                        .to_fields
+                        .iter()
+                        .map(|name| {
+                            field_names
+                                .get(&(introspected_target.as_str(), name.as_str()))
+                                .cloned()
+                                .unwrap_or_else(|| name.clone())
+                        })
+                        .collect();
+
+                    if let Some(target) = model_names.get(introspected_target.as_str()) {
+                        relation_info.to = target.clone();
+                    }
+                }
+                FieldType::Enum(enum_name) => {
+                    if let Some(merged_enum_name) = enum_names.get(enum_name.as_str()) {
+                        *enum_name = merged_enum_name.clone();
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    (merged, changes)
+}
+
+/// Unchanged fields are kept as they are. When the column changed, the introspected field is taken,
+/// keeping only the name, mapping and documentation of the existing one.
+fn merge_field(existing: &Field, introspected: &Field, enum_names: &HashMap<&str, String>) -> Field {
+    let is_unchanged = match (&existing.field_type, &introspected.field_type) {
+        // Relation fields carry user-chosen names on both sides, so they are always kept.
+        (FieldType::Relation(_), FieldType::Relation(_)) => true,
+        (FieldType::Enum(existing_enum), FieldType::Enum(introspected_enum)) => {
+            enum_names.get(introspected_enum.as_str()) == Some(existing_enum) && existing.arity == introspected.arity
+        }
+        (existing_type, introspected_type) => {
+            existing_type == introspected_type && existing.arity == introspected.arity
+        }
+    };
+
+    if is_unchanged {
+        return existing.clone();
+    }
+
+    let mut field = Field {
+        name: existing.name.clone(),
+        database_name: existing.database_name.clone(),
+        documentation: existing.documentation.clone(),
+        ..introspected.clone()
+    };
+
+    if let FieldType::Enum(enum_name) = &mut field.field_type {
+        if let Some(merged_enum_name) = enum_names.get(enum_name.as_str()) {
+            *enum_name = merged_enum_name.clone();
+        }
+    }
+
+    field
+}
+
+fn find_existing_model<'a>(existing: &'a Datamodel, introspected_model: &Model) -> Option<&'a Model> {
+    existing
+        .models()
+        .find(|existing_model| model_key(existing_model) == model_key(introspected_model))
+}
+
+fn find_existing_enum<'a>(existing: &'a Datamodel, introspected_enum: &dml::Enum) -> Option<&'a dml::Enum> {
+    existing
+        .enums()
+        .find(|existing_enum| enum_db_name(existing_enum) == enum_db_name(introspected_enum))
+}
+
+/// Models and enums share one namespace. Names of the existing datamodel stay reserved, because
+/// its models and enums are merged later or keep being referenced by name.
+fn is_top_name_taken(existing: &Datamodel, merged: &Datamodel, name: &str) -> bool {
+    [existing, merged]
+        .iter()
+        .any(|datamodel| datamodel.find_model(name).is_some() || datamodel.find_enum(name).is_some())
+}
+
+/// Appends a numeric suffix when the name is already taken.
+fn unused_name(name: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let mut candidate = name.to_owned();
+    let mut counter = 1;
+
+    while is_taken(&candidate) {
+        candidate = format!("{}_{}", name, counter);
+        counter += 1;
+    }
+
+    candidate
+}
+
+/// Scalar fields match when they have the same column. Relation fields match when they point to
+/// the same table and either have the same relation name or column, or are the only relation
+/// between the two tables.
+fn find_existing_field<'a>(
+    existing: &'a Datamodel,
+    existing_model: &'a Model,
+    introspected: &Datamodel,
+    introspected_model: &Model,
+    introspected_field: &Field,
+    already_matched: &[(&'a Field, &Field)],
+) -> Option<&'a Field> {
+    let unmatched_fields = existing_model.fields().filter(|existing_field| {
+        !already_matched
+            .iter()
+            .any(|(matched, _)| matched.name == existing_field.name)
+    });
+
+    let introspected_relation = match &introspected_field.field_type {
+        FieldType::Relation(relation_info) => relation_info,
+        _ => {
+            return unmatched_fields
+                .filter(|existing_field| !existing_field.field_type.is_relation())
+                .find(|existing_field| field_db_name(existing_field) == field_db_name(introspected_field));
+        }
+    };
+
+    let target_table = relation_target_table(introspected, &introspected_relation.to);
+    let candidates: Vec<&Field> = unmatched_fields
+        .filter(|existing_field| match &existing_field.field_type {
+            FieldType::Relation(relation_info) => relation_target_table(existing, &relation_info.to) == target_table,
+            _ => false,
+        })
+        .collect();
+
+    let by_relation_name = candidates
+        .iter()
+        .find(|existing_field| match &existing_field.field_type {
+            FieldType::Relation(relation_info) => relation_info.name == introspected_relation.name,
+            _ => false,
+        });
+    let by_column = candidates
+        .iter()
+        .find(|existing_field| field_db_name(existing_field) == field_db_name(introspected_field));
+
+    let introspected_relations_to_target = introspected_model
+        .fields()
+        .filter(|field| match &field.field_type {
+            FieldType::Relation(relation_info) => {
+                relation_target_table(introspected, &relation_info.to) == target_table
+            }
+            _ => false,
+        })
+        .count();
+    let only_relation = if candidates.len() == 1 && introspected_relations_to_target == 1 {
+        candidates.first()
+    } else {
+        None
+    };
+
+    by_relation_name.or(by_column).or(only_relation).cloned()
+}
+
+fn relation_target_table(datamodel: &Datamodel, model_name: &str) -> String {
+    datamodel
+        .find_model(model_name)
+        .map(|model| model_db_name(model).to_owned())
+        .unwrap_or_else(|| model_name.to_owned())
+}
+
+fn model_db_name(model: &Model) -> &str {
+    model.database_name.as_ref().unwrap_or(&model.name)
+}
+
+/// Tables are identified by their schema and name.
+fn model_key(model: &Model) -> (Option<&str>, &str) {
+    (model.schema.as_deref(), model_db_name(model))
+}
+
+fn enum_db_name(enm: &dml::Enum) -> &str {
+    enm.database_name.as_ref().unwrap_or(&enm.name)
+}
+
+fn field_db_name(field: &Field) -> &str {
+    field.database_name.as_ref().unwrap_or(&field.name)
+}
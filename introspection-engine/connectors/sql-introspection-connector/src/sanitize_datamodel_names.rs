@@ -0,0 +1,200 @@
+use datamodel::{Datamodel, Field, FieldArity, FieldType, Model};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sql_schema_describer::SqlSchema;
+use std::collections::HashSet;
+
+static RE_IDENTIFIER: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-zA-Z][_a-zA-Z0-9]*$").expect("compile regex"));
+
+/// Turn the table, column and enum names the datamodel was calculated from into valid Prisma
+/// identifiers. Invalid model and enum names become PascalCase, invalid field names camelCase.
+/// With `naming_conventions`, valid names are brought into that casing as well. The original
+/// names are kept with `@map` and `@@map`, and all references to renamed models, fields and
+/// enums are updated.
+///
+/// Enum values can not be mapped, so they are left as they are in the database. Invalid ones are
+/// reported by the `ENUM_VALUES_WITH_INVALID_NAMES` warning.
+pub(crate) fn sanitize_datamodel_names(datamodel: &mut Datamodel, schema: &SqlSchema, naming_conventions: bool) {
+    let inflector = prisma_inflector::default();
+    let field_needs_renaming =
+        |name: &str| needs_sanitation(name) || (naming_conventions && inflector.camel_case(name) != name);
+    let type_needs_renaming =
+        |name: &str| needs_sanitation(name) || (naming_conventions && inflector.pascal_case(name) != name);
+    // (model name, old field name, new field name)
+    let mut field_renames: Vec<(String, String, String)> = Vec::new();
+
+    for model in datamodel.models.iter_mut() {
+        let has_columns: Vec<bool> = model.fields().map(|field| has_column(schema, model, field)).collect();
+        let mut used_names: HashSet<String> = model
+            .fields()
+            .filter(|field| !field_needs_renaming(&field.name))
+            .map(|field| field.name.clone())
+            .collect();
+
+        for (field, is_column) in model.fields.iter_mut().zip(has_columns) {
+            if !field_needs_renaming(&field.name) {
+                continue;
+            }
+
+            let new_name = deduplicate(
+                with_valid_start(inflector.camel_case(&field.name), "field"),
+                &mut used_names,
+            );
+
+            // Back relation fields have no column, so there is nothing to map them to.
+            if is_column && field.database_name.is_none() {
+                field.database_name = Some(field.name.clone());
+            }
+
+            field_renames.push((model.name.clone(), field.name.clone(), new_name.clone()));
+            field.name = new_name;
+        }
+    }
+
+    for (model_name, old_name, new_name) in field_renames.iter() {
+        let model = datamodel.find_model_mut(model_name).unwrap();
+        rename_all(model.id_fields.iter_mut(), old_name, new_name);
+        for index in model.indexes.iter_mut() {
+            rename_all(index.fields.iter_mut(), old_name, new_name);
+        }
+        for field in model.fields_mut() {
+            if let FieldType::Relation(relation_info) = &mut field.field_type {
+                rename_all(relation_info.fields.iter_mut(), old_name, new_name);
+            }
+        }
+
+        for relation_info in relations_mut(datamodel).filter(|relation_info| &relation_info.to == model_name) {
+            rename_all(relation_info.to_fields.iter_mut(), old_name, new_name);
+        }
+    }
+
+    let mut used_names: HashSet<String> = datamodel
+        .models()
+        .map(|model| model.name.clone())
+        .chain(datamodel.enums().map(|enm| enm.name.clone()))
+        .filter(|name| !type_needs_renaming(name))
+        .collect();
+    let mut model_renames: Vec<(String, String)> = Vec::new();
+
+    for model in datamodel
+        .models
+        .iter_mut()
+        .filter(|model| type_needs_renaming(&model.name))
+    {
+        let new_name = deduplicate(
+            with_valid_start(inflector.pascal_case(&model.name), "Model"),
+            &mut used_names,
+        );
+
+        if model.database_name.is_none() {
+            model.database_name = Some(model.name.clone());
+        }
+
+        model_renames.push((model.name.clone(), new_name.clone()));
+        model.name = new_name;
+    }
+
+    for (old_name, new_name) in model_renames.iter() {
+        for relation_info in relations_mut(datamodel).filter(|relation_info| &relation_info.to == old_name) {
+            relation_info.to = new_name.clone();
+        }
+    }
+
+    let mut enum_renames: Vec<(String, String)> = Vec::new();
+
+    for enm in datamodel.enums.iter_mut().filter(|enm| type_needs_renaming(&enm.name)) {
+        let new_name = deduplicate(
+            with_valid_start(inflector.pascal_case(&enm.name), "Enum"),
+            &mut used_names,
+        );
+
+        if enm.database_name.is_none() {
+            enm.database_name = Some(enm.name.clone());
+        }
+
+        enum_renames.push((enm.name.clone(), new_name.clone()));
+        enm.name = new_name;
+    }
+
+    for field in datamodel.models.iter_mut().flat_map(|model| model.fields.iter_mut()) {
+        let enum_name = match &mut field.field_type {
+            FieldType::Enum(enum_name) => enum_name,
+            _ => continue,
+        };
+
+        if let Some((_, new_name)) = enum_renames.iter().find(|(old_name, _)| *old_name == *enum_name) {
+            *enum_name = new_name.clone();
+        }
+    }
+}
+
+/// Identifiers must start with a letter and may only contain letters, digits and underscores.
+pub(crate) fn needs_sanitation(name: &str) -> bool {
+    !RE_IDENTIFIER.is_match(name)
+}
+
+/// Names that don't start with a letter get a prefix, so `2fa_enabled` becomes `field2faEnabled`
+/// instead of losing its leading digits.
+fn with_valid_start(name: String, prefix: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("{}{}", prefix, name)
+    }
+}
+
+/// Appends a numeric suffix when the name is already taken, e.g. for `order-items` and
+/// `order_items` in the same schema.
+fn deduplicate(name: String, used_names: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut counter = 1;
+
+    while used_names.contains(&candidate) {
+        candidate = format!("{}_{}", name, counter);
+        counter += 1;
+    }
+
+    used_names.insert(candidate.clone());
+    candidate
+}
+
+fn rename_all<'a>(names: impl Iterator<Item = &'a mut String>, old_name: &str, new_name: &str) {
+    for name in names.filter(|name| name.as_str() == old_name) {
+        *name = new_name.to_owned();
+    }
+}
+
+fn relations_mut(datamodel: &mut Datamodel) -> impl Iterator<Item = &mut datamodel::RelationInfo> {
+    datamodel
+        .models
+        .iter_mut()
+        .flat_map(|model| model.fields.iter_mut())
+        .filter_map(|field| match &mut field.field_type {
+            FieldType::Relation(relation_info) => Some(relation_info),
+            _ => None,
+        })
+}
+
+fn has_column(schema: &SqlSchema, model: &Model, field: &Field) -> bool {
+    if field.field_type.is_relation() && field.arity == FieldArity::List {
+        return false;
+    }
+
+    let table_name = model.database_name.as_ref().unwrap_or(&model.name);
+
+    if model.is_view {
+        schema
+            .views
+            .iter()
+            .find(|view| &view.name == table_name && view.namespace == model.schema)
+            .and_then(|view| view.column(&field.name))
+            .is_some()
+    } else {
+        schema
+            .tables
+            .iter()
+            .find(|table| &table.name == table_name && table.namespace == model.schema)
+            .map(|table| table.has_column(&field.name))
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::calculate_datamodel::{is_join_table, is_migration_table, is_unsupported_type, references_excluded_table};
+use crate::sanitize_datamodel_names::needs_sanitation;
+use introspection_connector::{AffectedObject, Warning};
+use sql_schema_describer::*;
+
+/// Models without an id can not be used by the query engine.
+pub const TABLES_WITHOUT_PRIMARY_KEY: u32 = 1;
+/// Columns with unsupported types are commented out in the datamodel. JSON and UUID columns are
+/// not reported, they become `String` fields since their values can be read and written as text.
+pub const UNSUPPORTED_COLUMN_TYPES: u32 = 2;
+/// Foreign keys to tables excluded from introspection do not become relations.
+pub const RELATIONS_TO_EXCLUDED_TABLES: u32 = 3;
+/// Views without an `id` column become models without an id, which can not be queried.
+pub const VIEWS_WITHOUT_ID: u32 = 4;
+/// Enum values can not be mapped, so values that are not valid identifiers are kept as they are
+/// and the datamodel fails validation until they are renamed in the database. The affected object
+/// is the enum with the value as its column.
+pub const ENUM_VALUES_WITH_INVALID_NAMES: u32 = 5;
+
+/// Calculate the warnings for the constructs of a database schema introspection can not
+/// translate into a usable datamodel. The schema was described in the additional `namespaces`,
+/// with the `table_filter`.
+pub fn calculate_warnings(schema: &SqlSchema, namespaces: &[String], table_filter: &TableFilter) -> Vec<Warning> {
+    let tables: Vec<&Table> = schema
+        .tables
+        .iter()
+        .filter(|table| !is_migration_table(table))
+        .filter(|table| !is_join_table(table))
+        .collect();
+    let mut warnings = Vec::new();
+
+    let tables_without_primary_key: Vec<AffectedObject> = tables
+        .iter()
+        .filter(|table| table.primary_key.is_none())
+        .map(|table| AffectedObject {
+            table: table.name.clone(),
+            column: None,
+        })
+        .collect();
+
+    if !tables_without_primary_key.is_empty() {
+        warnings.push(Warning {
+            code: TABLES_WITHOUT_PRIMARY_KEY,
+            message: "These tables do not have a primary key. Their models need an id field before they can be used."
+                .to_owned(),
+            affected: tables_without_primary_key,
+        });
+    }
+
+    let views_without_id: Vec<AffectedObject> = schema
+        .views
+        .iter()
+        .filter(|view| !view.columns.iter().any(|column| column.name.to_lowercase() == "id"))
+        .map(|view| AffectedObject {
+            table: view.name.clone(),
+            column: None,
+        })
+        .collect();
+
+    if !views_without_id.is_empty() {
+        warnings.push(Warning {
+            code: VIEWS_WITHOUT_ID,
+            message: "These views have no `id` column. Their models need an id field before they can be queried."
+                .to_owned(),
+            affected: views_without_id,
+        });
+    }
+
+    let unsupported_table_columns = tables
+        .iter()
+        .flat_map(|table| table.columns.iter().map(move |column| (&table.name, column)));
+    let unsupported_view_columns = schema
+        .views
+        .iter()
+        .flat_map(|view| view.columns.iter().map(move |column| (&view.name, column)));
+    let unsupported_columns: Vec<AffectedObject> = unsupported_table_columns
+        .chain(unsupported_view_columns)
+        .filter(|(_, column)| is_unsupported_type(&column.tpe.family))
+        .map(|(table, column)| AffectedObject {
+            table: table.clone(),
+            column: Some(column.name.clone()),
+        })
+        .collect();
+
+    if !unsupported_columns.is_empty() {
+        warnings.push(Warning {
+            code: UNSUPPORTED_COLUMN_TYPES,
+            message: "These columns have types Prisma does not support. Their fields were commented out.".to_owned(),
+            affected: unsupported_columns,
+        });
+    }
+
+    let enum_values_with_invalid_names: Vec<AffectedObject> = schema
+        .enums
+        .iter()
+        .flat_map(|enm| enm.values.iter().map(move |value| (&enm.name, value)))
+        .filter(|(_, value)| needs_sanitation(value))
+        .map(|(enm, value)| AffectedObject {
+            table: enm.clone(),
+            column: Some(value.clone()),
+        })
+        .collect();
+
+    if !enum_values_with_invalid_names.is_empty() {
+        warnings.push(Warning {
+            code: ENUM_VALUES_WITH_INVALID_NAMES,
+            message: "These enum values are not valid identifiers. They need to be renamed in the database.".to_owned(),
+            affected: enum_values_with_invalid_names,
+        });
+    }
+
+    // Foreign keys to tables that were not described for other reasons, like being in another
+    // schema, are not the filter's doing.
+    let table_matcher = table_filter.matcher();
+    let was_filtered_out = |fk: &ForeignKey| {
+        let schema_was_described = match &fk.referenced_schema {
+            None => true,
+            Some(referenced_schema) => namespaces.contains(referenced_schema),
+        };
+
+        references_excluded_table(schema, fk) && schema_was_described && !table_matcher.includes(&fk.referenced_table)
+    };
+
+    let relations_to_excluded_tables: Vec<AffectedObject> = schema
+        .tables
+        .iter()
+        .filter(|table| !is_migration_table(table))
+        .flat_map(|table| table.foreign_keys.iter().map(move |fk| (&table.name, fk)))
+        .filter(|(_, fk)| was_filtered_out(fk))
+        .flat_map(|(table, fk)| {
+            fk.columns.iter().map(move |column| AffectedObject {
+                table: table.clone(),
+                column: Some(column.clone()),
+            })
+        })
+        .collect();
+
+    if !relations_to_excluded_tables.is_empty() {
+        warnings.push(Warning {
+            code: RELATIONS_TO_EXCLUDED_TABLES,
+            message: "These columns reference tables excluded from introspection. They became scalar fields."
+                .to_owned(),
+            affected: relations_to_excluded_tables,
+        });
+    }
+
+    warnings
+}
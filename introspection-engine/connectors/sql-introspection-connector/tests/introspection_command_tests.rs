@@ -1,11 +1,15 @@
-use std::collections::HashSet;
 use pretty_assertions::assert_eq;
 
 use datamodel::{
     common::{ScalarType, ScalarValue},
     dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, OnDeleteStrategy, RelationInfo,
 };
-use sql_introspection_connector::calculate_datamodel::calculate_model;
+use introspection_connector::{AffectedObject, Warning};
+use sql_introspection_connector::calculate_datamodel::{calculate_model, calculate_model_with_naming_conventions};
+use sql_introspection_connector::warnings::{
+    calculate_warnings, ENUM_VALUES_WITH_INVALID_NAMES, RELATIONS_TO_EXCLUDED_TABLES, TABLES_WITHOUT_PRIMARY_KEY,
+    UNSUPPORTED_COLUMN_TYPES, VIEWS_WITHOUT_ID,
+};
 use sql_schema_describer::*;
 
 #[test]
@@ -31,6 +35,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_composite_type: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
@@ -43,9 +52,8 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
                         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
                         ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
-                        // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
-                        // variants that don't yet have corresponding PrismaType variants
-                        _ => FieldType::Base(ScalarType::String),
+                        ColumnTypeFamily::Json | ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String),
+                        _ => FieldType::Unsupported("raw".to_string()),
                     };
                     Field {
                         name: col_type.to_string(),
@@ -79,6 +87,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 })
                 .collect(),
             indices: vec![],
@@ -87,9 +96,13 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -104,6 +117,11 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_composite_type: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
             fields: vec![
                 Field {
                     name: "optional".to_string(),
@@ -162,6 +180,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "required".to_string(),
@@ -172,6 +191,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "list".to_string(),
@@ -182,6 +202,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -190,9 +211,13 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -207,12 +232,17 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_composite_type: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
             fields: vec![
                 Field {
-                    name: "no-default".to_string(),
+                    name: "no_default".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::Int),
-                    database_name: None,
+                    database_name: Some("no-default".to_string()),
                     default_value: None,
                     is_unique: false,
                     id_info: None,
@@ -221,10 +251,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                 },
                 Field {
-                    name: "int-default".to_string(),
+                    name: "int_default".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::Int),
-                    database_name: None,
+                    database_name: Some("int-default".to_string()),
                     default_value: Some(ScalarValue::Int(1)),
                     is_unique: false,
                     id_info: None,
@@ -233,10 +263,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                 },
                 Field {
-                    name: "bool-default".to_string(),
+                    name: "bool_default".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::Boolean),
-                    database_name: None,
+                    database_name: Some("bool-default".to_string()),
                     default_value: Some(ScalarValue::Boolean(true)),
                     is_unique: false,
                     id_info: None,
@@ -245,10 +275,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                 },
                 Field {
-                    name: "float-default".to_string(),
+                    name: "float_default".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::Float),
-                    database_name: None,
+                    database_name: Some("float-default".to_string()),
                     default_value: Some(ScalarValue::Float(1.0)),
                     is_unique: false,
                     id_info: None,
@@ -257,10 +287,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                 },
                 Field {
-                    name: "string-default".to_string(),
+                    name: "string_default".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::String),
-                    database_name: None,
+                    database_name: Some("string-default".to_string()),
                     default_value: Some(ScalarValue::String("default".to_string())),
                     is_unique: false,
                     id_info: None,
@@ -289,6 +319,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "int-default".to_string(),
@@ -299,6 +330,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some("'1'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "bool-default".to_string(),
@@ -309,6 +341,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some("'1'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "float-default".to_string(),
@@ -319,6 +352,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some("'1.0'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "string-default".to_string(),
@@ -329,20 +363,116 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some("default".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn default_expressions_are_preserved_when_generating_data_model_from_a_schema() {
+    let column = |name: &str, raw: &str, family: ColumnTypeFamily, default: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: raw.to_string(),
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: Some(default.to_string()),
+        auto_increment: false,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            columns: vec![
+                column("id", "uuid", ColumnTypeFamily::Uuid, "gen_random_uuid()"),
+                column(
+                    "counter",
+                    "integer",
+                    ColumnTypeFamily::Int,
+                    "nextval(counter_seq::regclass)",
+                ),
+                column("created_at", "timestamp", ColumnTypeFamily::DateTime, "now()"),
+                column(
+                    "updated_at",
+                    "datetime",
+                    ColumnTypeFamily::DateTime,
+                    "CURRENT_TIMESTAMP",
+                ),
+                column(
+                    "due_at",
+                    "timestamp",
+                    ColumnTypeFamily::DateTime,
+                    "(now() + 1 day::interval)",
+                ),
+                column("amount", "integer", ColumnTypeFamily::Int, "(1 + 2)"),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let model = data_model.find_model("Table1").unwrap();
+    let default_value = |name: &str| model.find_field(name).unwrap().default_value.clone();
+    let function = |name: &str, return_type: ScalarType, args: Vec<ScalarValue>| {
+        Some(ScalarValue::Expression(name.to_string(), return_type, args))
+    };
+    let db_generated = |return_type: ScalarType, expression: &str| {
+        function(
+            "dbgenerated",
+            return_type,
+            vec![ScalarValue::String(expression.to_string())],
+        )
+    };
+
+    assert_eq!(
+        default_value("id"),
+        db_generated(ScalarType::String, "gen_random_uuid()")
+    );
+    assert_eq!(
+        default_value("counter"),
+        function("autoincrement", ScalarType::Int, vec![])
+    );
+    assert_eq!(
+        default_value("created_at"),
+        function("now", ScalarType::DateTime, vec![])
+    );
+    assert_eq!(
+        default_value("updated_at"),
+        function("now", ScalarType::DateTime, vec![])
+    );
+    assert_eq!(
+        default_value("due_at"),
+        db_generated(ScalarType::DateTime, "(now() + 1 day::interval)")
+    );
+    assert_eq!(default_value("amount"), db_generated(ScalarType::Int, "(1 + 2)"));
+}
+
 #[test]
 fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
@@ -353,12 +483,21 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(ScalarValue::Expression(
+                        "autoincrement".to_string(),
+                        ScalarType::Int,
+                        vec![],
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -378,6 +517,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -403,12 +547,21 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(ScalarValue::Expression(
+                        "autoincrement".to_string(),
+                        ScalarType::Int,
+                        vec![],
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -443,6 +596,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -450,6 +604,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
             Table {
                 name: "Table2".to_string(),
@@ -462,6 +619,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -469,6 +627,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
             Table {
                 name: "Table3".to_string(),
@@ -481,6 +642,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -489,13 +651,18 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         name: "sequence".to_string(),
                         initial_value: 1,
                         allocation_size: 1,
+                        namespace: None,
                     }),
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -510,12 +677,17 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_composite_type: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
             fields: vec![
                 Field {
-                    name: "non-unique".to_string(),
+                    name: "non_unique".to_string(),
                     arity: FieldArity::Optional,
                     field_type: FieldType::Base(ScalarType::Int),
-                    database_name: None,
+                    database_name: Some("non-unique".to_string()),
                     default_value: None,
                     is_unique: false,
                     id_info: None,
@@ -556,6 +728,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "unique".to_string(),
@@ -566,18 +739,27 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -585,7 +767,6 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
 }
 
 #[test]
-#[ignore]
 fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
         models: vec![
@@ -594,13 +775,22 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
-                        default_value: None,
+                        default_value: Some(ScalarValue::Expression(
+                            "autoincrement".to_string(),
+                            ScalarType::Int,
+                            vec![],
+                        )),
                         is_unique: false,
                         id_info: Some(IdInfo {
                             strategy: IdStrategy::Auto,
@@ -622,6 +812,26 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                     },
+                    Field {
+                        name: "users".to_string(),
+                        arity: FieldArity::List,
+                        field_type: FieldType::Relation(RelationInfo {
+                            name: "CityToUser".to_string(),
+                            to: "User".to_string(),
+                            fields: vec![],
+                            to_fields: vec!["city".to_string()],
+                            on_delete: OnDeleteStrategy::None,
+                            table: None,
+                            column: None,
+                        }),
+                        database_name: None,
+                        default_value: None,
+                        is_unique: false,
+                        id_info: None,
+                        documentation: None,
+                        is_generated: false,
+                        is_updated_at: false,
+                    },
                 ],
                 is_generated: false,
                 indexes: vec![],
@@ -632,12 +842,33 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
+                        default_value: Some(ScalarValue::Expression(
+                            "autoincrement".to_string(),
+                            ScalarType::Int,
+                            vec![],
+                        )),
+                        is_unique: false,
+                        id_info: None,
+                        documentation: None,
+                        is_generated: false,
+                        is_updated_at: false,
+                    },
+                    Field {
+                        name: "city_id".to_string(),
+                        arity: FieldArity::Required,
+                        field_type: FieldType::Base(ScalarType::Int),
+                        database_name: Some("city-id".to_string()),
                         default_value: None,
                         is_unique: false,
                         id_info: None,
@@ -646,15 +877,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                     },
                     Field {
-                        name: "city-id".to_string(),
+                        name: "city_name".to_string(),
                         arity: FieldArity::Required,
-                        field_type: FieldType::Relation(RelationInfo {
-                            name: "".to_string(),
-                            to: "City".to_string(),
-                            to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
-                        }),
-                        database_name: None,
+                        field_type: FieldType::Base(ScalarType::String),
+                        database_name: Some("city-name".to_string()),
                         default_value: None,
                         is_unique: false,
                         id_info: None,
@@ -663,13 +889,16 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                     },
                     Field {
-                        name: "city-name".to_string(),
+                        name: "city".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Relation(RelationInfo {
-                            name: "".to_string(),
+                            name: "CityToUser".to_string(),
                             to: "City".to_string(),
-                            to_fields: vec!["name".to_string()],
+                            fields: vec!["city_id".to_string(), "city_name".to_string()],
+                            to_fields: vec!["id".to_string(), "name".to_string()],
                             on_delete: OnDeleteStrategy::None,
+                            table: None,
+                            column: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -702,6 +931,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -712,6 +942,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -720,6 +951,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -733,6 +967,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -743,6 +978,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-name".to_string(),
@@ -753,6 +989,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -762,13 +999,18 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -783,13 +1025,22 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            is_composite_type: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
             fields: vec![
                 Field {
                     name: "id".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(ScalarValue::Expression(
+                        "autoincrement".to_string(),
+                        ScalarType::Int,
+                        vec![],
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -829,6 +1080,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             }],
             id_fields: vec![],
         }],
@@ -848,6 +1103,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -858,6 +1114,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "lastname".to_string(),
@@ -868,21 +1125,30 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -891,7 +1157,6 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
 
 #[test]
 fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
-
     let ref_data_model = Datamodel {
         models: vec![
             Model {
@@ -899,13 +1164,22 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
-                        default_value: None,
+                        default_value: Some(ScalarValue::Expression(
+                            "autoincrement".to_string(),
+                            ScalarType::Int,
+                            vec![],
+                        )),
                         is_unique: false,
                         id_info: Some(IdInfo {
                             strategy: IdStrategy::Auto,
@@ -932,9 +1206,12 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         arity: FieldArity::List,
                         field_type: FieldType::Relation(RelationInfo {
                             to: "User".to_string(),
-                            to_fields: vec!["city-id".to_string()],
+                            fields: vec![],
+                            to_fields: vec!["city_id".to_string()],
                             name: "CityToUser".to_string(),
                             on_delete: OnDeleteStrategy::None,
+                            table: None,
+                            column: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -954,13 +1231,22 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_composite_type: false,
+                is_view: false,
+                view_definition: None,
+                checks: vec![],
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
-                        default_value: None,
+                        default_value: Some(ScalarValue::Expression(
+                            "autoincrement".to_string(),
+                            ScalarType::Int,
+                            vec![],
+                        )),
                         is_unique: false,
                         id_info: None,
                         documentation: None,
@@ -968,15 +1254,18 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                     },
                     Field {
-                        name: "city-id".to_string(),
+                        name: "city_id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Relation(RelationInfo {
                             name: "CityToUser".to_string(),
                             to: "City".to_string(),
+                            fields: vec![],
                             to_fields: vec!["id".to_string()],
                             on_delete: OnDeleteStrategy::None,
+                            table: None,
+                            column: None,
                         }),
-                        database_name: None,
+                        database_name: Some("city-id".to_string()),
                         default_value: None,
                         is_unique: false,
                         id_info: None,
@@ -1007,6 +1296,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -1017,6 +1307,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1025,6 +1316,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -1038,6 +1332,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -1048,6 +1343,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1056,19 +1352,108 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                     columns: vec!["city-id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn custom_join_tables_become_many_to_many_relations_when_generating_data_model_from_a_schema() {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let foreign_key = |column: &str, referenced_table: &str| ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_string()],
+        referenced_table: referenced_table.to_string(),
+        referenced_schema: None,
+        on_delete_action: ForeignKeyAction::Cascade,
+        referenced_columns: vec!["id".to_string()],
+    };
+    let table = |name: &str, columns: Vec<Column>, primary_key: Vec<&str>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns,
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: primary_key.iter().map(|column| column.to_string()).collect(),
+            sequence: None,
+        }),
+        foreign_keys,
+        checks: vec![],
+        namespace: None,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            table("User", vec![int_column("id")], vec!["id"], vec![]),
+            table("Role", vec![int_column("id")], vec!["id"], vec![]),
+            table(
+                "user_roles",
+                vec![int_column("user_id"), int_column("role_id")],
+                vec!["user_id", "role_id"],
+                vec![foreign_key("user_id", "User"), foreign_key("role_id", "Role")],
+            ),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert!(data_model.find_model("user_roles").is_none());
+
+    let roles = data_model.find_model("User").unwrap().find_field("roles").unwrap();
+    assert_eq!(roles.arity, FieldArity::List);
+    assert_eq!(
+        roles.field_type,
+        FieldType::Relation(RelationInfo {
+            to: "Role".to_string(),
+            fields: vec![],
+            to_fields: vec!["id".to_string()],
+            name: "user_roles".to_string(),
+            on_delete: OnDeleteStrategy::None,
+            table: Some("user_roles".to_string()),
+            column: Some("user_id".to_string()),
+        })
+    );
+
+    let users = data_model.find_model("Role").unwrap().find_field("users").unwrap();
+    assert_eq!(users.arity, FieldArity::List);
+    assert_eq!(
+        users.field_type,
+        FieldType::Relation(RelationInfo {
+            to: "User".to_string(),
+            fields: vec![],
+            to_fields: vec!["id".to_string()],
+            name: "user_roles".to_string(),
+            on_delete: OnDeleteStrategy::None,
+            table: Some("user_roles".to_string()),
+            column: Some("role_id".to_string()),
+        })
+    );
+}
+
 #[test]
 fn enums_are_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
@@ -1081,18 +1466,780 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         }],
     };
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("a".to_string());
-    enum_values.insert("b".to_string());
+    let enum_values = vec!["a".to_string(), "b".to_string()];
     let schema = SqlSchema {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
             values: enum_values,
+            namespace: None,
         }],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
+
+#[test]
+fn enum_columns_become_enum_fields_when_generating_data_model_from_a_schema() {
+    let enum_values = vec!["happy".to_string(), "sad".to_string()];
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType {
+                        raw: "integer".to_string(),
+                        family: ColumnTypeFamily::Int,
+                        arity: ColumnArity::Required,
+                    },
+                    default: None,
+                    auto_increment: true,
+                    comment: None,
+                },
+                Column {
+                    name: "mood".to_string(),
+                    tpe: ColumnType {
+                        raw: "enum('happy','sad')".to_string(),
+                        family: ColumnTypeFamily::Enum("User_mood".to_string()),
+                        arity: ColumnArity::Required,
+                    },
+                    default: Some("'happy'".to_string()),
+                    auto_increment: false,
+                    comment: None,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![Enum {
+            name: "User_mood".to_string(),
+            values: enum_values,
+            namespace: None,
+        }],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let mood = data_model.find_model("User").unwrap().find_field("mood").unwrap();
+    assert_eq!(mood.field_type, FieldType::Enum("User_mood".to_string()));
+    assert_eq!(
+        mood.default_value,
+        Some(dml::ScalarValue::ConstantLiteral("happy".to_string()))
+    );
+    assert!(data_model.find_enum("User_mood").is_some());
+}
+
+#[test]
+fn namespaces_are_preserved_when_generating_data_model_from_a_schema() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Invoice".to_string(),
+            columns: vec![Column {
+                name: "id".to_string(),
+                tpe: ColumnType {
+                    raw: "integer".to_string(),
+                    family: ColumnTypeFamily::Int,
+                    arity: ColumnArity::Required,
+                },
+                default: None,
+                auto_increment: true,
+                comment: None,
+            }],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: Some("billing".to_string()),
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(
+        data_model.find_model("Invoice").unwrap().schema,
+        Some("billing".to_string())
+    );
+}
+
+#[test]
+fn comments_become_documentation_when_generating_data_model_from_a_schema() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![Column {
+                name: "id".to_string(),
+                tpe: ColumnType {
+                    raw: "integer".to_string(),
+                    family: ColumnTypeFamily::Int,
+                    arity: ColumnArity::Required,
+                },
+                default: None,
+                auto_increment: true,
+                comment: Some("The primary key.".to_string()),
+            }],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: Some("All our users.".to_string()),
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let user = data_model.find_model("User").unwrap();
+    assert_eq!(user.documentation, Some("All our users.".to_string()));
+    assert_eq!(
+        user.find_field("id").unwrap().documentation,
+        Some("The primary key.".to_string())
+    );
+}
+
+#[test]
+fn invalid_names_are_sanitized_and_mapped_when_generating_data_model_from_a_schema() {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let table = |name: &str, columns: Vec<Column>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns,
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+        }),
+        foreign_keys,
+        checks: vec![],
+        namespace: None,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            table("OrderItems", vec![int_column("id")], vec![]),
+            table("order-items", vec![int_column("id"), int_column("2fa-enabled")], vec![]),
+            table(
+                "Order",
+                vec![int_column("id"), int_column("item-id")],
+                vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["item-id".to_string()],
+                    referenced_table: "order-items".to_string(),
+                    referenced_schema: None,
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+            ),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(data_model.find_model("OrderItems").unwrap().database_name, None);
+
+    let items = data_model.find_model("OrderItems_1").unwrap();
+    assert_eq!(items.database_name, Some("order-items".to_string()));
+    let enabled = items.find_field("field2faEnabled").unwrap();
+    assert_eq!(enabled.database_name, Some("2fa-enabled".to_string()));
+
+    let item = data_model.find_model("Order").unwrap().find_field("itemId").unwrap();
+    assert_eq!(item.database_name, Some("item-id".to_string()));
+    match &item.field_type {
+        FieldType::Relation(relation_info) => assert_eq!(relation_info.to, "OrderItems_1"),
+        _ => panic!("Expected a relation field."),
+    }
+
+    let back_relation = items.fields().find(|field| field.field_type.is_relation()).unwrap();
+    assert_eq!(back_relation.database_name, None);
+    match &back_relation.field_type {
+        FieldType::Relation(relation_info) => assert_eq!(relation_info.to_fields, vec!["itemId".to_string()]),
+        _ => panic!("Expected a relation field."),
+    }
+}
+
+#[test]
+fn invalid_enum_names_are_sanitized_and_values_kept_when_generating_data_model_from_a_schema() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Shirt".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType {
+                        raw: "integer".to_string(),
+                        family: ColumnTypeFamily::Int,
+                        arity: ColumnArity::Required,
+                    },
+                    default: None,
+                    auto_increment: true,
+                    comment: None,
+                },
+                Column {
+                    name: "color".to_string(),
+                    tpe: ColumnType {
+                        raw: "shirt-color".to_string(),
+                        family: ColumnTypeFamily::Enum("shirt-color".to_string()),
+                        arity: ColumnArity::Required,
+                    },
+                    default: Some("'light-blue'".to_string()),
+                    auto_increment: false,
+                    comment: None,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![Enum {
+            name: "shirt-color".to_string(),
+            values: vec!["light-blue".to_string(), "2tone".to_string()],
+            namespace: None,
+        }],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let shirt_color = data_model.find_enum("ShirtColor").unwrap();
+    assert_eq!(shirt_color.database_name, Some("shirt-color".to_string()));
+    assert_eq!(shirt_color.values, vec!["light-blue".to_string(), "2tone".to_string()]);
+
+    let color = data_model.find_model("Shirt").unwrap().find_field("color").unwrap();
+    assert_eq!(color.field_type, FieldType::Enum("ShirtColor".to_string()));
+    assert_eq!(
+        color.default_value,
+        Some(ScalarValue::ConstantLiteral("light-blue".to_string()))
+    );
+
+    let warnings = calculate_warnings(&schema, &[], &TableFilter::default());
+    assert_eq!(
+        warnings,
+        vec![Warning {
+            code: ENUM_VALUES_WITH_INVALID_NAMES,
+            message: "These enum values are not valid identifiers. They need to be renamed in the database."
+                .to_string(),
+            affected: vec![
+                AffectedObject {
+                    table: "shirt-color".to_string(),
+                    column: Some("light-blue".to_string()),
+                },
+                AffectedObject {
+                    table: "shirt-color".to_string(),
+                    column: Some("2tone".to_string()),
+                },
+            ],
+        }]
+    );
+}
+
+#[test]
+fn naming_conventions_are_applied_to_valid_names_when_requested() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "order_items".to_string(),
+            columns: vec![column("id"), column("unit_price")],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let items = data_model.find_model("order_items").unwrap();
+    assert_eq!(items.database_name, None);
+    assert_eq!(items.find_field("unit_price").unwrap().database_name, None);
+
+    let data_model = calculate_model_with_naming_conventions(&schema, true).expect("calculate data model");
+    let items = data_model.find_model("OrderItems").unwrap();
+    assert_eq!(items.database_name, Some("order_items".to_string()));
+    assert_eq!(items.find_field("id").unwrap().database_name, None);
+    assert_eq!(
+        items.find_field("unitPrice").unwrap().database_name,
+        Some("unit_price".to_string())
+    );
+}
+
+#[test]
+fn unsupported_columns_and_missing_primary_keys_produce_warnings() {
+    let column = |name: &str, raw: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: raw.to_string(),
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "Place".to_string(),
+                columns: vec![
+                    column("id", "integer", ColumnTypeFamily::Int),
+                    column("location", "point", ColumnTypeFamily::Geometric),
+                    column("photo", "bytea", ColumnTypeFamily::Binary),
+                    column("external_id", "uuid", ColumnTypeFamily::Uuid),
+                ],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
+            },
+            Table {
+                name: "Log".to_string(),
+                columns: vec![column("message", "text", ColumnTypeFamily::String)],
+                indices: vec![],
+                primary_key: None,
+                foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let location = data_model.find_model("Place").unwrap().find_field("location").unwrap();
+    assert_eq!(location.field_type, FieldType::Unsupported("point".to_string()));
+    let external_id = data_model
+        .find_model("Place")
+        .unwrap()
+        .find_field("external_id")
+        .unwrap();
+    assert_eq!(external_id.field_type, FieldType::Base(ScalarType::String));
+
+    let warnings = calculate_warnings(&schema, &[], &TableFilter::default());
+    assert_eq!(
+        warnings,
+        vec![
+            Warning {
+                code: TABLES_WITHOUT_PRIMARY_KEY,
+                message:
+                    "These tables do not have a primary key. Their models need an id field before they can be used."
+                        .to_string(),
+                affected: vec![AffectedObject {
+                    table: "Log".to_string(),
+                    column: None,
+                }],
+            },
+            Warning {
+                code: UNSUPPORTED_COLUMN_TYPES,
+                message: "These columns have types Prisma does not support. Their fields were commented out."
+                    .to_string(),
+                affected: vec![
+                    AffectedObject {
+                        table: "Place".to_string(),
+                        column: Some("location".to_string()),
+                    },
+                    AffectedObject {
+                        table: "Place".to_string(),
+                        column: Some("photo".to_string()),
+                    },
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn views_without_id_produce_warnings_and_keep_their_schema() {
+    let schema = SqlSchema {
+        tables: vec![],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![View {
+            name: "UserName".to_string(),
+            definition: Some("SELECT name FROM users".to_string()),
+            columns: vec![Column {
+                name: "name".to_string(),
+                tpe: ColumnType {
+                    raw: "text".to_string(),
+                    family: ColumnTypeFamily::String,
+                    arity: ColumnArity::Required,
+                },
+                default: None,
+                auto_increment: false,
+                comment: None,
+            }],
+            namespace: Some("reporting".to_string()),
+        }],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let view = data_model.find_model("UserName").unwrap();
+    assert!(view.is_view);
+    assert_eq!(view.schema, Some("reporting".to_string()));
+
+    let warnings = calculate_warnings(&schema, &["reporting".to_string()], &TableFilter::default());
+    assert_eq!(
+        warnings,
+        vec![Warning {
+            code: VIEWS_WITHOUT_ID,
+            message: "These views have no `id` column. Their models need an id field before they can be queried."
+                .to_string(),
+            affected: vec![AffectedObject {
+                table: "UserName".to_string(),
+                column: None,
+            }],
+        }]
+    );
+}
+
+#[test]
+fn foreign_keys_to_excluded_tables_become_scalar_fields_and_produce_warnings() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    // The `Account` table referenced by `account_id` was excluded by a table filter.
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Invoice".to_string(),
+            columns: vec![column("id"), column("account_id")],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![ForeignKey {
+                constraint_name: None,
+                columns: vec!["account_id".to_string()],
+                referenced_table: "Account".to_string(),
+                referenced_schema: None,
+                on_delete_action: ForeignKeyAction::NoAction,
+                referenced_columns: vec!["id".to_string()],
+            }],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let account_id = data_model
+        .find_model("Invoice")
+        .unwrap()
+        .find_field("account_id")
+        .unwrap();
+    assert_eq!(account_id.field_type, FieldType::Base(ScalarType::Int));
+
+    let table_filter = TableFilter {
+        include: vec![],
+        exclude: vec!["Account".to_string()],
+    };
+    let warnings = calculate_warnings(&schema, &[], &table_filter);
+    assert_eq!(
+        warnings,
+        vec![Warning {
+            code: RELATIONS_TO_EXCLUDED_TABLES,
+            message: "These columns reference tables excluded from introspection. They became scalar fields."
+                .to_string(),
+            affected: vec![AffectedObject {
+                table: "Invoice".to_string(),
+                column: Some("account_id".to_string()),
+            }],
+        }]
+    );
+}
+
+#[test]
+fn partial_expression_and_sorted_indexes_are_preserved_when_generating_data_model_from_a_schema() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "text".to_string(),
+            family: ColumnTypeFamily::String,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let index = |name: &str, columns: &[&str]| Index {
+        name: name.to_string(),
+        columns: columns.iter().map(|column| column.to_string()).collect(),
+        tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
+    };
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![column("id"), column("email"), column("name")],
+            indices: vec![
+                Index {
+                    predicate: Some("(deleted_at IS NULL)".to_string()),
+                    ..index("active_email", &["email"])
+                },
+                Index {
+                    tpe: IndexType::Normal,
+                    expression: Some("lower(email)".to_string()),
+                    algorithm: Some(IndexAlgorithm::Hash),
+                    ..index("lower_email", &[])
+                },
+                Index {
+                    sort_orders: vec![SortOrder::Desc],
+                    ..index("name_desc", &["name"])
+                },
+            ],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let user = data_model.find_model("User").unwrap();
+
+    // Unique indexes with options can not be expressed as `@unique`.
+    assert!(!user.find_field("email").unwrap().is_unique);
+    assert!(!user.find_field("name").unwrap().is_unique);
+    assert_eq!(
+        user.indexes,
+        vec![
+            dml::IndexDefinition {
+                name: Some("active_email".to_string()),
+                fields: vec!["email".to_string()],
+                tpe: dml::IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: Some("(deleted_at IS NULL)".to_string()),
+                algorithm: None,
+            },
+            dml::IndexDefinition {
+                name: Some("lower_email".to_string()),
+                fields: vec![],
+                tpe: dml::IndexType::Normal,
+                sort_orders: vec![],
+                expression: Some("lower(email)".to_string()),
+                predicate: None,
+                algorithm: Some(dml::IndexAlgorithm::Hash),
+            },
+            dml::IndexDefinition {
+                name: Some("name_desc".to_string()),
+                fields: vec!["name".to_string()],
+                tpe: dml::IndexType::Unique,
+                sort_orders: vec![dml::SortOrder::Desc],
+                expression: None,
+                predicate: None,
+                algorithm: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn foreign_keys_to_tables_in_other_schemas_do_not_produce_warnings() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    // The `Account` table lives in the `billing` schema, which was not introspected.
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Invoice".to_string(),
+            columns: vec![column("id"), column("account_id")],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![ForeignKey {
+                constraint_name: None,
+                columns: vec!["account_id".to_string()],
+                referenced_table: "Account".to_string(),
+                referenced_schema: Some("billing".to_string()),
+                on_delete_action: ForeignKeyAction::NoAction,
+                referenced_columns: vec!["id".to_string()],
+            }],
+            checks: vec![],
+            namespace: None,
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let table_filter = TableFilter {
+        include: vec![],
+        exclude: vec!["Account".to_string()],
+    };
+
+    assert!(calculate_warnings(&schema, &[], &table_filter).is_empty());
+    assert!(calculate_warnings(&schema, &[], &TableFilter::default()).is_empty());
+}
+
+#[test]
+fn tables_with_the_same_name_in_different_schemas_become_distinct_models() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let table = |name: &str, namespace: Option<&str>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns: foreign_keys
+            .iter()
+            .map(|fk| column(&fk.columns[0]))
+            .chain(std::iter::once(column("id")))
+            .collect(),
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+        }),
+        foreign_keys,
+        checks: vec![],
+        namespace: namespace.map(|namespace| namespace.to_string()),
+        comment: None,
+    };
+    let foreign_key = |referenced_schema: Option<&str>| ForeignKey {
+        constraint_name: None,
+        columns: vec!["user_id".to_string()],
+        referenced_table: "users".to_string(),
+        referenced_schema: referenced_schema.map(|schema| schema.to_string()),
+        on_delete_action: ForeignKeyAction::NoAction,
+        referenced_columns: vec!["id".to_string()],
+    };
+    let schema = SqlSchema {
+        tables: vec![
+            table("users", None, vec![]),
+            table("posts", None, vec![foreign_key(None)]),
+            table("users", Some("billing"), vec![]),
+            table("invoices", Some("billing"), vec![foreign_key(Some("billing"))]),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let users = data_model.find_model("users").unwrap();
+    assert_eq!(users.schema, None);
+    assert_eq!(users.database_name, None);
+
+    let billing_users = data_model.find_model("billing_users").unwrap();
+    assert_eq!(billing_users.schema, Some("billing".to_string()));
+    assert_eq!(billing_users.database_name, Some("users".to_string()));
+
+    let relation_target = |model: &str| match &data_model
+        .find_model(model)
+        .unwrap()
+        .find_field("user_id")
+        .unwrap()
+        .field_type
+    {
+        FieldType::Relation(relation_info) => relation_info.to.clone(),
+        other => panic!("Expected a relation field, found {:?}", other),
+    };
+    assert_eq!(relation_target("posts"), "users");
+    assert_eq!(relation_target("invoices"), "billing_users");
+
+    assert!(users.find_field("posts").is_some());
+    assert!(users.find_field("invoices").is_none());
+    assert!(billing_users.find_field("invoices").is_some());
+}
@@ -0,0 +1,283 @@
+use datamodel::{FieldType, RelationInfo};
+use introspection_connector::{EnumAndValue, ModelAndField};
+use pretty_assertions::assert_eq;
+use sql_introspection_connector::merge_datamodel::merge_datamodels;
+
+fn parse(datamodel: &str) -> datamodel::Datamodel {
+    datamodel::parse_datamodel(datamodel).unwrap()
+}
+
+fn relation_info(field_type: &FieldType) -> &RelationInfo {
+    match field_type {
+        FieldType::Relation(relation_info) => relation_info,
+        _ => panic!("Expected a relation field."),
+    }
+}
+
+#[test]
+fn re_introspection_keeps_renamed_models_and_fields() {
+    let existing = parse(
+        r#"
+        /// Our users.
+        model Account {
+            id       Int    @id
+            fullName String @map("name")
+
+            @@map("User")
+        }
+        "#,
+    );
+    let introspected = parse(
+        r#"
+        model User {
+            id    Int    @id
+            name  String
+            email String
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    let account = merged.find_model("Account").unwrap();
+    assert_eq!(account.database_name.as_deref(), Some("User"));
+    assert_eq!(account.documentation.as_deref(), Some("Our users."));
+
+    let field_names: Vec<&str> = account.fields().map(|field| field.name.as_str()).collect();
+    assert_eq!(field_names, vec!["id", "fullName", "email"]);
+    assert_eq!(
+        account.find_field("fullName").unwrap().database_name.as_deref(),
+        Some("name")
+    );
+
+    assert_eq!(changes.added_models, Vec::<String>::new());
+    assert_eq!(changes.removed_models, Vec::<String>::new());
+    assert_eq!(
+        changes.added_fields,
+        vec![ModelAndField {
+            model: "Account".to_string(),
+            field: "email".to_string(),
+        }]
+    );
+    assert_eq!(changes.removed_fields, vec![]);
+}
+
+#[test]
+fn re_introspection_reports_removed_fields_and_models() {
+    let existing = parse(
+        r#"
+        model User {
+            id       Int    @id
+            nickname String
+        }
+
+        model Legacy {
+            id Int @id
+        }
+        "#,
+    );
+    let introspected = parse(
+        r#"
+        model User {
+            id Int @id
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    assert!(merged.find_model("Legacy").is_none());
+    assert!(merged.find_model("User").unwrap().find_field("nickname").is_none());
+    assert_eq!(changes.removed_models, vec!["Legacy".to_string()]);
+    assert_eq!(
+        changes.removed_fields,
+        vec![ModelAndField {
+            model: "User".to_string(),
+            field: "nickname".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn re_introspection_points_new_relations_to_renamed_models() {
+    let existing = parse(
+        r#"
+        model Author {
+            id    Int       @id
+            posts Article[]
+
+            @@map("User")
+        }
+
+        model Article {
+            id     Int    @id
+            writer Author @map("author")
+
+            @@map("Post")
+        }
+        "#,
+    );
+    let introspected = parse(
+        r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int       @id
+            author   User
+            comments Comment[]
+        }
+
+        model Comment {
+            id   Int  @id
+            post Post
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    let article = merged.find_model("Article").unwrap();
+    let field_names: Vec<&str> = article.fields().map(|field| field.name.as_str()).collect();
+    assert_eq!(field_names, vec!["id", "writer", "comments"]);
+
+    let writer = relation_info(&article.find_field("writer").unwrap().field_type);
+    assert_eq!(writer.to, "Author");
+
+    let comment = merged.find_model("Comment").unwrap();
+    let post = relation_info(&comment.find_field("post").unwrap().field_type);
+    assert_eq!(post.to, "Article");
+
+    assert_eq!(changes.added_models, vec!["Comment".to_string()]);
+    assert_eq!(changes.removed_fields, vec![]);
+}
+
+#[test]
+fn re_introspection_keeps_renamed_enums() {
+    let mut existing = parse(
+        r#"
+        model User {
+            id   Int  @id
+            role Role
+        }
+
+        enum Role {
+            ADMIN
+            USER
+        }
+        "#,
+    );
+    existing.find_enum_mut("Role").unwrap().database_name = Some("user_role".to_string());
+    let introspected = parse(
+        r#"
+        model User {
+            id   Int       @id
+            role user_role
+        }
+
+        enum user_role {
+            ADMIN
+            USER
+            GUEST
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    let role = merged.find_enum("Role").unwrap();
+    assert_eq!(role.values, vec!["ADMIN", "USER", "GUEST"]);
+    assert_eq!(
+        merged
+            .find_model("User")
+            .unwrap()
+            .find_field("role")
+            .unwrap()
+            .field_type,
+        FieldType::Enum("Role".to_string())
+    );
+    assert_eq!(changes.added_fields, vec![]);
+    assert_eq!(changes.removed_fields, vec![]);
+    assert_eq!(
+        changes.added_enum_values,
+        vec![EnumAndValue {
+            r#enum: "Role".to_string(),
+            value: "GUEST".to_string(),
+        }]
+    );
+    assert_eq!(changes.removed_enum_values, vec![]);
+}
+
+#[test]
+fn re_introspection_reports_added_and_removed_enums() {
+    let existing = parse(
+        r#"
+        model User {
+            id   Int  @id
+            role Role
+        }
+
+        enum Role {
+            ADMIN
+            USER
+        }
+        "#,
+    );
+    let introspected = parse(
+        r#"
+        model User {
+            id     Int    @id
+            status Status
+        }
+
+        enum Status {
+            ACTIVE
+            BANNED
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    assert!(merged.find_enum("Role").is_none());
+    assert!(merged.find_enum("Status").is_some());
+    assert_eq!(changes.added_enums, vec!["Status".to_string()]);
+    assert_eq!(changes.removed_enums, vec!["Role".to_string()]);
+}
+
+#[test]
+fn re_introspection_renames_new_models_whose_name_is_taken_by_a_mapped_model() {
+    let existing = parse(
+        r#"
+        model Account {
+            id Int @id
+
+            @@map("User")
+        }
+        "#,
+    );
+    let introspected = parse(
+        r#"
+        model User {
+            id Int @id
+        }
+
+        model Account {
+            id Int @id
+        }
+        "#,
+    );
+
+    let (merged, changes) = merge_datamodels(&existing, &introspected);
+
+    let account = merged.find_model("Account").unwrap();
+    assert_eq!(account.database_name.as_deref(), Some("User"));
+
+    let new_account = merged.find_model("Account_1").unwrap();
+    assert_eq!(new_account.database_name.as_deref(), Some("Account"));
+
+    assert_eq!(changes.added_models, vec!["Account_1".to_string()]);
+    assert_eq!(changes.removed_models, Vec::<String>::new());
+}
@@ -23,7 +23,7 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
                 int     Int 
                 string  String
             }
@@ -74,7 +74,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 authorId String @unique
-                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -100,7 +100,7 @@ async fn introspecting_a_table_with_multi_column_unique_index_must_work(api: &Te
     let dm = r#"
             model User {
                 firstname String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -123,7 +123,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
         .await;
     let dm = r#"
             model User {
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 optionalname String?
                 requiredname String
             }
@@ -132,27 +132,29 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
     custom_assert(&result, dm);
 }
 
-//#[test_one_connector(connector = "postgres")]
-//#[ignore]
-//fn introspecting_a_table_with_datetime_default_values_should_work(api: &TestApi) {
-//    let barrel = api.barrel();
-//    let _setup_schema = barrel.execute(|migration| {
-//        migration.create_table("User", |t| {
-//            t.add_column("id", types::primary());
-//            t.add_column("name", types::text());
-//            t.inject_custom("\"joined\" date DEFAULT CURRENT_DATE")
-//        });
-//    }).await;
-//    let dm = r#"
-//            model User {
-//                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
-//                joined DateTime? @default(now())
-//                name String
-//            }
-//        "#;
-//    let result = dbg!(api.introspect().await);
-//    custom_assert(&result, dm);
-//}
+#[test_one_connector(connector = "postgres")]
+async fn introspecting_a_table_with_datetime_default_values_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+                t.inject_custom("\"joined\" timestamp DEFAULT CURRENT_TIMESTAMP");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model User {
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                joined DateTime? @default(now())
+                name String
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
 
 #[test_one_connector(connector = "postgres")]
 async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
@@ -176,7 +178,7 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -201,7 +203,7 @@ async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi
     let dm = r#"
             model User {
                 a String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 @@index([a], name: "test")
             }
         "#;
@@ -230,7 +232,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
             model User {
                 a String
                 b String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 @@index([a,b], name: "test")
             }
         "#;
@@ -256,12 +258,12 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
 
     let dm = r#"
               model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User
             }
           
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post Post? 
             }
         "#;
@@ -285,13 +287,13 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
         
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
@@ -316,12 +318,12 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"        
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                post Post? 
             }
         "#;
@@ -345,12 +347,12 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"  
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
         "#;
@@ -374,12 +376,12 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
        "#;
@@ -413,12 +415,12 @@ async fn introspecting_a_prisma_many_to_many_relation_should_work(api: &TestApi)
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                users User[] 
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] 
             }
         "#;
@@ -448,7 +450,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: CASCADE)
             }
 
@@ -458,7 +460,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] @relation( onDelete: CASCADE)
             }
         "#;
@@ -489,7 +491,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -500,7 +502,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                postsToUserses PostsToUsers[] 
             }
         "#;
@@ -524,7 +526,7 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
@@ -553,12 +555,12 @@ async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
 
     let dm = r#"  
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
             
             model User {
-               id    Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id    Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[] @relation(onDelete: CASCADE)
             }
         "#;
@@ -586,7 +588,7 @@ async fn introspecting_native_arrays_should_work(api: &TestApi) {
               url = "postgresql://localhost:5432"
             }
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                ints Int []
             }
         "#;
@@ -615,12 +617,12 @@ async fn introspecting_default_values_on_relations_should_be_ignored(api: &TestA
               url = "postgresql://localhost:5432"
             }
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
                user_id User?
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                posts Post[]
             }
         "#;
@@ -649,7 +651,7 @@ async fn introspecting_default_values_on_lists_should_be_ignored(api: &TestApi)
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
                ints    Int []
                ints2   Int []
             }
@@ -680,7 +682,7 @@ async fn introspecting_id_fields_with_foreign_key_should_ignore_the_relation(api
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement()) @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
             }
         "#;
             let result = dbg!(api.introspect().await);
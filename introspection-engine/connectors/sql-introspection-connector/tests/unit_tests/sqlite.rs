@@ -22,7 +22,7 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 int     Int 
                 string  String
             }
@@ -74,7 +74,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 authorId String @unique
-                id Int @id
+                id Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -101,7 +101,7 @@ async fn introspecting_a_table_with_multi_column_unique_index_must_work(api: &Te
     let dm = r#"
             model User {
                 firstname String
-                id Int @id
+                id Int @id @default(autoincrement())
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -125,7 +125,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 
     let dm = r#"
             model User {
-                id Int @id
+                id Int @id @default(autoincrement())
                 optionalname String?
                 requiredname String
             }
@@ -134,28 +134,29 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
     custom_assert(&result, dm);
 }
 
-//#[test_one_connector(connector = "sqlite")]
-//#[ignore]
-//fn introspecting_a_table_with_datetime_default_values_should_work(api: &TestApi) {
-//    let barrel = api.barrel();
-//    let _setup_schema = barrel.execute(|migration| {
-//        migration.create_table("User", |t| {
-//            t.add_column("id", types::primary());
-//            t.add_column("name", types::text());
-//            t.inject_custom("\"joined\" date DEFAULT CURRENT_DATE")
-//        });
-//    });
-//
-//    let dm = r#"
-//            model User {
-//                id Int @id
-//                joined DateTime? @default(now())
-//                name String
-//            }
-//        "#;
-//    let result = dbg!(api.introspect().await);
-//    custom_assert(&result, dm);
-//}
+#[test_one_connector(connector = "sqlite")]
+async fn introspecting_a_table_with_datetime_default_values_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+                t.inject_custom("\"joined\" DATETIME DEFAULT CURRENT_TIMESTAMP");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model User {
+                id Int @id @default(autoincrement())
+                joined DateTime? @default(now())
+                name String
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
 
 #[test_one_connector(connector = "sqlite")]
 async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
@@ -180,7 +181,7 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id Int @id
+                id Int @id @default(autoincrement())
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -205,7 +206,7 @@ async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi
     let dm = r#"
             model User {
                 a String
-                id Int @id
+                id Int @id @default(autoincrement())
                 @@index([a], name: "test")
             }
         "#;
@@ -234,7 +235,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
             model User {
                 a String
                 b String
-                id Int @id
+                id Int @id @default(autoincrement())
                 @@index([a,b], name: "test")
             }
         "#;
@@ -263,12 +264,12 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post Post? 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User
             }
         "#;
@@ -300,13 +301,13 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
@@ -335,12 +336,12 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post Post? 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -368,12 +369,12 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -401,12 +402,12 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User
             }
         "#;
@@ -442,12 +443,12 @@ async fn introspecting_a_prisma_many_to_many_relation_should_work(api: &TestApi)
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                users User[] 
             }
         "#;
@@ -480,12 +481,12 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(onDelete: CASCADE)
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: CASCADE)
             }
             
@@ -523,12 +524,12 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -562,7 +563,7 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id                             Int    @id
+                id                             Int    @id @default(autoincrement())
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
@@ -594,12 +595,12 @@ async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] @relation(onDelete: CASCADE)
             }
             
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -626,12 +627,12 @@ async fn introspecting_id_fields_with_foreign_key_should_ignore_the_relation(api
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
             }
 
             model Post {
                test    String
-               user_id Int @id
+               user_id Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
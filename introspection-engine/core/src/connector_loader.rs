@@ -1,9 +1,19 @@
 use super::error::CoreResult;
 use introspection_connector::IntrospectionConnector;
-use sql_introspection_connector::SqlIntrospectionConnector;
+use sql_introspection_connector::{SqlIntrospectionConnector, TableFilter};
 
-pub async fn load_connector(connection_string: &str) -> CoreResult<Box<dyn IntrospectionConnector>> {
-    let connector: Box<dyn IntrospectionConnector> =
-        Box::new(SqlIntrospectionConnector::new(&connection_string).await?);
+pub async fn load_connector(
+    connection_string: &str,
+    schemas: &[String],
+    table_filter: TableFilter,
+    naming_conventions: bool,
+) -> CoreResult<Box<dyn IntrospectionConnector>> {
+    let connector: Box<dyn IntrospectionConnector> = Box::new(
+        SqlIntrospectionConnector::new(connection_string)
+            .await?
+            .with_schemas(schemas.to_vec())
+            .with_table_filter(table_filter)
+            .with_naming_conventions(naming_conventions),
+    );
     Ok(connector)
 }
@@ -1,9 +1,11 @@
 use crate::connector_loader::load_connector;
 use crate::error::CoreError;
-use introspection_connector::DatabaseMetadata;
+use datamodel::{Configuration, StringFromEnvVar};
+use introspection_connector::{DatabaseMetadata, IntrospectionChanges, Warning};
 use jsonrpc_core::*;
 use jsonrpc_derive::rpc;
 use serde_derive::*;
+use sql_introspection_connector::TableFilter;
 use std::{future::Future as StdFuture, sync::Mutex};
 use tokio::runtime::Runtime;
 use tracing_futures::Instrument;
@@ -17,7 +19,10 @@ pub trait Rpc {
     fn get_database_metadata(&self, url: UrlInput) -> Result<DatabaseMetadata>;
 
     #[rpc(name = "introspect")]
-    fn introspect(&self, url: UrlInput) -> Result<String>;
+    fn introspect(&self, url: UrlInput) -> Result<IntrospectionOutput>;
+
+    #[rpc(name = "reIntrospect")]
+    fn re_introspect(&self, input: ReIntrospectionInput) -> Result<ReIntrospectionOutput>;
 }
 
 pub(crate) struct RpcImpl {
@@ -33,8 +38,18 @@ impl Rpc for RpcImpl {
         self.block_on(Self::get_database_metadata_internal(&url.url))
     }
 
-    fn introspect(&self, url: UrlInput) -> Result<String> {
-        self.block_on(Self::introspect_internal(&url.url).instrument(tracing::info_span!("Introspect", ?url)))
+    fn introspect(&self, url: UrlInput) -> Result<IntrospectionOutput> {
+        self.block_on(
+            Self::introspect_internal(&url.url, &url.schemas, url.table_filter(), url.naming_conventions)
+                .instrument(tracing::info_span!("Introspect", ?url)),
+        )
+    }
+
+    fn re_introspect(&self, input: ReIntrospectionInput) -> Result<ReIntrospectionOutput> {
+        self.block_on(
+            Self::re_introspect_internal(&input.url, &input.schemas, &input.datamodel)
+                .instrument(tracing::info_span!("ReIntrospect", url = ?input.url)),
+        )
     }
 }
 
@@ -45,22 +60,85 @@ impl RpcImpl {
         }
     }
 
-    pub(crate) async fn introspect_internal(connection_string: &str) -> Result<String> {
-        let connector = load_connector(connection_string).await?;
-        let data_model = connector.introspect().await.map_err(CoreError::from)?;
-        Ok(datamodel::render_datamodel_to_string(&data_model).map_err(CoreError::from)?)
+    pub(crate) async fn introspect_internal(
+        connection_string: &str,
+        schemas: &[String],
+        table_filter: TableFilter,
+        naming_conventions: bool,
+    ) -> Result<IntrospectionOutput> {
+        let connector = load_connector(connection_string, schemas, table_filter, naming_conventions).await?;
+        let result = connector.introspect().await.map_err(CoreError::from)?;
+        let config = Self::introspected_config(connection_string, schemas)?;
+
+        Ok(IntrospectionOutput {
+            datamodel: datamodel::render_datamodel_and_config_to_string(&result.datamodel, &config)
+                .map_err(CoreError::from)?,
+            warnings: result.warnings,
+        })
+    }
+
+    pub(crate) async fn re_introspect_internal(
+        connection_string: &str,
+        schemas: &[String],
+        datamodel: &str,
+    ) -> Result<ReIntrospectionOutput> {
+        let existing = datamodel::parse_datamodel_or_pretty_error(datamodel, "schema.prisma")
+            .map_err(CoreError::InvalidDatamodel)?;
+        // The datasources and generators of the existing datamodel are kept as they are.
+        let config = datamodel::parse_configuration(datamodel)
+            .map_err(|errors| CoreError::InvalidDatamodel(format!("{}", errors)))?;
+        // The existing datamodel decides which models are kept and how they are named, so no tables
+        // are filtered out and no naming conventions are applied.
+        let connector = load_connector(connection_string, schemas, TableFilter::default(), false).await?;
+        let result = connector.re_introspect(&existing).await.map_err(CoreError::from)?;
+
+        Ok(ReIntrospectionOutput {
+            datamodel: datamodel::render_datamodel_and_config_to_string(&result.datamodel, &config)
+                .map_err(CoreError::from)?,
+            changes: result.changes,
+            warnings: result.warnings,
+        })
     }
 
     pub(crate) async fn list_databases_internal(connection_string: &str) -> Result<Vec<String>> {
-        let connector = load_connector(connection_string).await?;
+        let connector = load_connector(connection_string, &[], TableFilter::default(), false).await?;
         Ok(connector.list_databases().await.map_err(CoreError::from)?)
     }
 
     pub(crate) async fn get_database_metadata_internal(connection_string: &str) -> Result<DatabaseMetadata> {
-        let connector = load_connector(connection_string).await?;
+        let connector = load_connector(connection_string, &[], TableFilter::default(), false).await?;
         Ok(connector.get_metadata().await.map_err(CoreError::from)?)
     }
 
+    /// The datasource of a freshly introspected datamodel, pointing to the introspected database.
+    fn introspected_config(connection_string: &str, schemas: &[String]) -> Result<Configuration> {
+        let provider = match connection_string.split(':').next() {
+            Some("postgres") | Some("postgresql") => "postgresql",
+            Some("file") | Some("sqlite") => "sqlite",
+            _ => "mysql",
+        };
+        let definition = datamodel::get_builtin_sources()
+            .into_iter()
+            .find(|definition| definition.connector_type() == provider)
+            .expect("No source definition for a supported provider.");
+        let url = StringFromEnvVar {
+            from_env_var: None,
+            value: connection_string.to_owned(),
+        };
+        let mut datasource = definition
+            .create("db", url, &None)
+            .map_err(|err| CoreError::from(datamodel::error::ErrorCollection::from(err)))?;
+
+        if !schemas.is_empty() {
+            datasource.set_schemas(schemas.to_vec());
+        }
+
+        Ok(Configuration {
+            generators: Vec::new(),
+            datasources: vec![datasource],
+        })
+    }
+
     /// Will also catch panics.
     fn block_on<O>(&self, fut: impl StdFuture<Output = Result<O>>) -> Result<O> {
         let mut rt = self.runtime.lock().unwrap();
@@ -74,4 +152,48 @@ impl RpcImpl {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UrlInput {
     pub(crate) url: String,
+    /// Additional database schemas to introspect.
+    #[serde(default)]
+    pub(crate) schemas: Vec<String>,
+    /// Glob patterns of the tables to introspect. Empty means all tables.
+    #[serde(default)]
+    pub(crate) include: Vec<String>,
+    /// Glob patterns of the tables to leave out.
+    #[serde(default)]
+    pub(crate) exclude: Vec<String>,
+    /// Name all models and enums in PascalCase and all fields in camelCase.
+    #[serde(default, rename = "namingConventions")]
+    pub(crate) naming_conventions: bool,
+}
+
+impl UrlInput {
+    fn table_filter(&self) -> TableFilter {
+        TableFilter {
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntrospectionOutput {
+    pub(crate) datamodel: String,
+    pub(crate) warnings: Vec<Warning>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReIntrospectionInput {
+    pub(crate) url: String,
+    /// Additional database schemas to introspect.
+    #[serde(default)]
+    pub(crate) schemas: Vec<String>,
+    /// The datamodel to merge the introspection result into.
+    pub(crate) datamodel: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReIntrospectionOutput {
+    pub(crate) datamodel: String,
+    pub(crate) changes: IntrospectionChanges,
+    pub(crate) warnings: Vec<Warning>,
 }
@@ -1 +1,2 @@
 mod error_tests;
+mod re_introspection_tests;
@@ -10,7 +10,9 @@ async fn unreachable_database_must_return_a_proper_error_on_mysql() {
 
     url.set_port(Some(8787)).unwrap();
 
-    let error = RpcImpl::introspect_internal(url.as_str()).await.unwrap_err();
+    let error = RpcImpl::introspect_internal(url.as_str(), &[], Default::default(), false)
+        .await
+        .unwrap_err();
 
     let port = url.port().unwrap();
     let host = url.host().unwrap().to_string();
@@ -0,0 +1,104 @@
+use crate::rpc::RpcImpl;
+use datamodel::FieldType;
+use pretty_assertions::assert_eq;
+use quaint::prelude::Queryable;
+use serde_json::json;
+use test_setup::*;
+
+async fn setup_postgres(db_name: &str) -> String {
+    let url = postgres_10_url(db_name);
+    let conn = create_postgres_database(&url.parse().unwrap()).await.unwrap();
+    let schema_name = conn.connection_info().schema_name().to_owned();
+
+    let statements = vec![
+        format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", schema_name),
+        format!("CREATE SCHEMA \"{}\"", schema_name),
+        format!("CREATE TABLE \"{}\".\"User\" (id SERIAL PRIMARY KEY)", schema_name),
+        format!(
+            "CREATE TABLE \"{0}\".\"Post\" (id SERIAL PRIMARY KEY, user_id INTEGER NOT NULL REFERENCES \"{0}\".\"User\"(id))",
+            schema_name
+        ),
+    ];
+
+    for statement in statements {
+        conn.execute_raw(&statement, &[]).await.unwrap();
+    }
+
+    url
+}
+
+#[tokio::test]
+async fn introspection_must_render_a_datasource_on_postgres() {
+    let url = setup_postgres("introspection_renders_datasource").await;
+
+    let result = RpcImpl::introspect_internal(&url, &[], Default::default(), false)
+        .await
+        .unwrap();
+
+    let config = datamodel::parse_configuration(&result.datamodel).unwrap();
+    assert_eq!(config.datasources.len(), 1);
+    assert_eq!(config.datasources[0].connector_type(), "postgresql");
+    assert_eq!(config.datasources[0].url().value, url);
+    assert!(datamodel::parse_datamodel(&result.datamodel).is_ok());
+}
+
+#[tokio::test]
+async fn re_introspection_must_keep_the_config_and_manual_changes_on_postgres() {
+    let url = setup_postgres("re_introspection_keeps_config").await;
+    let existing = format!(
+        r#"
+        datasource db {{
+          provider = "postgresql"
+          url      = "{}"
+        }}
+
+        generator client {{
+          provider = "prisma-client-js"
+          output   = "../client"
+        }}
+
+        model Account {{
+          id    Int    @id @default(autoincrement())
+          posts Post[]
+
+          @@map("User")
+        }}
+
+        model Post {{
+          id     Int     @id @default(autoincrement())
+          author Account @map("user_id") @relation(references: [id])
+        }}
+        "#,
+        url
+    );
+
+    let result = RpcImpl::re_introspect_internal(&url, &[], &existing).await.unwrap();
+
+    let config = datamodel::parse_configuration(&result.datamodel).unwrap();
+    assert_eq!(config.datasources.len(), 1);
+    assert_eq!(config.datasources[0].name(), "db");
+    assert_eq!(config.datasources[0].url().value, url);
+    assert_eq!(config.generators.len(), 1);
+
+    let generator = serde_json::to_value(&config.generators[0]).unwrap();
+    assert_eq!(generator["name"], json!("client"));
+    assert_eq!(generator["output"], json!("../client"));
+
+    let datamodel = datamodel::parse_datamodel(&result.datamodel).unwrap();
+    let account = datamodel
+        .find_model("Account")
+        .expect("The renamed model must be kept.");
+    assert_eq!(account.database_name.as_deref(), Some("User"));
+
+    let author = datamodel
+        .find_model("Post")
+        .unwrap()
+        .find_field("author")
+        .expect("The renamed relation field must be kept.");
+    assert_eq!(author.database_name.as_deref(), Some("user_id"));
+
+    match &author.field_type {
+        FieldType::Relation(relation_info) => assert_eq!(relation_info.to, "Account"),
+        other => panic!("Expected a relation field, found {:?}", other),
+    }
+}
@@ -0,0 +1,111 @@
+use super::{declarative_connector::*, ScalarType};
+
+/// The native types of the databases Prisma supports out of the box.
+///
+/// The constructor names are the ones used in `@db.<Type>(args)` directives.
+pub struct BuiltinConnectors {}
+
+impl BuiltinConnectors {
+    pub fn mysql() -> DeclarativeConnector {
+        let type_aliases = vec![
+            TypeAlias::new("Integer", "Int"),
+            TypeAlias::new("Bool", "Boolean"),
+            TypeAlias::new("Numeric", "Decimal"),
+        ];
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("TinyInt", "tinyint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("MediumInt", "mediumint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Int", "int", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Year", "year", ScalarType::Int),
+            FieldTypeConstructor::without_args("Float", "float", ScalarType::Float),
+            FieldTypeConstructor::without_args("Double", "double", ScalarType::Float),
+            FieldTypeConstructor::with_optional_args("Decimal", "decimal", ScalarType::Float, 2),
+            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("TinyText", "tinytext", ScalarType::String),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("MediumText", "mediumtext", ScalarType::String),
+            FieldTypeConstructor::without_args("LongText", "longtext", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("DateTime", "datetime", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+            supports_scalar_lists: false,
+        }
+    }
+
+    pub fn postgres() -> DeclarativeConnector {
+        let type_aliases = vec![
+            TypeAlias::new("Int2", "SmallInt"),
+            TypeAlias::new("Int4", "Integer"),
+            TypeAlias::new("Int8", "BigInt"),
+            TypeAlias::new("Serial2", "SmallSerial"),
+            TypeAlias::new("Serial4", "Serial"),
+            TypeAlias::new("Serial8", "BigSerial"),
+            TypeAlias::new("Float4", "Real"),
+            TypeAlias::new("Float8", "DoublePrecision"),
+            TypeAlias::new("Numeric", "Decimal"),
+            TypeAlias::new("Bool", "Boolean"),
+            TypeAlias::new("Character", "Char"),
+            TypeAlias::new("CharacterVarying", "VarChar"),
+            TypeAlias::new("TimestampWithTimeZone", "Timestamptz"),
+            TypeAlias::new("TimeWithTimeZone", "Timetz"),
+        ];
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallSerial", "smallserial", ScalarType::Int),
+            FieldTypeConstructor::without_args("Serial", "serial", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
+            FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
+            FieldTypeConstructor::with_optional_args("Decimal", "decimal", ScalarType::Float, 2),
+            FieldTypeConstructor::without_args("Money", "money", ScalarType::Float),
+            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
+            FieldTypeConstructor::with_optional_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::with_optional_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("Name", "name", ScalarType::String),
+            FieldTypeConstructor::without_args("Uuid", "uuid", ScalarType::String),
+            FieldTypeConstructor::without_args("Xml", "xml", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timetz", "timetz", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamptz", "timestamptz", ScalarType::DateTime, 1),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+            supports_scalar_lists: true,
+        }
+    }
+
+    pub fn sqlite() -> DeclarativeConnector {
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("Integer", "INTEGER", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "REAL", ScalarType::Float),
+            FieldTypeConstructor::without_args("Numeric", "NUMERIC", ScalarType::Float),
+            FieldTypeConstructor::without_args("Boolean", "BOOLEAN", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("Text", "TEXT", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "DATE", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("DateTime", "DATETIME", ScalarType::DateTime),
+        ];
+
+        DeclarativeConnector {
+            type_aliases: vec![],
+            field_type_constructors,
+            supports_scalar_lists: false,
+        }
+    }
+}
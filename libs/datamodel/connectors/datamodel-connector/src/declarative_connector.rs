@@ -1,4 +1,4 @@
-use super::{Connector, ScalarFieldType, ScalarType};
+use super::{error::ConnectorError, Connector, ScalarFieldType, ScalarType};
 
 #[derive(Debug)]
 pub struct DeclarativeConnector {
@@ -8,18 +8,22 @@ pub struct DeclarativeConnector {
 }
 
 impl Connector for DeclarativeConnector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType> {
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError> {
         match self.get_type_alias(name) {
             Some(alias) => self.calculate_type(&alias.aliased_to, args),
-            None => self.get_field_type_constructor(&name).map(|constructor| {
-                let datasource_type = constructor.datasource_type(&args);
-
-                ScalarFieldType {
-                    name: name.to_string(),
-                    prisma_type: constructor.prisma_type,
-                    datasource_type,
-                }
-            }),
+            None => {
+                let constructor = self
+                    .get_field_type_constructor(name)
+                    .ok_or_else(|| ConnectorError::UnknownType { name: name.to_string() })?;
+                let datasource_type = constructor.datasource_type(&args)?;
+
+                Ok(ScalarFieldType::new(
+                    &constructor.name,
+                    constructor.prisma_type,
+                    &datasource_type,
+                    args,
+                ))
+            }
         }
     }
 
@@ -57,6 +61,8 @@ pub struct FieldTypeConstructor {
     name: String,
     datasource_type: String,
     number_of_args: usize,
+    /// If set, the type can also be used without any arguments.
+    args_are_optional: bool,
     prisma_type: ScalarType,
 }
 
@@ -67,6 +73,7 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args: 0,
+            args_are_optional: false,
         }
     }
 
@@ -81,6 +88,19 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args,
+            args_are_optional: false,
+        }
+    }
+
+    pub fn with_optional_args(
+        name: &str,
+        datasource_type: &str,
+        prisma_type: ScalarType,
+        number_of_args: usize,
+    ) -> FieldTypeConstructor {
+        FieldTypeConstructor {
+            args_are_optional: true,
+            ..FieldTypeConstructor::with_args(name, datasource_type, prisma_type, number_of_args)
         }
     }
 
@@ -92,19 +112,22 @@ impl FieldTypeConstructor {
         self.number_of_args
     }
 
-    pub fn datasource_type(&self, args: &Vec<i32>) -> String {
-        if self.number_of_args != args.len() {
-            panic!(
-                "Did not provide the required number of arguments. {} were required, but were {} provided.",
-                self.number_of_args,
-                args.len()
-            )
+    pub fn datasource_type(&self, args: &[i32]) -> Result<String, ConnectorError> {
+        let args_are_omitted = self.args_are_optional && args.is_empty();
+
+        if self.number_of_args != args.len() && !args_are_omitted {
+            return Err(ConnectorError::ArgumentCountMismatch {
+                name: self.name.clone(),
+                required_count: self.number_of_args,
+                given_count: args.len(),
+            });
         }
+
         if args.is_empty() {
-            self.datasource_type.to_string()
+            Ok(self.datasource_type.to_string())
         } else {
             let args_as_strings: Vec<String> = args.iter().map(|x| x.to_string()).collect();
-            self.datasource_type.to_string() + "(" + &args_as_strings.join(",") + ")"
+            Ok(self.datasource_type.to_string() + "(" + &args_as_strings.join(",") + ")")
         }
     }
 
@@ -1,2 +1,33 @@
-#[allow(unused)]
-enum ConnectorError {}
+use std::fmt;
+
+/// Errors raised by a connector when resolving a native database type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectorError {
+    /// The connector does not know a type of this name.
+    UnknownType { name: String },
+    /// The type constructor was given the wrong number of arguments.
+    ArgumentCountMismatch {
+        name: String,
+        required_count: usize,
+        given_count: usize,
+    },
+}
+
+impl fmt::Display for ConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConnectorError::UnknownType { name } => {
+                write!(f, "Native type {} is not supported by this connector.", name)
+            }
+            ConnectorError::ArgumentCountMismatch {
+                name,
+                required_count,
+                given_count,
+            } => write!(
+                f,
+                "Native type {} expects {} arguments, but received {}.",
+                name, required_count, given_count
+            ),
+        }
+    }
+}
@@ -3,14 +3,17 @@ use crate::scalars::ScalarType;
 pub mod error;
 pub mod scalars;
 
+mod builtin_connectors;
 mod declarative_connector;
 mod example_connector;
 
+pub use builtin_connectors::BuiltinConnectors;
 pub use declarative_connector::DeclarativeConnector;
 pub use example_connector::ExampleConnector;
 
 pub trait Connector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType>;
+    /// Resolves a native type of the given name, constructed with the given arguments.
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, error::ConnectorError>;
 
     fn supports_scalar_lists(&self) -> bool;
 }
@@ -20,21 +23,34 @@ pub struct ScalarFieldType {
     name: String,
     prisma_type: scalars::ScalarType,
     datasource_type: String,
+    args: Vec<i32>,
 }
 
 impl ScalarFieldType {
-    pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
+    pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str, args: Vec<i32>) -> Self {
         ScalarFieldType {
             name: name.to_string(),
             prisma_type,
             datasource_type: datasource_type.to_string(),
+            args,
         }
     }
 
+    /// The name of the native type, as used in the datamodel.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments the native type was constructed with.
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+
     pub fn prisma_type(&self) -> scalars::ScalarType {
         self.prisma_type
     }
 
+    /// The exact type in the database, including its arguments.
     pub fn datasource_type(&self) -> &str {
         &self.datasource_type
     }
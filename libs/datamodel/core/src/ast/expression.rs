@@ -22,22 +22,22 @@ pub enum Expression {
 }
 
 impl Expression {
-    pub fn with_lifted_span(&self, offset: usize) -> Expression {
+    pub fn with_lifted_span(&self, offset: usize, file_id: usize) -> Expression {
         match self {
-            Expression::NumericValue(v, s) => Expression::NumericValue(v.clone(), lift_span(&s, offset)),
-            Expression::BooleanValue(v, s) => Expression::BooleanValue(v.clone(), lift_span(&s, offset)),
-            Expression::StringValue(v, s) => Expression::StringValue(v.clone(), lift_span(&s, offset)),
-            Expression::ConstantValue(v, s) => Expression::ConstantValue(v.clone(), lift_span(&s, offset)),
+            Expression::NumericValue(v, s) => Expression::NumericValue(v.clone(), lift_span(s, offset, file_id)),
+            Expression::BooleanValue(v, s) => Expression::BooleanValue(v.clone(), lift_span(s, offset, file_id)),
+            Expression::StringValue(v, s) => Expression::StringValue(v.clone(), lift_span(s, offset, file_id)),
+            Expression::ConstantValue(v, s) => Expression::ConstantValue(v.clone(), lift_span(s, offset, file_id)),
             Expression::Function(v, a, s) => Expression::Function(
                 v.clone(),
-                a.iter().map(|elem| elem.with_lifted_span(offset)).collect(),
-                lift_span(&s, offset),
+                a.iter().map(|elem| elem.with_lifted_span(offset, file_id)).collect(),
+                lift_span(s, offset, file_id),
             ),
             Expression::Array(v, s) => Expression::Array(
-                v.iter().map(|elem| elem.with_lifted_span(offset)).collect(),
-                lift_span(&s, offset),
+                v.iter().map(|elem| elem.with_lifted_span(offset, file_id)).collect(),
+                lift_span(s, offset, file_id),
             ),
-            Expression::Any(v, s) => Expression::Any(v.clone(), lift_span(&s, offset)),
+            Expression::Any(v, s) => Expression::Any(v.clone(), lift_span(s, offset, file_id)),
         }
     }
 
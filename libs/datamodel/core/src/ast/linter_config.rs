@@ -0,0 +1,32 @@
+use super::*;
+
+/// A Linter block declaration, configuring the rules of the lint pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinterConfig {
+    /// Name of this linter configuration.
+    pub name: Identifier,
+    /// The severity of each configured rule.
+    pub properties: Vec<Argument>,
+    /// The comments for this linter block.
+    pub documentation: Option<Comment>,
+    /// The location of this linter block in the text representation.
+    pub span: Span,
+}
+
+impl WithIdentifier for LinterConfig {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for LinterConfig {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl WithDocumentation for LinterConfig {
+    fn documentation(&self) -> &Option<Comment> {
+        &self.documentation
+    }
+}
@@ -6,8 +6,10 @@ mod expression;
 mod field;
 mod generator_config;
 mod identifier;
+mod linter_config;
 mod model;
 mod source_config;
+mod source_map;
 mod span;
 mod top;
 mod traits;
@@ -23,9 +25,11 @@ pub use expression::*;
 pub use field::*;
 pub use generator_config::*;
 pub use identifier::*;
+pub use linter_config::*;
 pub use model::*;
 pub use r#enum::*;
 pub use source_config::*;
+pub use source_map::*;
 pub use span::*;
 pub use top::*;
 pub use traits::*;
@@ -43,7 +47,7 @@ pub use traits::*;
 /// Schema = Datamodel + Generators + Datasources
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAst {
-    /// All models, enums, datasources, generators or type aliases
+    /// All models, enums, datasources, generators, linter configurations or type aliases
     pub tops: Vec<Top>,
 }
 
@@ -158,4 +162,14 @@ impl SchemaAst {
             })
             .collect()
     }
+
+    pub fn linters(&self) -> Vec<&LinterConfig> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::Linter(x) => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
 }
@@ -11,6 +11,10 @@ pub struct Model {
     pub directives: Vec<Directive>,
     /// The documentation for this model.
     pub documentation: Option<Comment>,
+    /// Indicates if this model was declared as a database view (`view` keyword).
+    pub is_view: bool,
+    /// Indicates if this model was declared as a composite type (`type` keyword).
+    pub is_composite_type: bool,
     /// The location of this model in the text representation.
     pub span: Span,
 }
@@ -224,6 +224,7 @@ fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, Datamod
             directives,
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
+            is_commented_out: false,
         }),
         _ => panic!(
             "Encountered impossible field declaration during parsing: {:?}",
@@ -238,14 +239,13 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comments: Vec<String> = Vec::new();
+    let mut is_view = false;
+    let mut is_composite_type = false;
 
     match_children! { token, current,
         Rule::MODEL_KEYWORD => { },
-        Rule::TYPE_KEYWORD => { errors.push(
-            DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()))
-        ) },
+        Rule::VIEW_KEYWORD => is_view = true,
+        Rule::TYPE_KEYWORD => is_composite_type = true,
         Rule::identifier => name = Some(current.to_id()),
         Rule::directive => directives.push(parse_directive(&current)),
         Rule::field_declaration => {
@@ -266,6 +266,8 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
             fields,
             directives,
             documentation: doc_comments_to_string(&comments),
+            is_view,
+            is_composite_type,
             span: Span::from_pest(token.as_span()),
         }),
         _ => panic!(
@@ -385,6 +387,34 @@ fn parse_generator(token: &pest::iterators::Pair<'_, Rule>) -> GeneratorConfig {
     }
 }
 
+// Linter parsing
+fn parse_linter(token: &pest::iterators::Pair<'_, Rule>) -> LinterConfig {
+    let mut name: Option<Identifier> = None;
+    let mut properties: Vec<Argument> = vec![];
+    let mut comments: Vec<String> = Vec::new();
+
+    match_children! { token, current,
+        Rule::LINTER_KEYWORD => { },
+        Rule::identifier => name = Some(current.to_id()),
+        Rule::key_value => properties.push(parse_key_value(&current)),
+        Rule::doc_comment => comments.push(parse_doc_comment(&current)),
+        _ => unreachable!("Encountered impossible linter declaration during parsing: {:?}", current.tokens())
+    };
+
+    match name {
+        Some(name) => LinterConfig {
+            name,
+            properties,
+            documentation: doc_comments_to_string(&comments),
+            span: Span::from_pest(token.as_span()),
+        },
+        _ => panic!(
+            "Encountered impossible linter declaration during parsing, name is missing: {:?}",
+            token.as_str()
+        ),
+    }
+}
+
 // Custom type parsing
 fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
     let mut name: Option<Identifier> = None;
@@ -415,6 +445,7 @@ fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
             directives,
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
+            is_commented_out: false,
         },
         _ => panic!(
             "Encountered impossible custom type declaration during parsing: {:?}",
@@ -427,6 +458,16 @@ fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
 
 /// Parses a Prisma V2 datamodel document into an internal AST representation.
 pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
+    let (ast, errors) = parse_with_recovery(datamodel_string);
+    errors.ok()?;
+
+    Ok(ast)
+}
+
+/// Parses a datamodel string, skipping declarations that are malformed.
+///
+/// Returns the AST of all well-formed declarations, together with the errors of all malformed ones.
+pub fn parse_with_recovery(datamodel_string: &str) -> (SchemaAst, ErrorCollection) {
     let mut errors = ErrorCollection::new();
     let datamodel_result = PrismaDatamodelParser::parse(Rule::datamodel, datamodel_string);
 
@@ -443,32 +484,209 @@ pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
                 Rule::enum_declaration => models.push(Top::Enum(parse_enum(&current))),
                 Rule::source_block => models.push(Top::Source(parse_source(&current))),
                 Rule::generator_block => models.push(Top::Generator(parse_generator(&current))),
+                Rule::linter_block => models.push(Top::Linter(parse_linter(&current))),
                 Rule::type_declaration => models.push(Top::Type(parse_type(&current))),
+                Rule::invalid_declaration => errors.push(parse_invalid_declaration(&current)),
                 Rule::EOI => {},
                 _ => panic!("Encountered impossible datamodel declaration during parsing: {:?}", current.tokens())
             }
 
-            errors.ok()?;
-
-            Ok(SchemaAst { tops: models })
+            (SchemaAst { tops: models }, errors)
         }
         Err(err) => {
-            let location = match err.location {
-                pest::error::InputLocation::Pos(pos) => Span::new(pos, pos),
-                pest::error::InputLocation::Span((from, to)) => Span::new(from, to),
-            };
+            errors.push(to_parser_error(err, 0));
+            (SchemaAst { tops: vec![] }, errors)
+        }
+    }
+}
 
-            let expected = match err.variant {
-                pest::error::ErrorVariant::ParsingError { positives, .. } => get_expected_from_error(&positives),
-                _ => panic!("Could not construct parsing error. This should never happend."),
-            };
+/// Parses each file of a datamodel split across several files on its own, and joins their declarations
+/// into a single AST. All spans in the AST and the errors are moved to the file they are in.
+pub fn parse_files_with_recovery(files: &SourceMap) -> (SchemaAst, ErrorCollection) {
+    let mut tops = Vec::new();
+    let mut errors = ErrorCollection::new();
+
+    for (file_id, file) in files.files().iter().enumerate() {
+        let (ast, file_errors) = parse_with_recovery(&file.text);
+
+        for mut top in ast.tops {
+            move_top_to_file(&mut top, file_id);
+            tops.push(top);
+        }
+
+        for error in file_errors.errors {
+            errors.push(error.in_file(file_id));
+        }
+    }
+
+    (SchemaAst { tops }, errors)
+}
+
+/// Parses a datamodel split across several files into a single AST. See `parse_files_with_recovery`.
+pub fn parse_files(files: &SourceMap) -> Result<SchemaAst, ErrorCollection> {
+    let (ast, errors) = parse_files_with_recovery(files);
+    errors.ok()?;
+
+    Ok(ast)
+}
 
-            errors.push(DatamodelError::new_parser_error(&expected, location));
-            Err(errors)
+fn move_top_to_file(top: &mut Top, file_id: usize) {
+    match top {
+        Top::Model(model) => {
+            move_identifier_to_file(&mut model.name, file_id);
+            for field in model.fields.iter_mut() {
+                move_field_to_file(field, file_id);
+            }
+            move_directives_to_file(&mut model.directives, file_id);
+            model.span = model.span.in_file(file_id);
+        }
+        Top::Enum(r#enum) => {
+            move_identifier_to_file(&mut r#enum.name, file_id);
+            for value in r#enum.values.iter_mut() {
+                value.span = value.span.in_file(file_id);
+            }
+            move_directives_to_file(&mut r#enum.directives, file_id);
+            r#enum.span = r#enum.span.in_file(file_id);
         }
+        Top::Source(source) => {
+            move_identifier_to_file(&mut source.name, file_id);
+            move_arguments_to_file(&mut source.properties, file_id);
+            source.span = source.span.in_file(file_id);
+        }
+        Top::Generator(generator) => {
+            move_identifier_to_file(&mut generator.name, file_id);
+            move_arguments_to_file(&mut generator.properties, file_id);
+            generator.span = generator.span.in_file(file_id);
+        }
+        Top::Linter(linter) => {
+            move_identifier_to_file(&mut linter.name, file_id);
+            move_arguments_to_file(&mut linter.properties, file_id);
+            linter.span = linter.span.in_file(file_id);
+        }
+        Top::Type(custom_type) => move_field_to_file(custom_type, file_id),
+    }
+}
+
+fn move_field_to_file(field: &mut Field, file_id: usize) {
+    move_identifier_to_file(&mut field.field_type, file_id);
+    move_identifier_to_file(&mut field.name, file_id);
+    if let Some(default_value) = &field.default_value {
+        field.default_value = Some(default_value.with_lifted_span(0, file_id));
+    }
+    move_directives_to_file(&mut field.directives, file_id);
+    field.span = field.span.in_file(file_id);
+}
+
+fn move_directives_to_file(directives: &mut [Directive], file_id: usize) {
+    for directive in directives.iter_mut() {
+        move_identifier_to_file(&mut directive.name, file_id);
+        move_arguments_to_file(&mut directive.arguments, file_id);
+        directive.span = directive.span.in_file(file_id);
+    }
+}
+
+fn move_arguments_to_file(arguments: &mut [Argument], file_id: usize) {
+    for argument in arguments.iter_mut() {
+        move_identifier_to_file(&mut argument.name, file_id);
+        argument.value = argument.value.with_lifted_span(0, file_id);
+        argument.span = argument.span.in_file(file_id);
+    }
+}
+
+fn move_identifier_to_file(identifier: &mut Identifier, file_id: usize) {
+    identifier.span = identifier.span.in_file(file_id);
+}
+
+/// Returns the names of the models and enums among the malformed declarations of a datamodel string.
+/// These types are declared, even though their declarations could not be parsed.
+pub fn malformed_declaration_names(datamodel_string: &str) -> Vec<String> {
+    let datamodel = match PrismaDatamodelParser::parse(Rule::datamodel, datamodel_string) {
+        Ok(mut datamodel_wrapped) => datamodel_wrapped.next().unwrap(),
+        Err(_) => return Vec::new(),
+    };
+
+    datamodel
+        .into_inner()
+        .filter(|current| current.as_rule() == Rule::invalid_declaration)
+        .filter_map(|current| {
+            let mut words = declaration_head(current.as_str());
+            let name = match words.next() {
+                Some("model") | Some("view") | Some("type") | Some("enum") => words.next()?,
+                _ => return None,
+            };
+            let name = name.split('{').next().unwrap_or(name);
+            let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+            if is_identifier {
+                Some(name.to_owned())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// The words of the first line of a declaration that is not a documentation comment.
+fn declaration_head(declaration: &str) -> std::str::SplitWhitespace<'_> {
+    declaration
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.starts_with("///"))
+        .unwrap_or("")
+        .split_whitespace()
+}
+
+/// Parses a malformed declaration again on its own, with the rule it was meant to match,
+/// to report what is wrong with it.
+fn parse_invalid_declaration(token: &pest::iterators::Pair<'_, Rule>) -> DatamodelError {
+    let declaration = token.as_str();
+    let start = token.as_span().start();
+    let keyword = declaration_head(declaration).next();
+
+    let rule = match keyword {
+        Some("model") | Some("view") => Some(Rule::model_declaration),
+        Some("type") if declaration.contains('{') => Some(Rule::model_declaration),
+        Some("type") => Some(Rule::type_declaration),
+        Some("enum") => Some(Rule::enum_declaration),
+        Some("datasource") => Some(Rule::source_block),
+        Some("generator") => Some(Rule::generator_block),
+        Some("linter") => Some(Rule::linter_block),
+        _ => None,
+    };
+
+    match rule.and_then(|rule| PrismaDatamodelParser::parse(rule, declaration).err()) {
+        Some(err) => to_parser_error(err, start),
+        None => DatamodelError::new_parser_error(
+            &get_expected_from_error(&[
+                Rule::EOI,
+                Rule::type_declaration,
+                Rule::model_declaration,
+                Rule::enum_declaration,
+                Rule::source_block,
+                Rule::generator_block,
+                Rule::linter_block,
+            ]),
+            Span::new(start, start),
+        ),
     }
 }
 
+/// Converts a pest error into a parser error. The offset is added to the error location.
+fn to_parser_error(err: pest::error::Error<Rule>, offset: usize) -> DatamodelError {
+    let location = match err.location {
+        pest::error::InputLocation::Pos(pos) => Span::new(offset + pos, offset + pos),
+        pest::error::InputLocation::Span((from, to)) => Span::new(offset + from, offset + to),
+    };
+
+    let expected = match err.variant {
+        pest::error::ErrorVariant::ParsingError { positives, .. } => get_expected_from_error(&positives),
+        _ => panic!("Could not construct parsing error. This should never happend."),
+    };
+
+    DatamodelError::new_parser_error(&expected, location)
+}
+
 pub fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
     positives
         .iter()
@@ -483,6 +701,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
+        Rule::linter_block => "linter definition",
         Rule::enum_field_declaration => "enum field declaration",
         Rule::EOI => "end of input",
         Rule::identifier => "alphanumeric identifier",
@@ -515,8 +734,10 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::VIEW_KEYWORD => "\"view\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
+        Rule::LINTER_KEYWORD => "\"linter\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
         Rule::INTERPOLATION_START => "string interpolation start",
         Rule::INTERPOLATION_END => "string interpolation end",
@@ -524,6 +745,10 @@ fn rule_to_string(rule: Rule) -> &'static str {
         // Those are top level things and will never surface.
         Rule::datamodel => "datamodel declaration",
         Rule::string_interpolated => "string interpolated",
+        Rule::invalid_declaration => "",
+        Rule::declaration_keyword => "",
+        Rule::invalid_declaration_string => "",
+        Rule::invalid_declaration_comment => "",
 
         // Legacy stuff should never be suggested
         Rule::LEGACY_COLON => "",
@@ -21,7 +21,7 @@ fn newlines(target: &mut dyn LineWriteable, text: &str, _identifier: &str) {
 }
 
 fn comment(target: &mut dyn LineWriteable, comment_text: &str) {
-    let trimmed = &comment_text[0..comment_text.len() - 1]; // slice away line break.
+    let trimmed = comment_text.trim_end(); // slice away line break, which is missing at the end of the file.
 
     if !target.line_empty() {
         // Prefix with whitespace seperator.
@@ -32,14 +32,49 @@ fn comment(target: &mut dyn LineWriteable, comment_text: &str) {
     target.end_line();
 }
 
+/// Comments nested in directives or expressions can not be kept in place, as they end the line.
+/// They are written at the end of the line instead.
+fn nested_comments(target: &mut dyn LineWriteable, token: &Token) {
+    for current in token.clone().into_inner().flatten() {
+        if let Rule::COMMENT = current.as_rule() {
+            target.write(&format!(" {}", current.as_str().trim_end()));
+        }
+    }
+}
+
+/// A `@@` directive of a model or enum, collected in canonical mode to be written at the end of the block.
+struct BlockDirective<'a> {
+    token: Token<'a>,
+    /// Comments on the lines right above the directive.
+    leading_comments: Vec<&'a str>,
+    comment: Option<&'a str>,
+}
+
+impl<'a> BlockDirective<'a> {
+    fn name(&self) -> &'a str {
+        self.token.clone().into_inner().next().unwrap().as_str()
+    }
+}
+
 impl Reformatter {
     pub fn reformat_to(input: &str, output: &mut dyn std::io::Write, ident_width: usize) {
+        Self::reformat_with_mode(input, output, ident_width, false)
+    }
+
+    /// Reformats in canonical mode: On top of the regular formatting, the `@@` directives of models and enums
+    /// are sorted by name and aligned in a group at the end of their block. Two datamodels that only differ
+    /// in the order of their block directives thus produce the same output.
+    pub fn reformat_canonical_to(input: &str, output: &mut dyn std::io::Write, ident_width: usize) {
+        Self::reformat_with_mode(input, output, ident_width, true)
+    }
+
+    fn reformat_with_mode(input: &str, output: &mut dyn std::io::Write, ident_width: usize, canonical: bool) {
         let mut ast = PrismaDatamodelParser::parse(Rule::datamodel, input).unwrap(); // TODO: Handle error.
         let mut top_formatter = RefCell::new(Renderer::new(output, ident_width));
-        Self::reformat_top(&mut top_formatter, &ast.next().unwrap());
+        Self::reformat_top(&mut top_formatter, &ast.next().unwrap(), canonical);
     }
 
-    fn reformat_top(target: &mut RefCell<Renderer>, token: &Token) {
+    fn reformat_top(target: &mut RefCell<Renderer>, token: &Token, canonical: bool) {
         let mut types_table = TableFormat::new();
         let mut types_mode = false;
 
@@ -83,16 +118,22 @@ impl Reformatter {
                         comment(target.get_mut(), current.as_str());
                     }
                 }
-                Rule::model_declaration => Self::reformat_model(target, &current),
-                Rule::enum_declaration => Self::reformat_enum(target, &current),
+                Rule::model_declaration => Self::reformat_model(target, &current, canonical),
+                Rule::enum_declaration => Self::reformat_enum(target, &current, canonical),
                 Rule::source_block => Self::reformat_config_block(target.get_mut(), &current),
                 Rule::generator_block => Self::reformat_config_block(target.get_mut(), &current),
+                Rule::linter_block => Self::reformat_config_block(target.get_mut(), &current),
                 Rule::type_declaration => {
                     if !types_mode {
                         panic!("Renderer not in type mode.");
                     }
                     Self::reformat_type_declaration(&mut types_table, &current);
                 }
+                // Malformed declarations are kept as they are.
+                Rule::invalid_declaration => {
+                    target.get_mut().write(current.as_str().trim_end());
+                    target.get_mut().end_line();
+                }
                 Rule::EOI => {}
                 _ => unreachable!(
                     "Encounterd impossible datamodel declaration during parsing: {:?}",
@@ -117,6 +158,10 @@ impl Reformatter {
                     skip_whitespace = true;
                     target.write("datasource ");
                 }
+                Rule::LINTER_KEYWORD => {
+                    skip_whitespace = true;
+                    target.write("linter ");
+                }
                 Rule::BLOCK_OPEN => {
                     skip_whitespace = false;
                     target.write(" {");
@@ -141,6 +186,11 @@ impl Reformatter {
                         newlines(&mut table, current.as_str(), "m");
                     }
                 }
+                // Trailing comments stay on the line of their key/value.
+                Rule::COMMENT if !table.line_empty() => {
+                    table.continue_line();
+                    comment(&mut table, current.as_str());
+                }
                 Rule::COMMENT => comment(&mut table.interleave_writer(), current.as_str()),
                 _ => unreachable!(
                     "Encounterd impossible source declaration during parsing: {:?}",
@@ -166,26 +216,53 @@ impl Reformatter {
                 Rule::expression => {
                     Self::reformat_expression(&mut target.column_locked_writer_for(2), &current);
                 }
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside config key/value not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!(
                     "Encounterd impossible source property declaration during parsing: {:?}",
                     current.tokens()
                 ),
             }
         }
+
+        nested_comments(target, token);
     }
 
-    fn reformat_model(target: &mut RefCell<Renderer>, token: &Token) {
+    fn reformat_model(target: &mut RefCell<Renderer>, token: &Token, canonical: bool) {
         let mut table = RefCell::new(TableFormat::new());
         // Switch to skip whitespace in 'model xxxx {'
         let mut skip_whitespace = false;
+        let mut keyword = "model";
+        let mut block_directives = Vec::new();
+        // Switch to drop the line break of a directive that is moved to the end of the block.
+        let mut directive_moved = false;
+        // Switch to keep a trailing comment on the line of its directive.
+        let mut directive_line_open = false;
+        // Canonical mode: Comments on their own line, kept back until it is known whether a directive follows.
+        let mut pending_comments = Vec::new();
+        let mut has_fields = false;
 
         for current in token.clone().into_inner() {
+            match current.as_rule() {
+                Rule::COMMENT | Rule::WHITESPACE | Rule::directive => {}
+                _ => {
+                    for text in pending_comments.drain(..) {
+                        comment(&mut table.get_mut().interleave_writer(), text);
+                    }
+                }
+            }
+
             match current.as_rule() {
                 Rule::MODEL_KEYWORD => {
                     skip_whitespace = true;
                 }
+                Rule::VIEW_KEYWORD => {
+                    skip_whitespace = true;
+                    keyword = "view";
+                }
+                Rule::TYPE_KEYWORD => {
+                    skip_whitespace = true;
+                    keyword = "type";
+                }
                 Rule::BLOCK_OPEN => {
                     skip_whitespace = false;
                 }
@@ -193,22 +270,44 @@ impl Reformatter {
 
                 Rule::identifier => {
                     // Begin.
-                    target.get_mut().write(&format!("model {} {{", current.as_str()));
+                    target.get_mut().write(&format!("{} {} {{", keyword, current.as_str()));
                     target.get_mut().maybe_end_line();
                     target.get_mut().indent_up();
                 }
+                Rule::directive if canonical => {
+                    block_directives.push(BlockDirective {
+                        token: current.clone(),
+                        leading_comments: std::mem::take(&mut pending_comments),
+                        comment: None,
+                    });
+                    directive_moved = true;
+                }
                 Rule::directive => {
                     // Directives reset the table.
                     table.get_mut().render(target.get_mut());
                     table = RefCell::new(TableFormat::new());
                     Self::reformat_directive(target.get_mut(), &current, "@@");
+                    nested_comments(target.get_mut(), &current);
+                    directive_line_open = true;
+                }
+                Rule::field_declaration => {
+                    has_fields = true;
+                    Self::reformat_field(&mut table, &current)
                 }
-                Rule::field_declaration => Self::reformat_field(&mut table, &current),
                 // Doc comments are to be placed OUTSIDE of table block.
                 Rule::doc_comment => comment(target.get_mut(), current.as_str()),
                 Rule::WHITESPACE => {
                     if !skip_whitespace {
-                        let lines = count_lines(current.as_str());
+                        let text = Self::whitespace_after_directive(current.as_str(), &mut directive_moved);
+                        let lines = count_lines(text);
+                        directive_line_open = directive_line_open && lines == 0;
+
+                        // Comments separated from the next directive by an empty line stay in place.
+                        if lines > 0 {
+                            for text in pending_comments.drain(..) {
+                                comment(&mut table.get_mut().interleave_writer(), text);
+                            }
+                        }
 
                         if lines > 1 || (lines == 1 && table.get_mut().line_empty()) {
                             // Reset the table layout on more than one newline.
@@ -216,9 +315,24 @@ impl Reformatter {
                             table = RefCell::new(TableFormat::new());
                         }
 
-                        newlines(table.get_mut(), current.as_str(), "m");
+                        newlines(table.get_mut(), text, "m");
                     }
                 }
+                // A comment on the line of a moved directive moves along with it.
+                Rule::COMMENT if directive_moved => {
+                    directive_moved = false;
+                    block_directives.last_mut().unwrap().comment = Some(current.as_str());
+                }
+                // Trailing comments stay on the line of their field or directive.
+                Rule::COMMENT if directive_line_open => {
+                    directive_line_open = false;
+                    comment(target.get_mut(), current.as_str());
+                }
+                Rule::COMMENT if !table.get_mut().line_empty() => {
+                    table.get_mut().continue_line();
+                    comment(table.get_mut(), current.as_str());
+                }
+                Rule::COMMENT if canonical => pending_comments.push(current.as_str()),
                 Rule::COMMENT => comment(&mut table.get_mut().interleave_writer(), current.as_str()),
                 _ => unreachable!(
                     "Encounterd impossible model declaration during parsing: {:?}",
@@ -229,6 +343,7 @@ impl Reformatter {
 
         // End.
         table.get_mut().render(target.get_mut());
+        Self::reformat_block_directives(target.get_mut(), block_directives, has_fields);
         target.get_mut().indent_down();
         target.get_mut().write("}");
         target.get_mut().maybe_end_line();
@@ -236,12 +351,29 @@ impl Reformatter {
     }
 
     // TODO: This is very similar to model reformating.
-    fn reformat_enum(target: &mut RefCell<Renderer>, token: &Token) {
+    fn reformat_enum(target: &mut RefCell<Renderer>, token: &Token, canonical: bool) {
         let mut table = TableFormat::new();
         // Switch to skip whitespace in 'enum xxxx {'
         let mut skip_whitespace = false;
+        let mut block_directives = Vec::new();
+        // Switch to drop the line break of a directive that is moved to the end of the block.
+        let mut directive_moved = false;
+        // Switch to keep a trailing comment on the line of its directive.
+        let mut directive_line_open = false;
+        // Canonical mode: Comments on their own line, kept back until it is known whether a directive follows.
+        let mut pending_comments = Vec::new();
+        let mut has_values = false;
 
         for current in token.clone().into_inner() {
+            match current.as_rule() {
+                Rule::COMMENT | Rule::WHITESPACE | Rule::directive => {}
+                _ => {
+                    for text in pending_comments.drain(..) {
+                        comment(&mut table.interleave_writer(), text);
+                    }
+                }
+            }
+
             match current.as_rule() {
                 Rule::ENUM_KEYWORD => {
                     skip_whitespace = true;
@@ -257,17 +389,39 @@ impl Reformatter {
                     target.get_mut().maybe_end_line();
                     target.get_mut().indent_up();
                 }
+                Rule::directive if canonical => {
+                    block_directives.push(BlockDirective {
+                        token: current.clone(),
+                        leading_comments: std::mem::take(&mut pending_comments),
+                        comment: None,
+                    });
+                    directive_moved = true;
+                }
                 Rule::directive => {
                     table.render(target.get_mut());
                     table = TableFormat::new();
                     Self::reformat_directive(target.get_mut(), &current, "@@");
+                    nested_comments(target.get_mut(), &current);
+                    directive_line_open = true;
+                }
+                Rule::enum_field_declaration => {
+                    has_values = true;
+                    table.write(current.as_str())
                 }
-                Rule::enum_field_declaration => table.write(current.as_str()),
                 // Doc comments are to be placed OUTSIDE of table block.
                 Rule::doc_comment => comment(target.get_mut(), current.as_str()),
                 Rule::WHITESPACE => {
                     if !skip_whitespace {
-                        let lines = count_lines(current.as_str());
+                        let text = Self::whitespace_after_directive(current.as_str(), &mut directive_moved);
+                        let lines = count_lines(text);
+                        directive_line_open = directive_line_open && lines == 0;
+
+                        // Comments separated from the next directive by an empty line stay in place.
+                        if lines > 0 {
+                            for text in pending_comments.drain(..) {
+                                comment(&mut table.interleave_writer(), text);
+                            }
+                        }
 
                         if lines > 1 || (lines == 1 && table.line_empty()) {
                             // Reset the table layout on more than one newline.
@@ -275,9 +429,24 @@ impl Reformatter {
                             table = TableFormat::new();
                         }
 
-                        newlines(&mut table, current.as_str(), "m");
+                        newlines(&mut table, text, "m");
                     }
                 }
+                // A comment on the line of a moved directive moves along with it.
+                Rule::COMMENT if directive_moved => {
+                    directive_moved = false;
+                    block_directives.last_mut().unwrap().comment = Some(current.as_str());
+                }
+                // Trailing comments stay on the line of their value or directive.
+                Rule::COMMENT if directive_line_open => {
+                    directive_line_open = false;
+                    comment(target.get_mut(), current.as_str());
+                }
+                Rule::COMMENT if !table.line_empty() => {
+                    table.continue_line();
+                    comment(&mut table, current.as_str());
+                }
+                Rule::COMMENT if canonical => pending_comments.push(current.as_str()),
                 Rule::COMMENT => comment(&mut table.interleave_writer(), current.as_str()),
                 _ => unreachable!(
                     "Encounterd impossible enum declaration during parsing: {:?}",
@@ -288,15 +457,57 @@ impl Reformatter {
 
         // End.
         table.render(target.get_mut());
+        Self::reformat_block_directives(target.get_mut(), block_directives, has_values);
         target.get_mut().indent_down();
         target.get_mut().write("}");
         target.get_mut().maybe_end_line();
         target.get_mut().maybe_end_line();
     }
 
+    /// Drops the line break ending a directive that was moved to the end of the block.
+    fn whitespace_after_directive<'a>(text: &'a str, directive_moved: &mut bool) -> &'a str {
+        match text.find('\n') {
+            Some(index) if *directive_moved => {
+                *directive_moved = false;
+                &text[index + 1..]
+            }
+            _ => text,
+        }
+    }
+
+    /// Canonical mode: Writes the collected block directives, sorted by name, in a group at the end of the block.
+    fn reformat_block_directives(target: &mut Renderer, mut directives: Vec<BlockDirective>, after_fields: bool) {
+        if directives.is_empty() {
+            return;
+        }
+
+        directives.sort_by_key(|directive| directive.name());
+
+        if after_fields {
+            // Separate the group from the fields by an empty line.
+            target.maybe_end_line();
+            target.maybe_end_line();
+        }
+
+        for directive in directives {
+            for text in directive.leading_comments {
+                comment(target, text);
+            }
+
+            Self::reformat_directive(target, &directive.token, "@@");
+            nested_comments(target, &directive.token);
+
+            match directive.comment {
+                Some(text) => comment(target, text),
+                None => target.end_line(),
+            }
+        }
+    }
+
     fn reformat_field(target: &mut RefCell<TableFormat>, token: &Token) {
         let mut identifier = None;
         let mut directives_started = false;
+        let mut line_ended = false;
 
         for current in token.clone().into_inner() {
             match current.as_rule() {
@@ -309,17 +520,25 @@ impl Reformatter {
                 }
                 Rule::directive => {
                     directives_started = true;
-                    Self::reformat_directive(&mut target.get_mut().column_locked_writer_for(2), &current, "@")
+                    Self::reformat_directive(&mut target.get_mut().column_locked_writer_for(2), &current, "@");
+                    nested_comments(target.get_mut(), &current);
                 }
                 Rule::doc_comment => comment(&mut target.get_mut().interleave_writer(), current.as_str()),
                 Rule::COMMENT => {
-                    if directives_started {
+                    if line_ended || target.get_mut().line_empty() {
+                        // A comment on its own line, or before the type of the field.
+                        comment(&mut target.get_mut().interleave_writer(), current.as_str());
+                    } else if directives_started {
                         comment(&mut target.get_mut().column_locked_writer_for(2), current.as_str());
                     } else {
                         comment(target.get_mut(), current.as_str());
                     }
+                    line_ended = current.as_str().ends_with('\n');
+                }
+                Rule::WHITESPACE => {
+                    line_ended = line_ended || current.as_str().contains('\n');
+                    newlines(target.get_mut(), current.as_str(), "f")
                 }
-                Rule::WHITESPACE => newlines(target.get_mut(), current.as_str(), "f"),
                 _ => unreachable!("Encounterd impossible field during parsing: {:?}", current.tokens()),
             }
         }
@@ -330,6 +549,7 @@ impl Reformatter {
     fn reformat_type_declaration(target: &mut TableFormat, token: &Token) {
         let mut identifier = None;
         let mut directives_started = false;
+        let mut line_ended = false;
 
         for current in token.clone().into_inner() {
             match current.as_rule() {
@@ -344,16 +564,21 @@ impl Reformatter {
                 Rule::directive => {
                     directives_started = true;
                     Self::reformat_directive(&mut target.column_locked_writer_for(4), &current, "@");
+                    nested_comments(target, &current);
                 }
                 Rule::doc_comment => comment(&mut target.interleave_writer(), current.as_str()),
                 Rule::COMMENT => {
-                    if directives_started {
+                    if directives_started && !line_ended {
                         comment(&mut target.column_locked_writer_for(4), current.as_str());
                     } else {
                         comment(&mut target.interleave_writer(), current.as_str());
                     }
+                    line_ended = current.as_str().ends_with('\n');
+                }
+                Rule::WHITESPACE => {
+                    line_ended = line_ended || current.as_str().contains('\n');
+                    newlines(target, current.as_str(), "t")
                 }
-                Rule::WHITESPACE => newlines(target, current.as_str(), "t"),
                 _ => unreachable!(
                     "Encounterd impossible custom type during parsing: {:?}",
                     current.tokens()
@@ -404,8 +629,7 @@ impl Reformatter {
                     target.write(owl);
                     target.write(current.as_str());
                 }
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside attributes not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 Rule::directive_arguments => Self::reformat_directive_args(target, &current),
                 _ => unreachable!("Encounterd impossible directive during parsing: {:?}", current.tokens()),
             }
@@ -431,8 +655,7 @@ impl Reformatter {
                     }
                     Self::reformat_arg_value(&mut builder, &current);
                 }
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside attribute argument list not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!(
                     "Encounterd impossible directive argument list during parsing: {:?}",
                     current.tokens()
@@ -455,8 +678,7 @@ impl Reformatter {
                     target.write(": ");
                 }
                 Rule::argument_value => Self::reformat_arg_value(target, &current),
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside attribute argument not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!(
                     "Encounterd impossible directive argument during parsing: {:?}",
                     current.tokens()
@@ -469,8 +691,7 @@ impl Reformatter {
         for current in token.clone().into_inner() {
             match current.as_rule() {
                 Rule::expression => Self::reformat_expression(target, &current),
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside attributes not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!(
                     "Encounterd impossible argument value during parsing: {:?}",
                     current.tokens()
@@ -489,8 +710,7 @@ impl Reformatter {
                 Rule::constant_literal => target.write(current.as_str()),
                 Rule::function => Self::reformat_function_expression(target, &current),
                 Rule::array_expression => Self::reformat_array_expression(target, &current),
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside expressions not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!("Encounterd impossible literal during parsing: {:?}", current.tokens()),
             }
         }
@@ -509,8 +729,7 @@ impl Reformatter {
                     Self::reformat_expression(target, &current);
                     expr_count += 1;
                 }
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside expressions not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!("Encounterd impossible array during parsing: {:?}", current.tokens()),
             }
         }
@@ -534,8 +753,7 @@ impl Reformatter {
                     Self::reformat_arg_value(target, &current);
                     expr_count += 1;
                 }
-                Rule::WHITESPACE => {}
-                Rule::COMMENT => panic!("Comments inside expressions not supported yet."),
+                Rule::WHITESPACE | Rule::COMMENT => {}
                 _ => unreachable!("Encounterd impossible function during parsing: {:?}", current.tokens()),
             }
         }
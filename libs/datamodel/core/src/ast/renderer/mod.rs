@@ -70,6 +70,7 @@ impl<'a> Renderer<'a> {
                         ast::Top::Enum(enm) => self.render_enum(enm),
                         ast::Top::Source(source) => self.render_source_block(source),
                         ast::Top::Generator(generator) => self.render_generator_block(generator),
+                        ast::Top::Linter(linter) => self.render_linter_block(linter),
                         ast::Top::Type(_) => unreachable!(),
                     }
                 }
@@ -137,6 +138,31 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    fn render_linter_block(&mut self, linter: &ast::LinterConfig) {
+        Self::render_documentation(self, linter);
+
+        self.write("linter ");
+        self.write(&linter.name.name);
+        self.write(" {");
+        self.end_line();
+        self.indent_up();
+
+        let mut formatter = TableFormat::new();
+
+        for property in &linter.properties {
+            formatter.write(&property.name.name);
+            formatter.write(" = ");
+            formatter.write(&Self::render_value_to_string(&property.value));
+            formatter.end_line();
+        }
+
+        formatter.render(self);
+
+        self.indent_down();
+        self.write("}");
+        self.end_line();
+    }
+
     fn render_custom_type(target: &mut TableFormat, field: &ast::Field) {
         Self::render_documentation(&mut target.interleave_writer(), field);
 
@@ -162,7 +188,13 @@ impl<'a> Renderer<'a> {
     fn render_model(&mut self, model: &ast::Model) {
         Self::render_documentation(self, model);
 
-        self.write("model ");
+        if model.is_view {
+            self.write("view ");
+        } else if model.is_composite_type {
+            self.write("type ");
+        } else {
+            self.write("model ");
+        }
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -218,7 +250,11 @@ impl<'a> Renderer<'a> {
     fn render_field(target: &mut TableFormat, field: &ast::Field) {
         Self::render_documentation(&mut target.interleave_writer(), field);
 
-        target.write(&field.name.name);
+        if field.is_commented_out {
+            target.write(&format!("// {}", field.name.name));
+        } else {
+            target.write(&field.name.name);
+        }
 
         // Type
         {
@@ -94,6 +94,14 @@ impl TableFormat {
         }
     }
 
+    /// Continues the last row, if it was only ended lazily by `maybe_end_line`.
+    /// Used to keep trailing comments on the line they belong to.
+    pub fn continue_line(&mut self) {
+        if !self.line_ending {
+            self.maybe_new_line = false;
+        }
+    }
+
     fn start_new_line(&mut self) {
         self.table.push(Row::Regular(Vec::new()));
         self.row += 1;
@@ -0,0 +1,75 @@
+use super::Span;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// A file of a datamodel that is split across several files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    /// The name of the file, as shown in error messages.
+    pub name: String,
+    pub text: String,
+}
+
+impl SourceFile {
+    pub fn new(name: &str, text: &str) -> SourceFile {
+        SourceFile {
+            name: String::from(name),
+            text: String::from(text),
+        }
+    }
+
+    /// Reads the file at the given path.
+    pub fn load(path: &Path) -> io::Result<SourceFile> {
+        Ok(SourceFile {
+            name: path.display().to_string(),
+            text: fs::read_to_string(path)?,
+        })
+    }
+}
+
+/// The files of a datamodel.
+///
+/// Each file is parsed on its own, and the declarations of all files are validated as a whole,
+/// so types can be used in other files than the one they are declared in. The `file_id` of a span
+/// is the index of the file it is in.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new(files: Vec<SourceFile>) -> SourceMap {
+        SourceMap { files }
+    }
+
+    /// Loads all `.prisma` files in a directory, ordered by name. If the path is a file, only loads that file.
+    pub fn load(path: &Path) -> io::Result<SourceMap> {
+        if !path.is_dir() {
+            return Ok(SourceMap::new(vec![SourceFile::load(path)?]));
+        }
+
+        let mut paths = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<PathBuf>>>()?;
+        paths.retain(|path| path.is_file() && path.extension() == Some("prisma".as_ref()));
+        paths.sort();
+
+        let files = paths
+            .iter()
+            .map(|path| SourceFile::load(path))
+            .collect::<io::Result<Vec<SourceFile>>>()?;
+
+        Ok(SourceMap::new(files))
+    }
+
+    pub fn files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    /// The file a span is in.
+    ///
+    /// Panics if the span is not in any of the files.
+    pub fn file(&self, span: Span) -> &SourceFile {
+        &self.files[span.file_id]
+    }
+}
@@ -3,23 +3,27 @@
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    /// The index of the file the span is in, for datamodels split across several files. Zero otherwise.
+    pub file_id: usize,
 }
 
 impl Span {
     pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+        Span { start, end, file_id: 0 }
     }
 
     // Creates a new empty span.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span::new(0, 0)
     }
     /// Creates a new ast::Span from a pest::Span.
     pub fn from_pest(s: pest::Span) -> Span {
-        Span {
-            start: s.start(),
-            end: s.end(),
-        }
+        Span::new(s.start(), s.end())
+    }
+
+    /// Moves the span to the file with the given index.
+    pub fn in_file(self, file_id: usize) -> Span {
+        Span { file_id, ..self }
     }
 }
 
@@ -29,10 +33,11 @@ impl std::fmt::Display for Span {
     }
 }
 
-/// Adds an offset to a span.
-pub fn lift_span(span: &Span, offset: usize) -> Span {
+/// Adds an offset to a span, moving it to the given file.
+pub fn lift_span(span: &Span, offset: usize, file_id: usize) -> Span {
     Span {
         start: offset + span.start,
         end: offset + span.end,
+        file_id,
     }
 }
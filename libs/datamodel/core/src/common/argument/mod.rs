@@ -71,6 +71,17 @@ impl<'a> Arguments<'a> {
         }
     }
 
+    /// Gets the arg with the given name, or if it is not found, the first unnamed argument, if either exists.
+    pub fn optional_default_arg(&mut self, name: &str) -> Option<Result<value::ValueValidator, DatamodelError>> {
+        match (self.arg_internal(name), self.arg_internal("")) {
+            (Some(arg), None) | (None, Some(arg)) => Some(value::ValueValidator::new(&arg.value)),
+            (Some(arg), Some(_)) => Some(Err(DatamodelError::new_duplicate_default_argument_error(
+                name, arg.span,
+            ))),
+            (None, None) => None,
+        }
+    }
+
     /// Gets the full argument span for an argument, used to generate errors.
     fn arg_internal(&mut self, name: &str) -> Option<&'a ast::Argument> {
         for arg in self.arguments {
@@ -1,4 +1,4 @@
-use super::Functional;
+use super::{Functional, DB_GENERATED};
 use crate::ast;
 use crate::common::{
     value::{MaybeExpression, ValueValidator},
@@ -33,6 +33,34 @@ impl Functional for EnvFunctional {
     }
 }
 
+/// Database generated default values (`dbgenerated("...")`).
+///
+/// Wraps a raw database expression that Prisma does not understand, so it
+/// can be kept in the datamodel instead of being dropped.
+pub struct DbGeneratedFunctional {}
+
+impl Functional for DbGeneratedFunctional {
+    fn name(&self) -> &str {
+        DB_GENERATED
+    }
+
+    fn apply(&self, values: &[ValueValidator], span: ast::Span) -> Result<MaybeExpression, DatamodelError> {
+        self.check_arg_count(values, 1, span)?;
+
+        let expression = values[0].as_str()?;
+
+        // The return type is only a placeholder, it is replaced by the type of the field the default is set on.
+        Ok(MaybeExpression::Expression(
+            ScalarValue::Expression(
+                String::from(self.name()),
+                ScalarType::String,
+                vec![ScalarValue::String(expression)],
+            ),
+            span,
+        ))
+    }
+}
+
 /// Shallow implementation for trivial server side functionals.
 #[allow(unused)]
 pub struct ServerSideTrivialFunctional {
@@ -10,6 +10,9 @@ use crate::error::DatamodelError;
 
 use traits::*;
 
+/// The name of the function for database generated default values.
+pub const DB_GENERATED: &str = "dbgenerated";
+
 // Client side funcs
 const BUILTIN_ENV_FUNCTIONAL: builtin::EnvFunctional = builtin::EnvFunctional {};
 
@@ -31,13 +34,16 @@ const BUILTIN_AUTOINCREMENT_FUNCTIONAL: builtin::ServerSideTrivialFunctional = b
     return_type: ScalarType::Int,
 };
 
+const BUILTIN_DB_GENERATED_FUNCTIONAL: builtin::DbGeneratedFunctional = builtin::DbGeneratedFunctional {};
+
 /// Array of all builtin functionals.
-const BUILTIN_FUNCTIONALS: [&dyn Functional; 5] = [
+const BUILTIN_FUNCTIONALS: [&dyn Functional; 6] = [
     &BUILTIN_ENV_FUNCTIONAL,
     &BUILTIN_NOW_FUNCTIONAL,
     &BUILTIN_CUID_FUNCTIONAL,
     &BUILTIN_UUID_FUNCTIONAL,
     &BUILTIN_AUTOINCREMENT_FUNCTIONAL,
+    &BUILTIN_DB_GENERATED_FUNCTIONAL,
 ];
 
 /// Evaluator for arbitrary expressions.
@@ -6,18 +6,21 @@ use pest::Parser;
 
 pub struct StringInterpolator {}
 
-/// Parses an expression and adds an offset to the span start, so we have consistent error
-/// messages.
+/// Parses an expression and moves its span into the span of the string it is interpolated into,
+/// so we have consistent error messages.
 fn parse_expr_and_lift_span(
     token: &pest::iterators::Pair<'_, Rule>,
-    start: usize,
+    string_span: Span,
 ) -> Result<Expression, DatamodelError> {
+    // Skip the opening quote.
+    let start = string_span.start + 1;
+
     match parse_expression(token) {
         Expression::Array(_, s) => Err(DatamodelError::new_validation_error(
             "Arrays cannot be interpolated into strings.",
-            lift_span(&s, start),
+            lift_span(&s, start, string_span.file_id),
         )),
-        expr => Ok(expr.with_lifted_span(start)),
+        expr => Ok(expr.with_lifted_span(start, string_span.file_id)),
     }
 }
 
@@ -45,7 +48,7 @@ impl StringInterpolator {
                                     Rule::INTERPOLATION_START => {}
                                     Rule::INTERPOLATION_END => {}
                                     Rule::expression => {
-                                        let value = parse_expr_and_lift_span(&child, span.start + 1)?;
+                                        let value = parse_expr_and_lift_span(&child, span)?;
                                         parts.push(ValueValidator::new(&value)?.raw())
                                     }
                                     Rule::EOI => {}
@@ -57,7 +60,7 @@ impl StringInterpolator {
                         Rule::string_escaped_interpolation => parts.push(String::from("${")),
                         Rule::string_any => parts.push(String::from(current.as_str())),
                         Rule::expression => {
-                            let value = parse_expr_and_lift_span(&current, span.start + 1)?;
+                            let value = parse_expr_and_lift_span(&current, span)?;
                             parts.push(ValueValidator::new(&value)?.raw())
                         }
                         // No whitespace, no comments.
@@ -84,7 +87,10 @@ impl StringInterpolator {
                     _ => panic!("Could not construct parsing error. This should never happend."),
                 };
 
-                Err(DatamodelError::new_parser_error(&expected, location))
+                Err(DatamodelError::new_parser_error(
+                    &expected,
+                    location.in_file(span.file_id),
+                ))
             }
         }
     }
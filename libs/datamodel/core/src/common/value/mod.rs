@@ -2,7 +2,7 @@ use crate::ast;
 use crate::dml;
 use crate::error::DatamodelError;
 
-use super::functions::FunctionalEvaluator;
+use super::functions::{FunctionalEvaluator, DB_GENERATED};
 use super::interpolation::StringInterpolator;
 use super::FromStrAndSpan;
 use super::{ScalarType, ScalarValue};
@@ -80,13 +80,14 @@ impl ValueValidator {
                 ScalarType::DateTime => self.as_date_time().map(dml::ScalarValue::DateTime),
                 ScalarType::String => self.as_str().map(dml::ScalarValue::String),
             },
-            MaybeExpression::Expression(expr, _) => {
-                if expr.get_type() == scalar_type {
-                    Ok(expr.clone())
-                } else {
-                    Err(self.construct_error(&scalar_type.to_string()))
+            MaybeExpression::Expression(expr, _) => match expr {
+                _ if expr.get_type() == scalar_type => Ok(expr.clone()),
+                // Database generated values can be of any type.
+                dml::ScalarValue::Expression(name, _, args) if name == DB_GENERATED => {
+                    Ok(dml::ScalarValue::Expression(name.clone(), scalar_type, args.clone()))
                 }
-            }
+                _ => Err(self.construct_error(&scalar_type.to_string())),
+            },
         }
     }
 
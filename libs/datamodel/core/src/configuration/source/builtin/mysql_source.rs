@@ -1,5 +1,6 @@
 use crate::configuration::*;
-use datamodel_connector::{Connector, ExampleConnector};
+use crate::dml::IndexAlgorithm;
+use datamodel_connector::{BuiltinConnectors, Connector};
 
 pub const MYSQL_SOURCE_NAME: &str = "mysql";
 
@@ -34,6 +35,10 @@ impl Source for MySqlSource {
     }
 
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::empty())
+        Box::new(BuiltinConnectors::mysql())
+    }
+
+    fn index_algorithms(&self) -> &[IndexAlgorithm] {
+        &[IndexAlgorithm::BTree, IndexAlgorithm::Hash]
     }
 }
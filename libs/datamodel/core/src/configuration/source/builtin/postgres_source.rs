@@ -1,5 +1,6 @@
 use crate::configuration::*;
-use datamodel_connector::{Connector, ExampleConnector};
+use crate::dml::IndexAlgorithm;
+use datamodel_connector::{BuiltinConnectors, Connector};
 
 pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 
@@ -7,6 +8,7 @@ pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
     pub(super) documentation: Option<String>,
+    pub(super) schemas: Vec<String>,
 }
 
 impl Source for PostgresSource {
@@ -32,6 +34,33 @@ impl Source for PostgresSource {
     }
 
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::postgres())
+        Box::new(BuiltinConnectors::postgres())
+    }
+
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
+
+    fn supports_multiple_schemas(&self) -> bool {
+        true
+    }
+
+    fn set_schemas(&mut self, schemas: Vec<String>) {
+        self.schemas = schemas;
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        true
+    }
+
+    fn index_algorithms(&self) -> &[IndexAlgorithm] {
+        &[
+            IndexAlgorithm::BTree,
+            IndexAlgorithm::Hash,
+            IndexAlgorithm::Gist,
+            IndexAlgorithm::Gin,
+            IndexAlgorithm::SpGist,
+            IndexAlgorithm::Brin,
+        ]
     }
 }
@@ -24,6 +24,7 @@ impl SourceDefinition for PostgresSourceDefinition {
             name: String::from(name),
             url: url,
             documentation: documentation.clone(),
+            schemas: Vec::new(),
         }))
     }
 }
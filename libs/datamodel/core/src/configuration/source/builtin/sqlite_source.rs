@@ -1,5 +1,5 @@
 use crate::configuration::*;
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::{BuiltinConnectors, Connector};
 
 pub const SQLITE_SOURCE_NAME: &str = "sqlite";
 
@@ -33,6 +33,10 @@ impl Source for SqliteSource {
     }
 
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::empty())
+        Box::new(BuiltinConnectors::sqlite())
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        true
     }
 }
@@ -1,6 +1,7 @@
 use super::traits::{Source, SourceDefinition};
 use crate::ast;
 use crate::common::argument::Arguments;
+use crate::common::value::ValueListValidator;
 use crate::error::{DatamodelError, ErrorCollection};
 use crate::StringFromEnvVar;
 
@@ -86,12 +87,20 @@ impl SourceLoader {
             return Ok(None);
         }
 
+        let schemas = match args.optional_arg("schemas") {
+            Some(arg) => {
+                let arg = arg?;
+                Some((arg.as_array()?.to_str_vec()?, arg.span()))
+            }
+            None => None,
+        };
+
         for decl in &self.source_declarations {
             // The provider given in the config block identifies the source type.
             // TODO: The second condition is a fallback to mitigate the postgres -> postgresql rename. It should be
             // renamed at some point.
             if provider == decl.connector_type() || (decl.connector_type() == "postgresql" && provider == "postgres") {
-                return Ok(Some(decl.create(
+                let mut source = decl.create(
                     // The name in front of the block is the name of the concrete instantiation.
                     &ast_source.name.name,
                     StringFromEnvVar {
@@ -99,7 +108,23 @@ impl SourceLoader {
                         value: url,
                     },
                     &ast_source.documentation.clone().map(|comment| comment.text),
-                )?));
+                )?;
+
+                if let Some((schemas, span)) = schemas {
+                    if !source.supports_multiple_schemas() {
+                        return Err(DatamodelError::new_validation_error(
+                            &format!(
+                                "The `schemas` property is not supported by the {} provider.",
+                                decl.connector_type()
+                            ),
+                            span,
+                        ));
+                    }
+
+                    source.set_schemas(schemas);
+                }
+
+                return Ok(Some(source));
             }
         }
 
@@ -31,6 +31,15 @@ impl SourceSerializer {
             }
         }
 
+        if !source.schemas().is_empty() {
+            let values = source
+                .schemas()
+                .iter()
+                .map(|schema| ast::Expression::StringValue(schema.to_string(), ast::Span::empty()))
+                .collect();
+            arguments.push(ast::Argument::new_array("schemas", values));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(source.name()),
             properties: arguments,
@@ -1,3 +1,4 @@
+use crate::dml::IndexAlgorithm;
 use crate::error::DatamodelError;
 use crate::StringFromEnvVar;
 use datamodel_connector::Connector;
@@ -23,6 +24,28 @@ pub trait Source: Send + Sync {
     fn documentation(&self) -> &Option<String>;
 
     fn connector(&self) -> Box<dyn Connector>;
+
+    /// The database schemas this source spans, in addition to the default one.
+    fn schemas(&self) -> &[String] {
+        &[]
+    }
+
+    /// Whether this source can span multiple database schemas.
+    fn supports_multiple_schemas(&self) -> bool {
+        false
+    }
+
+    fn set_schemas(&mut self, _schemas: Vec<String>) {}
+
+    /// Whether indexes can be restricted to the rows matching a condition.
+    fn supports_partial_indexes(&self) -> bool {
+        false
+    }
+
+    /// The methods indexes can be created with.
+    fn index_algorithms(&self) -> &[IndexAlgorithm] {
+        &[]
+    }
 }
 
 /// Trait for source definitions.
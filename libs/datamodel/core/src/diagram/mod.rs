@@ -0,0 +1,216 @@
+use crate::dml;
+
+/// How many records of a model can be related to a record on the other side of a relation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cardinality {
+    ExactlyOne,
+    ZeroOrOne,
+    ZeroOrMore,
+}
+
+impl Cardinality {
+    fn of(field: Option<&dml::Field>) -> Cardinality {
+        match field.map(|field| field.arity) {
+            Some(dml::FieldArity::Required) => Cardinality::ExactlyOne,
+            Some(dml::FieldArity::Optional) => Cardinality::ZeroOrOne,
+            Some(dml::FieldArity::List) | None => Cardinality::ZeroOrMore,
+        }
+    }
+}
+
+/// A relation between two models, drawn once for both of its relation fields.
+struct Relation<'a> {
+    from: &'a dml::Model,
+    to: &'a dml::Model,
+    field: &'a dml::Field,
+    /// How many records of `from` a record of `to` is related to.
+    from_cardinality: Cardinality,
+    /// How many records of `to` a record of `from` is related to.
+    to_cardinality: Cardinality,
+}
+
+/// Renders an entity relationship diagram of the datamodel in the Graphviz DOT language.
+pub fn render_to_dot(datamodel: &dml::Datamodel) -> String {
+    let mut dot = String::from("digraph datamodel {\n  node [shape=record];\n");
+
+    for model in entities(datamodel) {
+        let attributes: String = attributes(model)
+            .map(|(field, keys)| {
+                let keys = if keys.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", keys.join(", "))
+                };
+                format!("{} : {}{}\\l", field.name, field_type(field, true), keys)
+            })
+            .collect();
+
+        dot.push_str(&format!(
+            "  {} [label=\"{{{}|{}}}\"];\n",
+            model.name, model.name, attributes
+        ));
+    }
+
+    for relation in relations(datamodel) {
+        dot.push_str(&format!(
+            "  {} -> {} [label=\"{}\", dir=both, arrowtail={}, arrowhead={}];\n",
+            relation.from.name,
+            relation.to.name,
+            relation.field.name,
+            dot_arrow(relation.from_cardinality),
+            dot_arrow(relation.to_cardinality)
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Renders an entity relationship diagram of the datamodel as a Mermaid `erDiagram`.
+pub fn render_to_mermaid(datamodel: &dml::Datamodel) -> String {
+    let mut mermaid = String::from("erDiagram\n");
+
+    for model in entities(datamodel) {
+        mermaid.push_str(&format!("  {} {{\n", model.name));
+
+        for (field, keys) in attributes(model) {
+            let mut attribute = format!("    {} {}", field_type(field, false), field.name);
+
+            if !keys.is_empty() {
+                attribute.push_str(&format!(" {}", keys.join(", ")));
+            }
+
+            // Mermaid attribute types can not express optionality.
+            if field.arity == dml::FieldArity::Optional {
+                attribute.push_str(" \"optional\"");
+            }
+
+            mermaid.push_str(&attribute);
+            mermaid.push('\n');
+        }
+
+        mermaid.push_str("  }\n");
+    }
+
+    for relation in relations(datamodel) {
+        let from = match relation.from_cardinality {
+            Cardinality::ExactlyOne => "||",
+            Cardinality::ZeroOrOne => "|o",
+            Cardinality::ZeroOrMore => "}o",
+        };
+        let to = match relation.to_cardinality {
+            Cardinality::ExactlyOne => "||",
+            Cardinality::ZeroOrOne => "o|",
+            Cardinality::ZeroOrMore => "o{",
+        };
+
+        mermaid.push_str(&format!(
+            "  {} {}--{} {} : \"{}\"\n",
+            relation.from.name, from, to, relation.to.name, relation.field.name
+        ));
+    }
+
+    mermaid
+}
+
+/// The models drawn as entities. Composite types are part of the models using them.
+fn entities(datamodel: &dml::Datamodel) -> impl Iterator<Item = &dml::Model> {
+    datamodel
+        .models()
+        .filter(|model| !model.is_generated && !model.is_composite_type)
+}
+
+/// The non-relation fields of a model, with their key markers: `PK` for ids, `UK` for unique fields
+/// and `FK` for the foreign key fields of relations.
+fn attributes(model: &dml::Model) -> impl Iterator<Item = (&dml::Field, Vec<&'static str>)> {
+    let foreign_keys: Vec<&String> = model
+        .fields()
+        .filter_map(|field| match &field.field_type {
+            dml::FieldType::Relation(relation_info) => Some(&relation_info.fields),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    model
+        .fields()
+        .filter(|field| !field.is_generated && !field.field_type.is_relation() && !field.field_type.is_unsupported())
+        .map(move |field| {
+            let mut keys = Vec::new();
+
+            if field.id_info.is_some() || model.id_fields.contains(&field.name) {
+                keys.push("PK");
+            }
+            if foreign_keys.contains(&&field.name) {
+                keys.push("FK");
+            }
+            if field.is_unique {
+                keys.push("UK");
+            }
+
+            (field, keys)
+        })
+}
+
+/// The relations of the datamodel, each drawn from the side declaring it. Both sides of a relation
+/// declared on both models are drawn once, from the first one.
+fn relations(datamodel: &dml::Datamodel) -> Vec<Relation<'_>> {
+    let mut relations: Vec<Relation> = Vec::new();
+
+    for from in entities(datamodel) {
+        for field in from.fields().filter(|field| !field.is_generated) {
+            let relation_info = match &field.field_type {
+                dml::FieldType::Relation(relation_info) => relation_info,
+                _ => continue,
+            };
+
+            let to = match datamodel.find_model(&relation_info.to) {
+                Some(to) => to,
+                None => continue,
+            };
+
+            let opposite_field = to.related_field(&from.name, &relation_info.name, &field.name);
+            let is_drawn = relations.iter().any(|relation| match opposite_field {
+                Some(opposite_field) => std::ptr::eq(relation.field, opposite_field),
+                None => false,
+            });
+
+            if !is_drawn {
+                relations.push(Relation {
+                    from,
+                    to,
+                    field,
+                    from_cardinality: Cardinality::of(opposite_field),
+                    to_cardinality: Cardinality::of(Some(field)),
+                });
+            }
+        }
+    }
+
+    relations
+}
+
+fn field_type(field: &dml::Field, with_arity: bool) -> String {
+    let type_name = match &field.field_type {
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => name.clone(),
+        field_type => field_type
+            .scalar_type()
+            .map(|scalar_type| scalar_type.to_string())
+            .unwrap_or_default(),
+    };
+
+    match field.arity {
+        dml::FieldArity::List => format!("{}[]", type_name),
+        dml::FieldArity::Optional if with_arity => format!("{}?", type_name),
+        _ => type_name,
+    }
+}
+
+/// Crow's foot notation for the end of an edge.
+fn dot_arrow(cardinality: Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::ExactlyOne => "teetee",
+        Cardinality::ZeroOrOne => "teeodot",
+        Cardinality::ZeroOrMore => "crowodot",
+    }
+}
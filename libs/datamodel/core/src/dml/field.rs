@@ -22,17 +22,36 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a composite type field, with the composite type of the given name.
+    CompositeType(String),
     /// Connector specific field type.
     ConnectorSpecific(ScalarFieldType),
     /// Base (built-in scalar) type.
     Base(ScalarType),
+    /// A database type Prisma does not support, with its name in the database.
+    /// Such fields are rendered commented out.
+    Unsupported(String),
 }
 
 impl FieldType {
     pub fn is_relation(&self) -> bool {
+        matches!(self, Self::Relation(_))
+    }
+
+    pub fn is_composite_type(&self) -> bool {
+        matches!(self, Self::CompositeType(_))
+    }
+
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self, Self::Unsupported(_))
+    }
+
+    /// The scalar type of a base type, or the one a connector specific type maps to.
+    pub fn scalar_type(&self) -> Option<ScalarType> {
         match self {
-            Self::Relation(_) => true,
-            _ => false,
+            Self::Base(scalar_type) => Some(*scalar_type),
+            Self::ConnectorSpecific(native_type) => Some(native_type.prisma_type()),
+            _ => None,
         }
     }
 }
@@ -13,12 +13,23 @@ pub struct Model {
     pub database_name: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Indicates if this model is a composite type, stored inline in the records of the models using it.
+    /// Composite types are always embedded.
+    pub is_composite_type: bool,
     /// Describes Composite Indexes
     pub indexes: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
+    /// Indicates if this model is backed by a database view. Views are read-only.
+    pub is_view: bool,
+    /// The SQL query defining the view, if this model is a view managed by migrations.
+    pub view_definition: Option<String>,
+    /// Describes check constraints the database enforces on every row.
+    pub checks: Vec<CheckDefinition>,
+    /// The database schema the table of this model lives in. `None` means the default schema.
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -26,6 +37,28 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// The sort order of each field, in the order of `fields`. Empty if all fields are sorted ascending.
+    pub sort_orders: Vec<SortOrder>,
+    /// The SQL key list to index instead of fields, passed to the database verbatim.
+    pub expression: Option<String>,
+    /// The condition of a partial index, passed to the database verbatim.
+    pub predicate: Option<String>,
+    /// The index method. `None` means the default method of the database.
+    pub algorithm: Option<IndexAlgorithm>,
+}
+
+impl IndexDefinition {
+    /// Whether the index covers plain fields over all rows, so it can identify records.
+    pub fn is_plain(&self) -> bool {
+        self.expression.is_none() && self.predicate.is_none()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckDefinition {
+    pub name: Option<String>,
+    /// The SQL expression, passed to the database verbatim.
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -34,6 +67,78 @@ pub enum IndexType {
     Normal,
 }
 
+/// The sort order of an indexed field.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Parsable for SortOrder {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ASC" => Some(SortOrder::Asc),
+            "DESC" => Some(SortOrder::Desc),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "sort order"
+    }
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SortOrder::Asc => f.write_str("ASC"),
+            SortOrder::Desc => f.write_str("DESC"),
+        }
+    }
+}
+
+/// The method of an index. Not every database supports every method.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gist,
+    Gin,
+    SpGist,
+    Brin,
+}
+
+impl Parsable for IndexAlgorithm {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "BTREE" => Some(IndexAlgorithm::BTree),
+            "HASH" => Some(IndexAlgorithm::Hash),
+            "GIST" => Some(IndexAlgorithm::Gist),
+            "GIN" => Some(IndexAlgorithm::Gin),
+            "SPGIST" => Some(IndexAlgorithm::SpGist),
+            "BRIN" => Some(IndexAlgorithm::Brin),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "index type"
+    }
+}
+
+impl std::fmt::Display for IndexAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IndexAlgorithm::BTree => f.write_str("BTREE"),
+            IndexAlgorithm::Hash => f.write_str("HASH"),
+            IndexAlgorithm::Gist => f.write_str("GIST"),
+            IndexAlgorithm::Gin => f.write_str("GIN"),
+            IndexAlgorithm::SpGist => f.write_str("SPGIST"),
+            IndexAlgorithm::Brin => f.write_str("BRIN"),
+        }
+    }
+}
+
 impl Model {
     /// Creates a new model with the given name.
     pub fn new(name: &str) -> Model {
@@ -45,7 +150,12 @@ impl Model {
             documentation: None,
             database_name: None,
             is_embedded: false,
+            is_composite_type: false,
             is_generated: false,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
         }
     }
 
@@ -88,6 +198,11 @@ impl Model {
         }
     }
 
+    /// Whether the model has a single or a multi field id.
+    pub fn has_id(&self) -> bool {
+        self.singular_id_fields().next().is_some() || !self.id_fields.is_empty()
+    }
+
     /// Finds the name of all id fields
     pub fn singular_id_fields(&self) -> impl std::iter::Iterator<Item = &Field> {
         self.fields().filter(|x| x.id_info.is_some())
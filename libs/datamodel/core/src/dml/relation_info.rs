@@ -5,6 +5,9 @@ use super::Parsable;
 pub struct RelationInfo {
     /// The target model of the relation.
     pub to: String,
+    /// The scalar fields of this model holding the foreign key. Empty when the relation field
+    /// itself is the foreign key.
+    pub fields: Vec<String>,
     /// The target field of the relation.
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
@@ -12,6 +15,10 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// The join table of a many-to-many relation, if it is not named after the relation.
+    pub table: Option<String>,
+    /// The column of the join table that references the model holding this field.
+    pub column: Option<String>,
 }
 
 impl RelationInfo {
@@ -20,26 +27,35 @@ impl RelationInfo {
     pub fn new(to: &str) -> RelationInfo {
         RelationInfo {
             to: String::from(to),
+            fields: Vec::new(),
             to_fields: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            table: None,
+            column: None,
         }
     }
     pub fn new_with_field(to: &str, to_field: &str) -> RelationInfo {
         RelationInfo {
             to: String::from(to),
+            fields: Vec::new(),
             to_fields: vec![String::from(to_field)],
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            table: None,
+            column: None,
         }
     }
 
     pub fn new_with_fields(to: &str, to_fields: Vec<String>) -> RelationInfo {
         RelationInfo {
             to: String::from(to),
+            fields: Vec::new(),
             to_fields,
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            table: None,
+            column: None,
         }
     }
 }
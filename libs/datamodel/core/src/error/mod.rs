@@ -1,7 +1,7 @@
 mod collection;
 pub use collection::*;
 
-use crate::ast::Span;
+use crate::ast::{SourceMap, Span};
 use colored::Colorize;
 
 // No format for this file, on purpose.
@@ -337,20 +337,68 @@ impl DatamodelError {
             DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => *span,
         }
     }
+
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            DatamodelError::ArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveArgumentNotFound { span, .. } => span,
+            DatamodelError::ArgumentCountMissmatch { span, .. } => span,
+            DatamodelError::SourceArgumentNotFound { span, .. } => span,
+            DatamodelError::GeneratorArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveValidationError { span, .. } => span,
+            DatamodelError::DirectiveNotKnownError { span, .. } => span,
+            DatamodelError::ReservedScalarTypeError { span, .. } => span,
+            DatamodelError::FunctionNotKnownError { span, .. } => span,
+            DatamodelError::SourceNotKnownError { span, .. } => span,
+            DatamodelError::LiteralParseError { span, .. } => span,
+            DatamodelError::TypeNotFoundError { span, .. } => span,
+            DatamodelError::ScalarTypeNotFoundError { span, .. } => span,
+            DatamodelError::ParserError { span, .. } => span,
+            DatamodelError::FunctionalEvaluationError { span, .. } => span,
+            DatamodelError::EnvironmentFunctionalEvaluationError { span, .. } => span,
+            DatamodelError::TypeMismatchError { span, .. } => span,
+            DatamodelError::ValueParserError { span, .. } => span,
+            DatamodelError::ValidationError { span, .. } => span,
+            DatamodelError::LegacyParserError { span, .. } => span,
+            DatamodelError::ModelValidationError { span, .. } => span,
+            DatamodelError::DuplicateDirectiveError { span, .. } => span,
+            DatamodelError::DuplicateConfigKeyError { span, .. } => span,
+            DatamodelError::DuplicateTopError { span, .. } => span,
+            DatamodelError::DuplicateFieldError { span, .. } => span,
+            DatamodelError::DuplicateEnumValueError { span, .. } => span,
+            DatamodelError::DuplicateArgumentError { span, .. } => span,
+            DatamodelError::DuplicateDefaultArgumentError { span, .. } => span,
+            DatamodelError::UnusedArgumentError { span, .. } => span,
+            DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => span,
+        }
+    }
+
+    /// Moves the error to the file with the given index of a datamodel split across several files.
+    pub fn in_file(mut self, file_id: usize) -> DatamodelError {
+        let span = self.span_mut();
+        *span = span.in_file(file_id);
+        self
+    }
     pub fn description(&self) -> String {
         format!("{}", self)
     }
 
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
-        pretty_print_error(f, file_name, text, self)
+        pretty_print_error(f, file_name, text, self.span(), self)
+    }
+
+    /// Pretty prints an error of a datamodel split across several files, showing the file it occurred in.
+    pub fn pretty_print_in_files(&self, f: &mut dyn std::io::Write, files: &SourceMap) -> std::io::Result<()> {
+        let file = files.file(self.span());
+
+        pretty_print_error(f, &file.name, &file.text, self.span(), self)
     }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
 /// the offending portion of the source code, for human-friendly reading.
 #[rustfmt::skip]
-fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, error_obj: &DatamodelError) -> std::io::Result<()> {
-    let span = error_obj.span();
+fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, span: Span, error_obj: &DatamodelError) -> std::io::Result<()> {
     let error = error_obj.description();
 
     let start_line_number = text[..span.start].matches("\n").count();
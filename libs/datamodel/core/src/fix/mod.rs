@@ -0,0 +1,389 @@
+use crate::{
+    ast, configuration, dml,
+    error::ErrorCollection,
+    validator::{Standardiser, ValidationPipeline},
+};
+use std::collections::HashMap;
+
+/// Writes what the standardisation of a datamodel would infer into its text representation:
+///
+/// * Missing opposite relation fields.
+/// * Relation names for ambiguous, unnamed relations.
+/// * Missing foreign key scalar fields referred to by `@relation(fields: [...])`.
+///
+/// The fixes are inserted into the given text, which is kept as it is otherwise.
+pub fn fix(
+    ast_schema: &ast::SchemaAst,
+    text: &str,
+    sources: &[Box<dyn configuration::Source + Send + Sync>],
+) -> Result<String, ErrorCollection> {
+    // Without relation names and foreign key fields, the datamodel can not be lifted.
+    let mut fixer = Fixer::new(ast_schema, text);
+    fixer.name_ambiguous_relations();
+    fixer.add_missing_foreign_key_fields();
+    let text = fixer.apply();
+
+    let ast_schema = ast::parser::parse(&text)?;
+    let datamodel = ValidationPipeline::with_sources(sources).lift_and_validate(&ast_schema)?;
+
+    let mut fixer = Fixer::new(&ast_schema, &text);
+    fixer.add_missing_back_relation_fields(&ast_schema, &datamodel);
+
+    Ok(fixer.apply())
+}
+
+struct RelationField<'a> {
+    model: &'a ast::Model,
+    field: &'a ast::Field,
+    to: &'a ast::Model,
+    /// The name of the relation, including the ones given by this fixer. Empty if unnamed.
+    name: String,
+}
+
+struct Fixer<'a> {
+    text: &'a str,
+    relation_fields: Vec<RelationField<'a>>,
+    /// Text insertions by offset.
+    insertions: Vec<(usize, String)>,
+    /// New field declarations by model name, added at the end of the model.
+    new_fields: Vec<(&'a ast::Model, String)>,
+}
+
+impl<'a> Fixer<'a> {
+    fn new(ast_schema: &'a ast::SchemaAst, text: &'a str) -> Fixer<'a> {
+        let mut relation_fields = Vec::new();
+
+        for model in ast_schema.models().into_iter().filter(|model| !model.is_composite_type) {
+            for field in model.fields.iter().filter(|field| !field.is_commented_out) {
+                match ast_schema.find_model(&field.field_type.name) {
+                    Some(to) if !to.is_composite_type => relation_fields.push(RelationField {
+                        model,
+                        field,
+                        to,
+                        name: relation_name(field),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        Fixer {
+            text,
+            relation_fields,
+            insertions: Vec::new(),
+            new_fields: Vec::new(),
+        }
+    }
+
+    /// Names unnamed relation fields of a model pointing to the same other model. Unnamed opposite
+    /// relation fields can't be matched to one of them, so they stay unnamed.
+    fn name_ambiguous_relations(&mut self) {
+        let mut unnamed: HashMap<(&str, &str), Vec<usize>> = HashMap::new();
+
+        for (idx, relation_field) in self.relation_fields.iter().enumerate() {
+            if relation_field.name.is_empty() && relation_field.model.name.name != relation_field.to.name.name {
+                unnamed
+                    .entry((&relation_field.model.name.name, &relation_field.to.name.name))
+                    .or_default()
+                    .push(idx);
+            }
+        }
+
+        let mut ambiguous: Vec<usize> = unnamed
+            .into_iter()
+            .filter(|(_, fields)| fields.len() > 1)
+            .flat_map(|(_, fields)| fields)
+            .collect();
+        ambiguous.sort();
+
+        for idx in ambiguous {
+            let relation_field = &self.relation_fields[idx];
+            let name = format!(
+                "{}{}",
+                relation_field.model.name.name,
+                capitalize(&relation_field.field.name.name)
+            );
+            let insertion = self.relation_name_insertion(relation_field.field, &name);
+
+            self.insertions.push(insertion);
+            self.relation_fields[idx].name = name;
+        }
+    }
+
+    /// Adds the opposite relation fields the standardisation would generate to the datamodel. The
+    /// standardisation fails if several of them would get the same name, or if the name is taken. In
+    /// that case, the names are prefixed with the name of the field they are the opposite of.
+    fn add_missing_back_relation_fields(&mut self, ast_schema: &'a ast::SchemaAst, datamodel: &dml::Datamodel) {
+        let missing = Standardiser::new().missing_back_relation_fields(datamodel);
+
+        for back_relation_field in &missing {
+            let is_shared = missing
+                .iter()
+                .filter(|other| other.model == back_relation_field.model && other.field == back_relation_field.field)
+                .count()
+                > 1;
+            let is_taken = datamodel
+                .find_model(&back_relation_field.model)
+                .and_then(|model| model.find_field(&back_relation_field.field))
+                .is_some();
+
+            let name = if is_shared || is_taken {
+                format!(
+                    "{}{}",
+                    back_relation_field.related_field,
+                    capitalize(&back_relation_field.field)
+                )
+            } else {
+                back_relation_field.field.clone()
+            };
+
+            let arity = match back_relation_field.arity {
+                dml::FieldArity::Required => "",
+                dml::FieldArity::Optional => "?",
+                dml::FieldArity::List => "[]",
+            };
+
+            let mut declaration = format!("{} {}{}", name, back_relation_field.related_model, arity);
+
+            if !back_relation_field.relation_info.name.is_empty() {
+                declaration.push_str(&format!(" @relation(\"{}\")", back_relation_field.relation_info.name));
+            }
+
+            if let Some(model) = ast_schema.find_model(&back_relation_field.model) {
+                self.new_fields.push((model, declaration));
+            }
+        }
+    }
+
+    /// Adds the scalar fields listed in `@relation(fields: [...])` which do not exist yet, typed like
+    /// the fields they reference.
+    fn add_missing_foreign_key_fields(&mut self) {
+        let mut new_fields: Vec<(&ast::Model, String, String)> = Vec::new();
+
+        for relation_field in &self.relation_fields {
+            let directive = match relation_directive(relation_field.field) {
+                Some(directive) => directive,
+                None => continue,
+            };
+
+            let references = match array_argument(directive, "references") {
+                Some(references) => references,
+                None => id_field_names(relation_field.to),
+            };
+
+            let arity = match relation_field.field.arity {
+                ast::FieldArity::Optional => "?",
+                _ => "",
+            };
+
+            for (fk_name, reference) in array_argument(directive, "fields")
+                .unwrap_or_default()
+                .iter()
+                .zip(references.iter())
+            {
+                let exists = relation_field
+                    .model
+                    .fields
+                    .iter()
+                    .any(|field| &field.name.name == fk_name)
+                    || new_fields
+                        .iter()
+                        .any(|(model, name, _)| model.name.name == relation_field.model.name.name && name == fk_name);
+
+                let referenced_field = relation_field
+                    .to
+                    .fields
+                    .iter()
+                    .find(|field| &field.name.name == reference);
+
+                if let (false, Some(referenced_field)) = (exists, referenced_field) {
+                    let declaration = format!("{} {}{}", fk_name, referenced_field.field_type.name, arity);
+                    new_fields.push((relation_field.model, fk_name.clone(), declaration));
+                }
+            }
+        }
+
+        for (model, _, declaration) in new_fields {
+            self.new_fields.push((model, declaration));
+        }
+    }
+
+    /// Adds a relation name to the `@relation` directive of the field, or adds the directive.
+    fn relation_name_insertion(&self, field: &ast::Field, name: &str) -> (usize, String) {
+        match relation_directive(field) {
+            Some(directive) => {
+                let directive_text = &self.text[directive.span.start..directive.span.end];
+
+                match directive_text.find('(') {
+                    Some(paren) if directive.arguments.is_empty() => {
+                        (directive.span.start + paren + 1, format!("\"{}\"", name))
+                    }
+                    Some(paren) => (directive.span.start + paren + 1, format!("\"{}\", ", name)),
+                    None => (
+                        directive.span.start + directive_text.trim_end().len(),
+                        format!("(\"{}\")", name),
+                    ),
+                }
+            }
+            None => {
+                let end = field
+                    .directives
+                    .iter()
+                    .map(|directive| directive.span.end)
+                    .chain(std::iter::once(field.field_type.span.end))
+                    .max()
+                    .unwrap_or(field.span.end);
+                let end = self.text[..end].trim_end().len();
+
+                (end, format!(" @relation(\"{}\")", name))
+            }
+        }
+    }
+
+    fn apply(mut self) -> String {
+        let mut models: Vec<&ast::Model> = Vec::new();
+
+        for (model, _) in &self.new_fields {
+            if !models.iter().any(|known| std::ptr::eq(*known, *model)) {
+                models.push(*model);
+            }
+        }
+
+        for model in models {
+            let declarations: Vec<&str> = self
+                .new_fields
+                .iter()
+                .filter(|(other, _)| std::ptr::eq(*other, model))
+                .map(|(_, declaration)| declaration.as_str())
+                .collect();
+
+            let insertion = self.block_end_insertion(model, &declarations);
+            self.insertions.push(insertion);
+        }
+
+        // Insertions at the same offset keep their order.
+        self.insertions.sort_by_key(|(offset, _)| *offset);
+
+        let mut result = String::with_capacity(self.text.len());
+        let mut last = 0;
+
+        for (offset, insertion) in &self.insertions {
+            result.push_str(&self.text[last..*offset]);
+            result.push_str(insertion);
+            last = *offset;
+        }
+
+        result.push_str(&self.text[last..]);
+        result
+    }
+
+    /// Inserts field declarations before the closing brace of the model, indented like its fields.
+    fn block_end_insertion(&self, model: &ast::Model, declarations: &[&str]) -> (usize, String) {
+        let model_text = &self.text[model.span.start..model.span.end];
+        let block_close = model.span.start + model_text.rfind('}').expect("Model without closing brace.");
+        let line_start = self.text[..block_close].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+
+        let indentation = model
+            .fields
+            .first()
+            .map(|field| {
+                let field_line_start = self.text[..field.span.start]
+                    .rfind('\n')
+                    .map(|pos| pos + 1)
+                    .unwrap_or(0);
+                &self.text[field_line_start..field.span.start]
+            })
+            .filter(|indentation| indentation.chars().all(char::is_whitespace))
+            .unwrap_or("  ");
+
+        let lines: String = declarations
+            .iter()
+            .map(|declaration| format!("{}{}\n", indentation, declaration))
+            .collect();
+
+        if self.text[line_start..block_close].trim().is_empty() {
+            (line_start, lines)
+        } else {
+            (block_close, format!("\n{}", lines))
+        }
+    }
+}
+
+fn relation_directive(field: &ast::Field) -> Option<&ast::Directive> {
+    field
+        .directives
+        .iter()
+        .find(|directive| directive.name.name == "relation")
+}
+
+/// The name given in the `@relation` directive of the field, or an empty string.
+fn relation_name(field: &ast::Field) -> String {
+    relation_directive(field)
+        .and_then(|directive| {
+            directive
+                .arguments
+                .iter()
+                .find(|argument| argument.name.name.is_empty() || argument.name.name == "name")
+        })
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(name, _) => Some(name.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// The field names given in an array argument of a directive, like `fields: [a, b]`.
+fn array_argument(directive: &ast::Directive, name: &str) -> Option<Vec<String>> {
+    let argument = directive.arguments.iter().find(|argument| argument.name.name == name)?;
+
+    let values = match &argument.value {
+        ast::Expression::Array(values, _) => values.iter().collect(),
+        value => vec![value],
+    };
+
+    Some(
+        values
+            .into_iter()
+            .filter_map(|value| match value {
+                ast::Expression::ConstantValue(name, _) => Some(name.clone()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+fn id_field_names(model: &ast::Model) -> Vec<String> {
+    let id_field = model
+        .fields
+        .iter()
+        .find(|field| field.directives.iter().any(|directive| directive.name.name == "id"));
+
+    match id_field {
+        Some(field) => vec![field.name.name.clone()],
+        None => model
+            .directives
+            .iter()
+            .find(|directive| directive.name.name == "id")
+            .and_then(|directive| directive.arguments.first())
+            .map(|argument| match &argument.value {
+                ast::Expression::Array(values, _) => values
+                    .iter()
+                    .filter_map(|value| match value {
+                        ast::Expression::ConstantValue(name, _) => Some(name.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .unwrap_or_default(),
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{common::ScalarType, dml};
+
+/// Scalar types without a built-in GraphQL counterpart, declared as custom scalars when used.
+const CUSTOM_SCALARS: &[ScalarType] = &[ScalarType::DateTime, ScalarType::Decimal];
+
+/// Renders the datamodel as GraphQL SDL: a type for each model and composite type, and an enum for each enum.
+///
+/// The SDL describes the data only. Ids, defaults, database names and other attributes of the datamodel
+/// have no representation in it. Relation fields are rendered as fields of the related type.
+pub fn render_to_sdl(datamodel: &dml::Datamodel) -> String {
+    let mut blocks = Vec::new();
+
+    let models: Vec<&dml::Model> = datamodel.models().filter(|model| !model.is_generated).collect();
+    let fields: Vec<&dml::Field> = models.iter().flat_map(|model| rendered_fields(model)).collect();
+
+    for scalar_type in CUSTOM_SCALARS {
+        if fields
+            .iter()
+            .any(|field| field.field_type.scalar_type() == Some(*scalar_type))
+        {
+            blocks.push(format!("scalar {}", scalar_type.to_string()));
+        }
+    }
+
+    for en in datamodel.enums() {
+        blocks.push(render_enum(en));
+    }
+
+    for model in models {
+        blocks.push(render_model(model));
+    }
+
+    let mut sdl = blocks.join("\n\n");
+    sdl.push('\n');
+    sdl
+}
+
+fn rendered_fields(model: &dml::Model) -> impl Iterator<Item = &dml::Field> {
+    model
+        .fields()
+        .filter(|field| !field.is_generated && !field.field_type.is_unsupported())
+}
+
+fn render_enum(en: &dml::Enum) -> String {
+    let mut sdl = description(&en.documentation, "");
+    sdl.push_str(&format!("enum {} {{\n", en.name));
+
+    for value in &en.values {
+        sdl.push_str(&format!("  {}\n", value));
+    }
+
+    sdl.push('}');
+    sdl
+}
+
+fn render_model(model: &dml::Model) -> String {
+    let mut sdl = description(&model.documentation, "");
+    sdl.push_str(&format!("type {} {{\n", model.name));
+
+    for field in rendered_fields(model) {
+        sdl.push_str(&description(&field.documentation, "  "));
+        sdl.push_str(&format!("  {}: {}\n", field.name, render_field_type(field)));
+    }
+
+    sdl.push('}');
+    sdl
+}
+
+fn render_field_type(field: &dml::Field) -> String {
+    let type_name = match &field.field_type {
+        dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => name.clone(),
+        dml::FieldType::Base(scalar_type) => scalar_type.to_string(),
+        dml::FieldType::ConnectorSpecific(native_type) => native_type.prisma_type().to_string(),
+        dml::FieldType::Unsupported(_) => unreachable!("Unsupported fields are not rendered."),
+    };
+
+    match field.arity {
+        dml::FieldArity::Required => format!("{}!", type_name),
+        dml::FieldArity::Optional => type_name,
+        dml::FieldArity::List => format!("[{}!]!", type_name),
+    }
+}
+
+/// Documentation is rendered as a block string description.
+fn description(documentation: &Option<String>, indentation: &str) -> String {
+    match documentation {
+        Some(documentation) => {
+            let lines: String = documentation
+                .lines()
+                .map(|line| format!("{}{}\n", indentation, line.replace("\"\"\"", "\\\"\"\"")))
+                .collect();
+
+            format!("{}\"\"\"\n{}{}\"\"\"\n", indentation, lines, indentation)
+        }
+        None => String::new(),
+    }
+}
@@ -32,11 +32,16 @@ fn model_from_dmmf(model: &Model) -> dml::Model {
         name: model.name.clone(),
         database_name: model.db_name.clone(),
         is_embedded: model.is_embedded,
+        is_composite_type: model.is_composite_type.unwrap_or(false),
         fields: model.fields.iter().map(&field_from_dmmf).collect(),
         indexes: vec![],
         id_fields: model.id_fields.clone(),
         documentation: model.documentation.clone(),
         is_generated: model.is_generated.unwrap_or(false),
+        is_view: model.is_view.unwrap_or(false),
+        view_definition: None,
+        checks: vec![],
+        schema: None,
     }
 }
 
@@ -108,9 +113,15 @@ fn type_from_string(scalar: &str) -> ScalarType {
 }
 
 fn function_from_dmmf(func: &Function, expected_type: ScalarType) -> dml::ScalarValue {
-    if !func.args.is_empty() {
-        panic!("Function argument deserialization is not supported with DMMF. There are no type annotations yet, so it's not clear which is meant.");
-    }
+    // Only string arguments can be deserialized. There are no type annotations yet, so it's not clear which type is meant otherwise.
+    let args = func
+        .args
+        .iter()
+        .map(|arg| match arg {
+            serde_json::Value::String(value) => dml::ScalarValue::String(value.clone()),
+            _ => panic!("Function argument deserialization is only supported for strings with DMMF."),
+        })
+        .collect();
 
     if func.return_type != expected_type.to_string() {
         panic!(
@@ -120,7 +131,7 @@ fn function_from_dmmf(func: &Function, expected_type: ScalarType) -> dml::Scalar
         );
     }
 
-    dml::ScalarValue::Expression(func.name.clone(), expected_type, vec![])
+    dml::ScalarValue::Expression(func.name.clone(), expected_type, args)
 }
 
 fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
@@ -134,11 +145,15 @@ fn get_field_type(field: &Field) -> dml::FieldType {
     match &field.kind as &str {
         "object" => dml::FieldType::Relation(dml::RelationInfo {
             to: field.field_type.clone(),
+            fields: Vec::new(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
             name: field.relation_name.clone().unwrap_or(String::new()),
             on_delete: get_on_delete_strategy(&field.relation_on_delete),
+            table: None,
+            column: None,
         }),
         "enum" => dml::FieldType::Enum(field.field_type.clone()),
+        "composite" => dml::FieldType::CompositeType(field.field_type.clone()),
         "scalar" => dml::FieldType::Base(type_from_string(&field.field_type)),
         _ => panic!(format!("Unknown field kind {}.", &field.kind)),
     }
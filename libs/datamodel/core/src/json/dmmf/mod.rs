@@ -57,6 +57,10 @@ pub struct Model {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_view: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_composite_type: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
     pub id_fields: Vec<String>,
 }
@@ -23,7 +23,8 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.enums.push(enum_to_dmmf(&enum_model));
     }
 
-    for model in schema.models() {
+    // Views without an id can not be queried by clients.
+    for model in schema.models().filter(|model| !model.is_view || model.has_id()) {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
@@ -44,8 +45,15 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields().map(&field_to_dmmf).collect(),
+        // Fields of unsupported types can not be used by clients.
+        fields: model
+            .fields()
+            .filter(|field| !field.field_type.is_unsupported())
+            .map(&field_to_dmmf)
+            .collect(),
         is_generated: Some(model.is_generated),
+        is_view: if model.is_view { Some(true) } else { None },
+        is_composite_type: if model.is_composite_type { Some(true) } else { None },
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
     }
@@ -75,7 +83,8 @@ fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
-        dml::FieldType::Base(_) => String::from("scalar"),
+        dml::FieldType::CompositeType(_) => String::from("composite"),
+        dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
     }
 }
@@ -84,8 +93,10 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Base(t) => type_to_string(t),
         dml::FieldType::ConnectorSpecific(sft) => type_to_string(&sft.prisma_type()),
+        dml::FieldType::Unsupported(_) => unreachable!("Unsupported fields are not rendered to DMMF."),
     }
 }
 
@@ -0,0 +1,4 @@
+mod to_json_schema;
+
+pub use to_json_schema::render_to_json_schema;
+pub use to_json_schema::render_to_json_schema_value;
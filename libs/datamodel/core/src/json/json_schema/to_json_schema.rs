@@ -0,0 +1,168 @@
+use crate::common::ScalarType;
+use crate::dml;
+use serde_json::{json, Map, Value};
+
+const JSON_SCHEMA_VERSION: &str = "http://json-schema.org/draft-07/schema#";
+
+/// The kind of object a definition describes, which decides on its required fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    /// A composite type value. Required fields are required.
+    CompositeType,
+    /// The data to create a record with. Required fields without a default value are required.
+    Create,
+    /// The data to update a record with. No field is required.
+    Update,
+}
+
+pub fn render_to_json_schema(schema: &dml::Datamodel) -> String {
+    let json_schema = render_to_json_schema_value(schema);
+    serde_json::to_string_pretty(&json_schema).expect("Failed to render JSON")
+}
+
+/// Renders the datamodel as JSON Schema, with a definition for each enum and composite type, and
+/// `<Model>CreateInput` and `<Model>UpdateInput` definitions for the data of each model.
+///
+/// Relation fields are not part of the data of a model, their foreign key fields are.
+pub fn render_to_json_schema_value(schema: &dml::Datamodel) -> Value {
+    let mut definitions = Map::new();
+
+    for enum_model in schema.enums() {
+        definitions.insert(enum_model.name.clone(), enum_to_json_schema(enum_model));
+    }
+
+    for model in schema.models().filter(|model| !model.is_generated) {
+        if model.is_composite_type {
+            definitions.insert(model.name.clone(), model_to_json_schema(model, Shape::CompositeType));
+        } else {
+            definitions.insert(
+                format!("{}CreateInput", model.name),
+                model_to_json_schema(model, Shape::Create),
+            );
+            definitions.insert(
+                format!("{}UpdateInput", model.name),
+                model_to_json_schema(model, Shape::Update),
+            );
+        }
+    }
+
+    json!({
+        "$schema": JSON_SCHEMA_VERSION,
+        "definitions": definitions,
+    })
+}
+
+fn enum_to_json_schema(en: &dml::Enum) -> Value {
+    let mut json_schema = json!({
+        "type": "string",
+        "enum": en.values,
+    });
+
+    add_description(&mut json_schema, &en.documentation);
+    json_schema
+}
+
+fn model_to_json_schema(model: &dml::Model, shape: Shape) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in model.fields().filter(|field| !field.is_generated) {
+        if let Some(field_schema) = field_to_json_schema(field) {
+            if is_required(field, shape) {
+                required.push(field.name.clone());
+            }
+
+            properties.insert(field.name.clone(), field_schema);
+        }
+    }
+
+    let mut json_schema = json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+
+    if !required.is_empty() {
+        json_schema["required"] = json!(required);
+    }
+
+    add_description(&mut json_schema, &model.documentation);
+    json_schema
+}
+
+fn is_required(field: &dml::Field, shape: Shape) -> bool {
+    let is_set_by_default = field.default_value.is_some() || field.is_updated_at;
+
+    match shape {
+        Shape::CompositeType => field.arity == dml::FieldArity::Required,
+        Shape::Create => field.arity == dml::FieldArity::Required && !is_set_by_default,
+        Shape::Update => false,
+    }
+}
+
+/// The schema of the values of a field, or `None` for relation and unsupported fields.
+fn field_to_json_schema(field: &dml::Field) -> Option<Value> {
+    let value_schema = match &field.field_type {
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => {
+            json!({ "$ref": format!("#/definitions/{}", name) })
+        }
+        field_type => scalar_type_to_json_schema(field_type.scalar_type()?),
+    };
+
+    let mut json_schema = match field.arity {
+        dml::FieldArity::Required => value_schema,
+        dml::FieldArity::Optional => nullable(value_schema),
+        dml::FieldArity::List => json!({
+            "type": "array",
+            "items": value_schema,
+        }),
+    };
+
+    if let Some(default) = field.default_value.as_ref().and_then(default_value_to_json) {
+        json_schema["default"] = default;
+    }
+
+    add_description(&mut json_schema, &field.documentation);
+    Some(json_schema)
+}
+
+fn scalar_type_to_json_schema(scalar_type: ScalarType) -> Value {
+    match scalar_type {
+        ScalarType::Int => json!({ "type": "integer" }),
+        ScalarType::Float | ScalarType::Decimal => json!({ "type": "number" }),
+        ScalarType::Boolean => json!({ "type": "boolean" }),
+        ScalarType::String => json!({ "type": "string" }),
+        ScalarType::DateTime => json!({ "type": "string", "format": "date-time" }),
+    }
+}
+
+/// Allows `null` next to the values of the given schema.
+fn nullable(json_schema: Value) -> Value {
+    match json_schema["type"].as_str().map(String::from) {
+        Some(type_name) => {
+            let mut json_schema = json_schema;
+            json_schema["type"] = json!([type_name, "null"]);
+            json_schema
+        }
+        None => json!({ "anyOf": [json_schema, { "type": "null" }] }),
+    }
+}
+
+/// Default values computed by the database or the query engine, like `now()`, have no JSON representation.
+fn default_value_to_json(value: &dml::ScalarValue) -> Option<Value> {
+    match value {
+        dml::ScalarValue::Boolean(val) => Some(json!(val)),
+        dml::ScalarValue::String(val) => Some(json!(val)),
+        dml::ScalarValue::ConstantLiteral(val) => Some(json!(val)),
+        dml::ScalarValue::Int(val) => Some(json!(val)),
+        dml::ScalarValue::Float(val) | dml::ScalarValue::Decimal(val) => Some(json!(val)),
+        dml::ScalarValue::DateTime(val) => Some(json!(val.to_rfc3339())),
+        dml::ScalarValue::Expression(_, _, _) => None,
+    }
+}
+
+fn add_description(json_schema: &mut Value, documentation: &Option<String>) {
+    if let Some(documentation) = documentation {
+        json_schema["description"] = json!(documentation);
+    }
+}
@@ -9,6 +9,8 @@ pub struct SourceConfig {
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
 }
 
 pub fn render_sources_to_json_value(sources: &[Box<dyn configuration::Source + Send + Sync>]) -> serde_json::Value {
@@ -37,6 +39,7 @@ fn source_to_json_struct(source: &dyn configuration::Source) -> SourceConfig {
         connector_type: String::from(source.connector_type()),
         url: source.url().clone(),
         documentation: source.documentation().clone(),
+        schemas: source.schemas().to_vec(),
     }
 }
 
@@ -88,6 +91,15 @@ fn source_from_json(
         }
     }
 
+    if !source.schemas.is_empty() {
+        let values = source
+            .schemas
+            .iter()
+            .map(|schema| ast::Expression::StringValue(schema.to_string(), ast::Span::empty()))
+            .collect();
+        arguments.push(ast::Argument::new_array("schemas", values));
+    }
+
     let ast_source = ast::SourceConfig {
         name: ast::Identifier::new(&source.name),
         properties: arguments,
@@ -1,2 +1,3 @@
 pub mod dmmf;
+pub mod json_schema;
 pub mod mcf;
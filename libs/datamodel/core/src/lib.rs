@@ -51,15 +51,19 @@ extern crate failure; // Failure enum display derivation
 pub mod ast;
 pub mod common;
 pub mod configuration;
+pub mod diagram;
 pub mod dml;
 pub mod error;
+pub mod fix;
+pub mod graphql;
 pub mod json;
+pub mod lint;
 pub mod validator;
 
 pub use configuration::*;
 pub use dml::*;
 
-use crate::ast::SchemaAst;
+use crate::ast::{SchemaAst, SourceMap};
 use std::io::Write;
 use validator::ValidationPipeline;
 
@@ -104,17 +108,70 @@ pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str)
     }
 }
 
+/// Parses and validates a datamodel split across several files, using core attributes only.
+/// The `file_id` of the spans in errors is the index of the file they are in.
+pub fn parse_datamodel_files(files: &SourceMap) -> Result<Datamodel, error::ErrorCollection> {
+    let (ast, errors) = ast::parser::parse_files_with_recovery(files);
+    let malformed_names = if errors.has_errors() {
+        files
+            .files()
+            .iter()
+            .flat_map(|file| ast::parser::malformed_declaration_names(&file.text))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    validate_with_sources(&ast, errors, &malformed_names, vec![])
+}
+
+/// Parses and validates a datamodel split across several files, using core attributes only.
+/// In case of an error, a pretty, colorful string is returned, naming the file of each error.
+pub fn parse_datamodel_files_or_pretty_error(files: &SourceMap) -> Result<Datamodel, String> {
+    match parse_datamodel_files(files) {
+        Ok(dml) => Ok(dml),
+        Err(errs) => {
+            let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
+
+            for error in errs.to_iter() {
+                writeln!(&mut buffer).expect("Failed to render error.");
+                error
+                    .pretty_print_in_files(&mut buffer, files)
+                    .expect("Failed to render error.");
+            }
+
+            Err(String::from_utf8(buffer.into_inner()).expect("Failed to convert error buffer."))
+        }
+    }
+}
+
 /// Parses and validates a datamodel string, using core attributes and the given sources.
-/// If source loading failes, validation continues, but an error is returned.
+/// If parsing some declarations or source loading failes, validation continues with the rest, but an error is returned.
 pub fn parse_datamodel_with_sources(
     datamodel_string: &str,
     source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
 ) -> Result<Datamodel, error::ErrorCollection> {
-    let ast = ast::parser::parse(datamodel_string)?;
+    let (ast, errors) = ast::parser::parse_with_recovery(datamodel_string);
+    let malformed_names = if errors.has_errors() {
+        ast::parser::malformed_declaration_names(datamodel_string)
+    } else {
+        Vec::new()
+    };
 
-    let mut errors = error::ErrorCollection::new();
+    validate_with_sources(&ast, errors, &malformed_names, source_definitions)
+}
 
-    let sources = match load_sources(&ast, source_definitions) {
+/// Validates an AST parsed with recovery, given the errors of parsing it and the names of the models and enums
+/// whose declarations are malformed.
+fn validate_with_sources(
+    ast: &SchemaAst,
+    mut errors: error::ErrorCollection,
+    malformed_names: &[String],
+    source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
+) -> Result<Datamodel, error::ErrorCollection> {
+    let has_parser_errors = errors.has_errors();
+
+    let sources = match load_sources(ast, source_definitions) {
         Ok(src) => src,
         Err(mut err) => {
             errors.append(&mut err);
@@ -124,19 +181,62 @@ pub fn parse_datamodel_with_sources(
 
     let validator = ValidationPipeline::with_sources(&sources);
 
-    match validator.validate(&ast) {
+    match validator.validate(ast) {
+        Ok(_) if has_parser_errors => Err(errors),
         Ok(src) => Ok(src),
         Err(mut err) => {
+            // Malformed declarations were reported already, references to them are not an error of their own.
+            err.errors.retain(|error| match error {
+                error::DatamodelError::TypeNotFoundError { type_name, .. } => !malformed_names.contains(type_name),
+                _ => true,
+            });
             errors.append(&mut err);
             Err(errors)
         }
     }
 }
 
+/// Parses and validates a datamodel string, then runs the lint pass configured by its `linter` blocks.
+pub fn lint_datamodel(datamodel_string: &str) -> Result<Vec<lint::LintWarning>, error::ErrorCollection> {
+    let ast = ast::parser::parse(datamodel_string)?;
+    // The lint rules do not depend on the datasources, so errors loading them are left to validation.
+    let sources = load_sources(&ast, vec![]).unwrap_or_default();
+    let datamodel = ValidationPipeline::with_sources(&sources).validate(&ast)?;
+    let config = lint::LintConfig::from_ast(&ast)?;
+
+    Ok(lint::lint(&ast, &datamodel, &config))
+}
+
+/// Writes the fields and relation names the standardisation of a datamodel would infer into the datamodel string,
+/// keeping the rest of it as it is. See `fix::fix` for what is fixed.
+pub fn fix_datamodel(datamodel_string: &str) -> Result<String, error::ErrorCollection> {
+    let ast = ast::parser::parse(datamodel_string)?;
+    let sources = load_sources(&ast, vec![])?;
+
+    fix::fix(&ast, datamodel_string, &sources)
+}
+
 pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::ErrorCollection> {
     ast::parser::parse(datamodel_string)
 }
 
+/// Parses a datamodel string into an AST of all well-formed declarations, along with the errors of the malformed ones.
+pub fn parse_schema_ast_with_recovery(datamodel_string: &str) -> (SchemaAst, error::ErrorCollection) {
+    ast::parser::parse_with_recovery(datamodel_string)
+}
+
+/// Parses a datamodel split across several files into a single AST.
+pub fn parse_schema_ast_files(files: &SourceMap) -> Result<SchemaAst, error::ErrorCollection> {
+    ast::parser::parse_files(files)
+}
+
+/// Loads all configuration blocks from a datamodel split across several files using the built-in source definitions.
+pub fn parse_configuration_files(files: &SourceMap) -> Result<Configuration, error::ErrorCollection> {
+    let ast = ast::parser::parse_files(files)?;
+
+    load_configuration(&ast, vec![])
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
     parse_configuration_with_sources(datamodel_string, vec![])
@@ -148,8 +248,16 @@ pub fn parse_configuration_with_sources(
     source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
 ) -> Result<Configuration, error::ErrorCollection> {
     let ast = ast::parser::parse(datamodel_string)?;
-    let datasources = load_sources(&ast, source_definitions)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+
+    load_configuration(&ast, source_definitions)
+}
+
+fn load_configuration(
+    ast: &SchemaAst,
+    source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
+) -> Result<Configuration, error::ErrorCollection> {
+    let datasources = load_sources(ast, source_definitions)?;
+    let generators = GeneratorLoader::load_generators_from_ast(ast)?;
 
     Ok(Configuration {
         datasources,
@@ -0,0 +1,60 @@
+use super::{LintRule, Severity};
+use crate::{
+    ast,
+    common::{value::ValueValidator, FromStrAndSpan},
+    error::{DatamodelError, ErrorCollection},
+};
+use std::collections::HashMap;
+
+/// The severity of each lint rule, as configured by the `linter` blocks of a datamodel.
+/// Rules that are not configured are reported with their default severity.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LintConfig {
+    severities: HashMap<LintRule, Severity>,
+}
+
+impl LintConfig {
+    /// Loads the configuration from all `linter` blocks of the given AST.
+    pub fn from_ast(ast_schema: &ast::SchemaAst) -> Result<LintConfig, ErrorCollection> {
+        let mut config = LintConfig::default();
+        let mut errors = ErrorCollection::new();
+
+        for linter in ast_schema.linters() {
+            for property in &linter.properties {
+                match Self::lift_property(property) {
+                    Ok((rule, severity)) => config.set_severity(rule, severity),
+                    Err(err) => errors.push(err),
+                }
+            }
+        }
+
+        errors.ok()?;
+
+        Ok(config)
+    }
+
+    fn lift_property(property: &ast::Argument) -> Result<(LintRule, Severity), DatamodelError> {
+        let rule = LintRule::from_name(&property.name.name).ok_or_else(|| {
+            DatamodelError::new_validation_error(
+                &format!("Lint rule \"{}\" is not known.", property.name.name),
+                property.name.span,
+            )
+        })?;
+
+        let value = ValueValidator::new(&property.value)?;
+        let severity = Severity::from_str_and_span(&value.as_str()?, value.span())?;
+
+        Ok((rule, severity))
+    }
+
+    pub fn severity(&self, rule: LintRule) -> Severity {
+        self.severities
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+
+    pub fn set_severity(&mut self, rule: LintRule, severity: Severity) {
+        self.severities.insert(rule, severity);
+    }
+}
@@ -0,0 +1,125 @@
+mod config;
+mod rules;
+
+pub use config::*;
+
+use crate::{ast, dml};
+use serde::Serialize;
+
+/// A rule of the lint pass. Unlike validation errors, lint warnings point out
+/// questionable, but valid datamodels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// Models without `@@map` named after a reserved SQL word. Prisma quotes all table names, so
+    /// this only matters for raw SQL queries, and the rule has to be turned on explicitly.
+    ReservedTableNames,
+    /// Relation scalar fields without an index starting with them.
+    RelationScalarIndexes,
+    /// Model or field names deviating from the naming convention of most others.
+    NamingConventions,
+    /// Optional relation fields with a required foreign key scalar field.
+    OptionalRelationScalars,
+    /// Enums not used by any field.
+    UnusedEnums,
+}
+
+impl LintRule {
+    pub fn all() -> &'static [LintRule] {
+        &[
+            LintRule::ReservedTableNames,
+            LintRule::RelationScalarIndexes,
+            LintRule::NamingConventions,
+            LintRule::OptionalRelationScalars,
+            LintRule::UnusedEnums,
+        ]
+    }
+
+    /// The name of the rule, as used in `linter` blocks.
+    pub fn name(self) -> &'static str {
+        match self {
+            LintRule::ReservedTableNames => "reservedTableNames",
+            LintRule::RelationScalarIndexes => "relationScalarIndexes",
+            LintRule::NamingConventions => "namingConventions",
+            LintRule::OptionalRelationScalars => "optionalRelationScalars",
+            LintRule::UnusedEnums => "unusedEnums",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<LintRule> {
+        LintRule::all().iter().copied().find(|rule| rule.name() == name)
+    }
+
+    /// How the rule is reported when it is not configured in a `linter` block.
+    pub fn default_severity(self) -> Severity {
+        match self {
+            LintRule::ReservedTableNames => Severity::Off,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+/// How a lint rule is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl dml::Parsable for Severity {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "off" => Some(Severity::Off),
+            "warn" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "lint severity"
+    }
+}
+
+/// A finding of the lint pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintWarning {
+    pub rule: LintRule,
+    pub severity: Severity,
+    pub message: String,
+    pub span: ast::Span,
+}
+
+/// Runs all enabled lint rules over a valid datamodel.
+///
+/// The AST is only used to locate the findings in the text representation.
+pub fn lint(ast: &ast::SchemaAst, datamodel: &dml::Datamodel, config: &LintConfig) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+
+    for rule in LintRule::all() {
+        let severity = config.severity(*rule);
+
+        if severity == Severity::Off {
+            continue;
+        }
+
+        let findings = match rule {
+            LintRule::ReservedTableNames => rules::reserved_table_names(ast, datamodel),
+            LintRule::RelationScalarIndexes => rules::relation_scalar_indexes(ast, datamodel),
+            LintRule::NamingConventions => rules::naming_conventions(ast, datamodel),
+            LintRule::OptionalRelationScalars => rules::optional_relation_scalars(ast, datamodel),
+            LintRule::UnusedEnums => rules::unused_enums(ast, datamodel),
+        };
+
+        warnings.extend(findings.into_iter().map(|(message, span)| LintWarning {
+            rule: *rule,
+            severity,
+            message,
+            span,
+        }));
+    }
+
+    warnings.sort_by_key(|warning| warning.span.start);
+    warnings
+}
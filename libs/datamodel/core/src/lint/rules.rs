@@ -0,0 +1,245 @@
+use crate::{ast, dml};
+
+/// A finding of a rule: its message and where it is located.
+type Finding = (String, ast::Span);
+
+/// Words reserved by at least one of the supported SQL databases, which can not be used as
+/// unquoted table names.
+#[rustfmt::skip]
+const RESERVED_SQL_WORDS: &[&str] = &[
+    "ALL", "AND", "ANY", "AS", "ASC", "BETWEEN", "BY", "CASE", "CHECK", "COLUMN", "CONSTRAINT", "CREATE", "CROSS",
+    "DEFAULT", "DELETE", "DESC", "DISTINCT", "DROP", "ELSE", "END", "EXCEPT", "EXISTS", "FALSE", "FETCH", "FOR",
+    "FOREIGN", "FROM", "FULL", "GRANT", "GROUP", "HAVING", "IN", "INDEX", "INNER", "INSERT", "INTERSECT", "INTO", "IS",
+    "JOIN", "KEY", "LEFT", "LIKE", "LIMIT", "NOT", "NULL", "OFFSET", "ON", "OR", "ORDER", "OUTER", "PRIMARY",
+    "REFERENCES", "RIGHT", "ROW", "SELECT", "SET", "TABLE", "THEN", "TO", "TRUE", "UNION", "UNIQUE", "UPDATE", "USER",
+    "USING", "VALUES", "WHEN", "WHERE", "WITH",
+];
+
+pub(super) fn reserved_table_names(ast: &ast::SchemaAst, datamodel: &dml::Datamodel) -> Vec<Finding> {
+    user_models(datamodel)
+        .filter(|model| model.database_name.is_none() && !model.is_composite_type)
+        .filter(|model| RESERVED_SQL_WORDS.contains(&model.name.to_uppercase().as_str()))
+        .map(|model| {
+            let message = format!(
+                "The name of model \"{}\" is a reserved SQL word. Consider renaming it, or choose a different table name with @@map.",
+                model.name
+            );
+
+            (message, model_span(ast, &model.name))
+        })
+        .collect()
+}
+
+pub(super) fn relation_scalar_indexes(ast: &ast::SchemaAst, datamodel: &dml::Datamodel) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for model in user_models(datamodel).filter(|model| !model.is_view) {
+        for (field, relation_info) in relation_fields(model) {
+            if relation_info.fields.is_empty() || is_indexed(model, &relation_info.fields) {
+                continue;
+            }
+
+            let message = format!(
+                "The foreign key of relation field \"{}\" on model \"{}\" is not indexed. Consider adding @@index([{}]).",
+                field.name,
+                model.name,
+                relation_info.fields.join(", ")
+            );
+
+            findings.push((message, field_span(ast, &model.name, &field.name)));
+        }
+    }
+
+    findings
+}
+
+pub(super) fn naming_conventions(ast: &ast::SchemaAst, datamodel: &dml::Datamodel) -> Vec<Finding> {
+    let models: Vec<_> = user_models(datamodel)
+        .map(|model| NamedItem {
+            description: format!("Model \"{}\"", model.name),
+            name: &model.name,
+            span: model_span(ast, &model.name),
+        })
+        .collect();
+
+    let fields: Vec<_> = user_models(datamodel)
+        .flat_map(|model| {
+            model
+                .fields
+                .iter()
+                .filter(|field| !field.is_generated)
+                .map(move |field| NamedItem {
+                    description: format!("Field \"{}\" on model \"{}\"", field.name, model.name),
+                    name: &field.name,
+                    span: field_span(ast, &model.name, &field.name),
+                })
+        })
+        .collect();
+
+    let mut findings = deviating_names(&models, "models", "PascalCase");
+    findings.append(&mut deviating_names(&fields, "fields", "camelCase"));
+    findings
+}
+
+pub(super) fn optional_relation_scalars(ast: &ast::SchemaAst, datamodel: &dml::Datamodel) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for model in user_models(datamodel) {
+        for (field, relation_info) in relation_fields(model) {
+            if field.arity != dml::FieldArity::Optional {
+                continue;
+            }
+
+            let required_scalars = relation_info
+                .fields
+                .iter()
+                .filter_map(|name| model.find_field(name))
+                .filter(|scalar| scalar.arity == dml::FieldArity::Required);
+
+            for scalar in required_scalars {
+                let message = format!(
+                    "The relation field \"{}\" on model \"{}\" is optional, but its foreign key field \"{}\" is required.",
+                    field.name, model.name, scalar.name
+                );
+
+                findings.push((message, field_span(ast, &model.name, &field.name)));
+            }
+        }
+    }
+
+    findings
+}
+
+pub(super) fn unused_enums(ast: &ast::SchemaAst, datamodel: &dml::Datamodel) -> Vec<Finding> {
+    let is_used = |enum_name: &str| {
+        datamodel.models().any(|model| {
+            model.fields.iter().any(|field| match &field.field_type {
+                dml::FieldType::Enum(name) => name == enum_name,
+                _ => false,
+            })
+        })
+    };
+
+    datamodel
+        .enums()
+        .filter(|r#enum| !is_used(&r#enum.name))
+        .map(|r#enum| {
+            let span = ast
+                .find_enum(&r#enum.name)
+                .map(|r#enum| r#enum.name.span)
+                .unwrap_or_else(ast::Span::empty);
+
+            (format!("Enum \"{}\" is not used by any field.", r#enum.name), span)
+        })
+        .collect()
+}
+
+fn user_models(datamodel: &dml::Datamodel) -> impl Iterator<Item = &dml::Model> {
+    datamodel.models().filter(|model| !model.is_generated)
+}
+
+fn relation_fields(model: &dml::Model) -> impl Iterator<Item = (&dml::Field, &dml::RelationInfo)> {
+    model
+        .fields
+        .iter()
+        .filter(|field| !field.is_generated)
+        .filter_map(|field| match &field.field_type {
+            dml::FieldType::Relation(relation_info) => Some((field, relation_info)),
+            _ => None,
+        })
+}
+
+/// Whether the id, a unique field or an index of the model starts with the given fields.
+fn is_indexed(model: &dml::Model, fields: &[String]) -> bool {
+    let starts_with_fields = |indexed: &[String]| indexed.len() >= fields.len() && indexed[..fields.len()] == *fields;
+
+    let is_unique_field = match fields {
+        [field] => model
+            .find_field(field)
+            .map(|field| field.is_unique || field.id_info.is_some())
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    is_unique_field
+        || starts_with_fields(&model.id_fields)
+        || model
+            .indexes
+            .iter()
+            .any(|index| index.is_plain() && starts_with_fields(&index.fields))
+}
+
+fn model_span(ast: &ast::SchemaAst, model: &str) -> ast::Span {
+    ast.find_model(model)
+        .map(|model| model.name.span)
+        .unwrap_or_else(ast::Span::empty)
+}
+
+fn field_span(ast: &ast::SchemaAst, model: &str, field: &str) -> ast::Span {
+    ast.find_field(model, field)
+        .map(|field| field.name.span)
+        .unwrap_or_else(ast::Span::empty)
+}
+
+struct NamedItem<'a> {
+    description: String,
+    name: &'a str,
+    span: ast::Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Convention {
+    CamelCase,
+    SnakeCase,
+}
+
+impl Convention {
+    /// Single lowercase words, like `id`, match both conventions.
+    fn of(name: &str) -> Option<Convention> {
+        let has_uppercase = name.chars().any(char::is_uppercase);
+
+        match (name.contains('_'), has_uppercase) {
+            (true, false) => Some(Convention::SnakeCase),
+            (false, true) => Some(Convention::CamelCase),
+            _ => None,
+        }
+    }
+}
+
+/// The names following the less common convention of the given items.
+/// Ties are decided in favour of camel case, the Prisma convention.
+fn deviating_names(items: &[NamedItem], kind: &str, camel_case: &str) -> Vec<Finding> {
+    let count = |convention| {
+        items
+            .iter()
+            .filter(|item| Convention::of(item.name) == Some(convention))
+            .count()
+    };
+
+    let describe = |convention| match convention {
+        Convention::CamelCase => camel_case,
+        Convention::SnakeCase => "snake_case",
+    };
+
+    let (expected, deviating) = if count(Convention::SnakeCase) > count(Convention::CamelCase) {
+        (Convention::SnakeCase, Convention::CamelCase)
+    } else {
+        (Convention::CamelCase, Convention::SnakeCase)
+    };
+
+    items
+        .iter()
+        .filter(|item| Convention::of(item.name) == Some(deviating))
+        .map(|item| {
+            let message = format!(
+                "{} uses {}, while most {} use {}.",
+                item.description,
+                describe(deviating),
+                kind,
+                describe(expected)
+            );
+
+            (message, item.span)
+        })
+        .collect()
+}
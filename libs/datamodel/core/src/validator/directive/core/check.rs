@@ -0,0 +1,62 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@check` directive, adding a check constraint to the table of a model.
+pub struct CheckDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for CheckDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        "check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        if obj.is_view {
+            return self.error("The `@@check` directive cannot be used on views.", args.span());
+        }
+
+        let expression = match args.default_arg("expression")?.as_str() {
+            Ok(expression) => expression,
+            Err(err) => return Err(self.parser_error(&err)),
+        };
+
+        if expression.trim().is_empty() {
+            return self.error("The check expression must not be empty.", args.span());
+        }
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg?.as_str()?),
+            None => None,
+        };
+
+        obj.checks.push(dml::CheckDefinition { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = model
+            .checks
+            .iter()
+            .map(|check| {
+                let mut args = vec![ast::Argument::new_string("", &check.expression)];
+
+                if let Some(name) = &check.name {
+                    args.push(ast::Argument::new_string("name", name));
+                }
+
+                ast::Directive::new(self.directive_name(), args)
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
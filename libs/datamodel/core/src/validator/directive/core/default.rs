@@ -15,7 +15,7 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
             return self.error("Cannot set a default value on list field.", args.span());
         }
 
-        if let dml::FieldType::Base(scalar_type) = field.field_type {
+        if let Some(scalar_type) = field.field_type.scalar_type() {
             match args.default_arg("value")?.as_type(scalar_type) {
                 // TODO: Here, a default value directive can override the default value syntax sugar.
                 Ok(value) => field.default_value = Some(value),
@@ -0,0 +1,40 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@definition` directive, holding the SQL query of a view.
+pub struct DefinitionDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for DefinitionDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        "definition"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        if !obj.is_view {
+            return self.error("The `@@definition` directive can only be used on views.", args.span());
+        }
+
+        match args.default_arg("sql")?.as_str() {
+            Ok(definition) => obj.view_definition = Some(definition),
+            Err(err) => return Err(self.parser_error(&err)),
+        };
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(definition) = &model.view_definition {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", definition)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
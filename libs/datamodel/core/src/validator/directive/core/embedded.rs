@@ -19,7 +19,8 @@ impl DirectiveValidator<dml::Model> for EmbeddedDirectiveValidator {
         model: &dml::Model,
         _datamodel: &dml::Datamodel,
     ) -> Result<Vec<ast::Directive>, DatamodelError> {
-        if model.is_embedded {
+        // Composite types are embedded by definition.
+        if model.is_embedded && !model.is_composite_type {
             return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
         }
 
@@ -12,13 +12,9 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
-        let strategy = match (&obj.field_type, &obj.default_value) {
-            (dml::FieldType::Base(dml::ScalarType::Int), Some(ScalarValue::Expression(_, _, _))) => {
-                dml::IdStrategy::Auto
-            }
-            (dml::FieldType::Base(dml::ScalarType::String), Some(ScalarValue::Expression(_, _, _))) => {
-                dml::IdStrategy::Auto
-            }
+        let strategy = match (obj.field_type.scalar_type(), &obj.default_value) {
+            (Some(dml::ScalarType::Int), Some(ScalarValue::Expression(_, _, _))) => dml::IdStrategy::Auto,
+            (Some(dml::ScalarType::String), Some(ScalarValue::Expression(_, _, _))) => dml::IdStrategy::Auto,
             _ => dml::IdStrategy::None,
         };
 
@@ -1,11 +1,14 @@
 use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 
+mod check;
 mod default;
+mod definition;
 mod embedded;
 mod id;
 mod map;
 mod relation;
+mod schema;
 mod sequence;
 mod unique_and_index;
 mod updated_at;
@@ -35,6 +38,9 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(definition::DefinitionDirectiveValidator {}));
+    validator.add(Box::new(check::CheckDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
 
     validator
 }
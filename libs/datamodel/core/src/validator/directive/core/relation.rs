@@ -27,10 +27,30 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 relation_info.to_fields = related_fields.as_array()?.to_literal_vec()?;
             }
 
+            if let Ok(base_fields) = args.arg("fields") {
+                let span = base_fields.span();
+                relation_info.fields = base_fields.as_array()?.to_literal_vec()?;
+
+                if relation_info.fields.len() != relation_info.to_fields.len() {
+                    return self.error(
+                        "The `fields` and `references` arguments of a relation must have the same length.",
+                        span,
+                    );
+                }
+            }
+
             if let Ok(on_delete) = args.arg("onDelete") {
                 relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
             }
 
+            if let Ok(table) = args.arg("table") {
+                relation_info.table = Some(table.as_str()?);
+            }
+
+            if let Ok(column) = args.arg("column") {
+                relation_info.column = Some(column.as_str()?);
+            }
+
             Ok(())
         } else {
             self.error("Invalid field type, not a relation.", args.span())
@@ -64,9 +84,22 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
 
             relation_fields.sort();
             all_related_ids.sort();
-            if !relation_info.to_fields.is_empty()
-                && relation_fields != all_related_ids
-                && parent_model.name < related_model.name
+
+            // Relations over several scalar fields always need both sides spelled out.
+            if !relation_info.fields.is_empty() {
+                let base_fields: Vec<ast::Expression> = relation_info
+                    .fields
+                    .iter()
+                    .map(|field| ast::Expression::ConstantValue(field.clone(), ast::Span::empty()))
+                    .collect();
+
+                args.push(ast::Argument::new_array("fields", base_fields));
+            }
+
+            if !relation_info.fields.is_empty()
+                || (!relation_info.to_fields.is_empty()
+                    && relation_fields != all_related_ids
+                    && parent_model.name < related_model.name)
             {
                 let mut related_fields: Vec<ast::Expression> = Vec::new();
                 for related_field in &relation_info.to_fields {
@@ -79,6 +112,14 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_array("references", related_fields));
             }
 
+            if let Some(table) = &relation_info.table {
+                args.push(ast::Argument::new_string("table", table));
+            }
+
+            if let Some(column) = &relation_info.column {
+                args.push(ast::Argument::new_string("column", column));
+            }
+
             if relation_info.on_delete != dml::OnDeleteStrategy::None {
                 args.push(ast::Argument::new_constant(
                     "onDelete",
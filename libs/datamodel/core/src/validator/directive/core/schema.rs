@@ -0,0 +1,36 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@schema` directive, placing the table of a model in a non-default database schema.
+pub struct SchemaDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for SchemaDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        "schema"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        match args.default_arg("name")?.as_str() {
+            Ok(schema) => obj.schema = Some(schema),
+            Err(err) => return Err(self.parser_error(&err)),
+        };
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(schema) = &model.schema {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", schema)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -1,6 +1,6 @@
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexAlgorithm, IndexDefinition, IndexType, SortOrder};
 
 /// Prismas builtin `@unique` directive.
 pub struct FieldLevelUniqueDirectiveValidator {}
@@ -106,6 +106,10 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            sort_orders: vec![],
+            expression: None,
+            predicate: None,
+            algorithm: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg?.as_str()?),
@@ -113,12 +117,65 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         };
         index_def.name = name;
 
-        match args.default_arg("fields")?.as_array() {
-            Ok(fields) => {
-                let fields = fields.iter().map(|f| f.as_constant_literal().unwrap()).collect();
-                index_def.fields = fields;
+        if let Some(expression) = args.optional_arg("expression") {
+            index_def.expression = Some(expression?.as_str()?);
+        }
+
+        match (args.optional_default_arg("fields"), &index_def.expression) {
+            (Some(fields), None) => match fields?.as_array() {
+                Ok(fields) => {
+                    let fields = fields.iter().map(|f| f.as_constant_literal().unwrap()).collect();
+                    index_def.fields = fields;
+                }
+                Err(err) => return Err(self.parser_error(&err)),
+            },
+            (None, Some(_)) if index_def.name.is_none() => {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "An index over an expression must have a name.",
+                    self.directive_name(),
+                    args.span(),
+                ))
+            }
+            (None, Some(_)) => (),
+            (Some(_), Some(_)) => {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "An index is defined either by its fields or by an expression, but not both.",
+                    self.directive_name(),
+                    args.span(),
+                ))
+            }
+            (None, None) => return Err(DatamodelError::new_argument_not_found_error("fields", args.span())),
+        }
+
+        if let Some(sort_orders) = args.optional_arg("sort") {
+            let sort_orders = match sort_orders?.as_array() {
+                Ok(sort_orders) => sort_orders
+                    .iter()
+                    .map(|sort_order| sort_order.parse_literal::<SortOrder>())
+                    .collect::<Result<Vec<_>, _>>()?,
+                Err(err) => return Err(self.parser_error(&err)),
+            };
+
+            if sort_orders.len() != index_def.fields.len() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "The `sort` argument must have one sort order for each field of the index.",
+                    self.directive_name(),
+                    args.span(),
+                ));
+            }
+
+            // All ascending is the default, which is represented without sort orders.
+            if sort_orders.contains(&SortOrder::Desc) {
+                index_def.sort_orders = sort_orders;
             }
-            Err(err) => return Err(self.parser_error(&err)),
+        }
+
+        if let Some(predicate) = args.optional_arg("where") {
+            index_def.predicate = Some(predicate?.as_str()?);
+        }
+
+        if let Some(algorithm) = args.optional_arg("type") {
+            index_def.algorithm = Some(algorithm?.parse_literal::<IndexAlgorithm>()?);
         }
 
         let undefined_fields: Vec<String> = index_def
@@ -160,17 +217,40 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             .map(|index_def| {
                 let mut args = Vec::new();
 
-                args.push(ast::Argument::new_array(
-                    "",
-                    index_def
-                        .fields
-                        .iter()
-                        .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
-                        .collect(),
-                ));
+                if index_def.expression.is_none() {
+                    args.push(ast::Argument::new_array(
+                        "",
+                        index_def
+                            .fields
+                            .iter()
+                            .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                            .collect(),
+                    ));
+                }
+                if !index_def.sort_orders.is_empty() {
+                    args.push(ast::Argument::new_array(
+                        "sort",
+                        index_def
+                            .sort_orders
+                            .iter()
+                            .map(|sort_order| {
+                                ast::Expression::ConstantValue(sort_order.to_string(), ast::Span::empty())
+                            })
+                            .collect(),
+                    ));
+                }
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if let Some(expression) = &index_def.expression {
+                    args.push(ast::Argument::new_string("expression", expression));
+                }
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", predicate));
+                }
+                if let Some(algorithm) = &index_def.algorithm {
+                    args.push(ast::Argument::new_constant("type", &algorithm.to_string()));
+                }
 
                 ast::Directive::new(self.directive_name(), args)
             })
@@ -6,7 +6,7 @@ use crate::{
     configuration, dml,
     error::{DatamodelError, ErrorCollection},
 };
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::{Connector, ScalarFieldType};
 
 /// Helper for lifting a datamodel.
 ///
@@ -15,9 +15,11 @@ use datamodel_connector::{Connector, ExampleConnector};
 /// additional semantics are attached.
 pub struct LiftAstToDml {
     directives: DirectiveBox,
+    connector: Option<Box<dyn Connector>>,
 }
 
-const USE_CONNECTORS_FOR_CUSTOM_TYPES: bool = false; // FEATURE FLAG
+/// Directives of the form `@db.<Type>(args)` declare the native type of a field.
+const NATIVE_TYPE_PREFIX: &str = "db.";
 
 impl LiftAstToDml {
     /// Creates a new instance, with all builtin directives and
@@ -27,6 +29,7 @@ impl LiftAstToDml {
     pub fn with_sources(sources: &[Box<dyn configuration::Source + Send + Sync>]) -> LiftAstToDml {
         LiftAstToDml {
             directives: DirectiveBox::with_sources(sources),
+            connector: sources.first().map(|source| source.connector()),
         }
     }
 
@@ -46,6 +49,7 @@ impl LiftAstToDml {
                 },
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
+                ast::Top::Linter(_) => { /* Linter blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
                 ast::Top::Type(_) => { /* Type blocks are inlined */ }
             }
@@ -62,6 +66,9 @@ impl LiftAstToDml {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, ErrorCollection> {
         let mut model = dml::Model::new(&ast_model.name.name);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
+        model.is_composite_type = ast_model.is_composite_type;
+        model.is_embedded = ast_model.is_composite_type;
 
         let mut errors = ErrorCollection::new();
 
@@ -117,8 +124,8 @@ impl LiftAstToDml {
         if let Some(value) = &ast_field.default_value {
             let validator = ValueValidator::new(value)?;
 
-            if let dml::FieldType::Base(base_type) = &field_type {
-                match validator.as_type(*base_type) {
+            if let Some(scalar_type) = field_type.scalar_type() {
+                match validator.as_type(scalar_type) {
                     Ok(val) => field.default_value = Some(val),
                     Err(err) => errors.push(err),
                 };
@@ -131,7 +138,12 @@ impl LiftAstToDml {
         }
 
         // We merge arttributes so we can fail on duplicates.
-        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
+        // Native type directives were already consumed when lifting the field type.
+        let attributes: Vec<ast::Directive> = [&extra_attributes[..], &ast_field.directives[..]]
+            .concat()
+            .into_iter()
+            .filter(|directive| !is_native_type_directive(directive))
+            .collect();
 
         if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
@@ -164,26 +176,16 @@ impl LiftAstToDml {
         let type_name = &ast_field.field_type.name;
 
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
-            if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-                let pg_connector = ExampleConnector::postgres();
-                let args = vec![]; // TODO: figure out args
-                let pg_type_specification = ast_field
-                    .directives
-                    .iter()
-                    .find(|dir| dir.name.name.starts_with("pg.")) // we use find because there should be at max 1.
-                    .map(|dir| dir.name.name.trim_start_matches("pg."));
-
-                if let Some(x) = pg_type_specification.and_then(|ts| pg_connector.calculate_type(&ts, args)) {
-                    let field_type = dml::FieldType::ConnectorSpecific(x);
-                    Ok((field_type, vec![]))
-                } else {
-                    Ok((dml::FieldType::Base(scalar_type), vec![]))
-                }
+            match self.lift_native_type(ast_field, scalar_type)? {
+                Some(native_type) => Ok((dml::FieldType::ConnectorSpecific(native_type), vec![])),
+                None => Ok((dml::FieldType::Base(scalar_type), vec![])),
+            }
+        } else if let Some(model) = ast_schema.find_model(type_name) {
+            if model.is_composite_type {
+                Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
             } else {
-                Ok((dml::FieldType::Base(scalar_type), vec![]))
+                Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
             }
-        } else if ast_schema.find_model(type_name).is_some() {
-            Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
         } else {
@@ -215,7 +217,7 @@ impl LiftAstToDml {
             checked_types.push(custom_type.name.name.clone());
             let (field_type, mut attrs) = self.lift_field_type(custom_type, ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if field_type.is_relation() || field_type.is_composite_type() {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -224,19 +226,6 @@ impl LiftAstToDml {
 
             attrs.append(&mut custom_type.directives.clone());
             Ok((field_type, attrs))
-        } else if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-            let pg_connector = ExampleConnector::postgres();
-            let args = vec![]; // TODO: figure out args
-
-            if let Some(x) = pg_connector.calculate_type(&ast_field.field_type.name, args) {
-                let field_type = dml::FieldType::ConnectorSpecific(x);
-                Ok((field_type, vec![]))
-            } else {
-                Err(DatamodelError::new_type_not_found_error(
-                    type_name,
-                    ast_field.field_type.span,
-                ))
-            }
         } else {
             Err(DatamodelError::new_type_not_found_error(
                 type_name,
@@ -244,4 +233,60 @@ impl LiftAstToDml {
             ))
         }
     }
+
+    /// Internal: Resolves the `@db.<Type>(args)` directive of a scalar field, if there is one,
+    /// with the connector of the datasource.
+    fn lift_native_type(
+        &self,
+        ast_field: &ast::Field,
+        scalar_type: ScalarType,
+    ) -> Result<Option<ScalarFieldType>, DatamodelError> {
+        // We use find because there should be at most one.
+        let directive = match ast_field.directives.iter().find(|dir| is_native_type_directive(dir)) {
+            Some(directive) => directive,
+            None => return Ok(None),
+        };
+        let directive_name = &directive.name.name;
+
+        let connector = match &self.connector {
+            Some(connector) => connector,
+            None => {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "Native types can only be used with a datasource.",
+                    directive_name,
+                    directive.span,
+                ))
+            }
+        };
+
+        let args = directive
+            .arguments
+            .iter()
+            .map(|arg| ValueValidator::new(&arg.value)?.as_int())
+            .collect::<Result<Vec<i32>, DatamodelError>>()?;
+
+        let native_type = connector
+            .calculate_type(directive_name.trim_start_matches(NATIVE_TYPE_PREFIX), args)
+            .map_err(|err| {
+                DatamodelError::new_directive_validation_error(&err.to_string(), directive_name, directive.span)
+            })?;
+
+        if native_type.prisma_type() != scalar_type {
+            return Err(DatamodelError::new_directive_validation_error(
+                &format!(
+                    "Native type {} can not be used on a field of type {}.",
+                    native_type.name(),
+                    scalar_type.to_string()
+                ),
+                directive_name,
+                directive.span,
+            ));
+        }
+
+        Ok(Some(native_type))
+    }
+}
+
+fn is_native_type_directive(directive: &ast::Directive) -> bool {
+    directive.name.name.starts_with(NATIVE_TYPE_PREFIX)
 }
@@ -2,6 +2,7 @@ use super::{common::*, DirectiveBox};
 use crate::configuration;
 use crate::error::ErrorCollection;
 use crate::{ast, dml};
+use datamodel_connector::ScalarFieldType;
 
 pub struct LowerDmlToAst {
     directives: DirectiveBox,
@@ -71,6 +72,8 @@ impl LowerDmlToAst {
             fields,
             directives: self.directives.model.serialize(model, datamodel)?,
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
+            is_view: model.is_view,
+            is_composite_type: model.is_composite_type,
             span: ast::Span::empty(),
         })
     }
@@ -98,14 +101,21 @@ impl LowerDmlToAst {
         model: &dml::Model,
         datamodel: &dml::Datamodel,
     ) -> Result<ast::Field, ErrorCollection> {
+        let mut directives = self.directives.field.serialize(field, datamodel)?;
+
+        if let dml::FieldType::ConnectorSpecific(native_type) = &field.field_type {
+            directives.push(self.lower_native_type(native_type));
+        }
+
         Ok(ast::Field {
             name: ast::Identifier::new(&field.name),
             arity: self.lower_field_arity(field.arity),
             default_value: field.default_value.clone().map(|v| v.into()),
-            directives: self.directives.field.serialize(field, datamodel)?,
+            directives,
             field_type: self.lower_type(&field.field_type, field, model, &datamodel),
             documentation: field.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
+            is_commented_out: field.field_type.is_unsupported(),
         })
     }
 
@@ -128,7 +138,12 @@ impl LowerDmlToAst {
     ) -> ast::Identifier {
         match field_type {
             dml::FieldType::Base(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::ConnectorSpecific(native_type) => {
+                ast::Identifier::new(&native_type.prisma_type().to_string())
+            }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&format!("Unsupported(\"{}\")", tpe)),
             dml::FieldType::Relation(rel) => {
                 let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
 
@@ -154,7 +169,17 @@ impl LowerDmlToAst {
                     ast::Identifier::new(&rel.to)
                 }
             }
-            _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
+
+    /// Internal: Lowers a connector specific type to its `@db.<Type>(args)` directive.
+    fn lower_native_type(&self, native_type: &ScalarFieldType) -> ast::Directive {
+        let args = native_type
+            .args()
+            .iter()
+            .map(|arg| ast::Argument::new("", ast::Expression::NumericValue(arg.to_string(), ast::Span::empty())))
+            .collect();
+
+        ast::Directive::new(&format!("db.{}", native_type.name()), args)
+    }
 }
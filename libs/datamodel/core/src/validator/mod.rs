@@ -13,6 +13,6 @@ use directive_box::*;
 
 use lift::*;
 pub use lower::*;
-use standardise::*;
+pub(crate) use standardise::*;
 use validate::*;
 pub use validation_pipeline::*;
@@ -13,6 +13,7 @@ impl Precheck {
         let mut top_level_types_checker = DuplicateChecker::new();
         let mut sources_checker = DuplicateChecker::new();
         let mut generators_checker = DuplicateChecker::new();
+        let mut linters_checker = DuplicateChecker::new();
 
         for top in &datamodel.tops {
             let error_fn = |existing: &ast::Top| {
@@ -48,12 +49,18 @@ impl Precheck {
                     generators_checker.check_if_duplicate_exists(top, error_fn);
                     Self::precheck_generator_config(&generator, &mut errors);
                 }
+                ast::Top::Linter(linter) => {
+                    Self::assert_is_not_a_reserved_scalar_type(&linter.name, &mut errors);
+                    linters_checker.check_if_duplicate_exists(top, error_fn);
+                    Self::precheck_linter_config(linter, &mut errors);
+                }
             }
         }
 
         errors.append(&mut top_level_types_checker.errors());
         errors.append(&mut sources_checker.errors());
         errors.append(&mut generators_checker.errors());
+        errors.append(&mut linters_checker.errors());
 
         errors.ok()
     }
@@ -101,6 +108,20 @@ impl Precheck {
         errors.append(&mut checker.errors());
     }
 
+    fn precheck_linter_config(config: &ast::LinterConfig, errors: &mut ErrorCollection) {
+        let mut checker = DuplicateChecker::new();
+        for arg in &config.properties {
+            checker.check_if_duplicate_exists(arg, |_| {
+                DatamodelError::new_duplicate_config_key_error(
+                    &format!("linter configuration \"{}\"", config.name.name),
+                    &arg.name.name,
+                    arg.identifier().span,
+                )
+            });
+        }
+        errors.append(&mut checker.errors());
+    }
+
     fn precheck_source_config(config: &ast::SourceConfig, errors: &mut ErrorCollection) {
         let mut checker = DuplicateChecker::new();
         for arg in &config.properties {
@@ -145,10 +145,15 @@ impl Standardiser {
             name: relation_name,
             database_name: None,
             is_embedded: false,
+            is_composite_type: false,
             fields: vec![a_related_field, b_related_field],
             indexes: vec![],
             id_fields: vec![],
             is_generated: true,
+            is_view: false,
+            view_definition: None,
+            checks: vec![],
+            schema: None,
         }
     }
 
@@ -157,9 +162,12 @@ impl Standardiser {
             &NameNormalizer::camel_case(&model.name),
             dml::FieldType::Relation(dml::RelationInfo {
                 to: model.name.clone(),
+                fields: Vec::new(),
                 to_fields: model.id_field_names(),
                 name: String::from(relation_name), // Will be corrected in later step
                 on_delete: dml::OnDeleteStrategy::None,
+                table: None,
+                column: None,
             }),
         )
     }
@@ -219,12 +227,7 @@ impl Standardiser {
     ) -> Result<(), ErrorCollection> {
         let mut errors = ErrorCollection::new();
 
-        let mut missing_back_relation_fields = Vec::new();
-        for model in &schema.models {
-            missing_back_relation_fields.append(&mut self.find_missing_back_relation_fields(&model, schema));
-        }
-
-        for missing_back_relation_field in missing_back_relation_fields {
+        for missing_back_relation_field in self.missing_back_relation_fields(schema) {
             let model = schema
                 .find_model(&missing_back_relation_field.model)
                 .expect(STATE_ERROR);
@@ -265,6 +268,15 @@ impl Standardiser {
         }
     }
 
+    /// The back relation fields the standardisation adds to the datamodel.
+    pub(crate) fn missing_back_relation_fields(&self, schema: &dml::Datamodel) -> Vec<AddMissingBackRelationField> {
+        schema
+            .models
+            .iter()
+            .flat_map(|model| self.find_missing_back_relation_fields(model, schema))
+            .collect()
+    }
+
     fn find_missing_back_relation_fields(
         &self,
         model: &dml::Model,
@@ -286,9 +298,12 @@ impl Standardiser {
                 if !back_field_exists {
                     let relation_info = dml::RelationInfo {
                         to: model.name.clone(),
+                        fields: Vec::new(),
                         to_fields: vec![],
                         name: rel.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        table: None,
+                        column: None,
                     };
 
                     let (arity, field_name) = if field.arity.is_singular() {
@@ -379,11 +394,11 @@ impl Standardiser {
 }
 
 #[derive(Debug)]
-struct AddMissingBackRelationField {
-    model: String,
-    field: String,
-    arity: dml::FieldArity,
-    relation_info: dml::RelationInfo,
-    related_model: String,
-    related_field: String,
+pub(crate) struct AddMissingBackRelationField {
+    pub model: String,
+    pub field: String,
+    pub arity: dml::FieldArity,
+    pub relation_info: dml::RelationInfo,
+    pub related_model: String,
+    pub related_field: String,
 }
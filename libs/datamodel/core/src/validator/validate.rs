@@ -1,5 +1,7 @@
 use crate::{
-    ast, configuration, dml,
+    ast,
+    common::functions::DB_GENERATED,
+    configuration, dml,
     error::{DatamodelError, ErrorCollection},
     FieldArity,
 };
@@ -38,6 +40,28 @@ impl<'a> Validator<'a> {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors.push(err);
             }
+            if let Err(err) = self.validate_composite_type_fields(ast_schema, model) {
+                errors.push(err);
+            }
+            if let Err(err) = self.validate_views_have_no_relations(ast_schema, schema, model) {
+                errors.push(err);
+            }
+            if let Err(err) = self.validate_relation_fields_exist(ast_schema, schema, model) {
+                errors.push(err);
+            }
+            if let Err(err) = self.validate_relation_tables(ast_schema, schema, model) {
+                errors.push(err);
+            }
+            if let Err(err) =
+                self.validate_schema_is_declared(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.push(err);
+            }
+            if let Err(err) =
+                self.validate_index_arguments_supported(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.push(err);
+            }
 
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
@@ -63,7 +87,11 @@ impl<'a> Validator<'a> {
         };
 
         for field in model.fields() {
-            if field.arity == FieldArity::List && !scalar_lists_are_supported && !field.field_type.is_relation() {
+            if field.arity == FieldArity::List
+                && !scalar_lists_are_supported
+                && !field.field_type.is_relation()
+                && !field.field_type.is_composite_type()
+            {
                 let ast_field = ast_model
                     .fields
                     .iter()
@@ -86,9 +114,19 @@ impl<'a> Validator<'a> {
     }
 
     fn validate_model_has_id(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        // Extempt from the id rule, we have an relation table.
         if model.is_relation_model() {
             return Ok(());
-            // Extempt from the id rule, we have an relation table.
+        }
+
+        // Composite types are stored inside of records, they are not identified on their own.
+        if model.is_composite_type {
+            return Ok(());
+        }
+
+        // Views without an id can be migrated and introspected, but not queried.
+        if model.is_view && !model.has_id() {
+            return Ok(());
         }
 
         let multiple_single_field_id_error = Err(DatamodelError::new_model_validation_error(
@@ -114,41 +152,37 @@ impl<'a> Validator<'a> {
 
     fn validate_id_fields_valid(&self, ast_schema: &ast::SchemaAst, model: &dml::Model) -> Result<(), DatamodelError> {
         for id_field in model.singular_id_fields() {
-            let is_valid = match (&id_field.default_value, &id_field.field_type, &id_field.arity) {
+            let is_valid = match (
+                &id_field.default_value,
+                id_field.field_type.scalar_type(),
+                &id_field.arity,
+            ) {
                 (
                     Some(dml::ScalarValue::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::String),
+                    Some(dml::ScalarType::String),
                     dml::FieldArity::Required,
                 ) => {
-                    let name_eq = name == "cuid" || name == "uuid";
+                    let name_eq = name == "cuid" || name == "uuid" || name == DB_GENERATED;
                     let type_eq = return_type == &dml::ScalarType::String;
-                    let args_eq = args.is_empty();
+                    let args_eq = args.is_empty() || name == DB_GENERATED;
 
                     name_eq && type_eq && args_eq
                 }
-                (
-                    Some(dml::ScalarValue::String(_)),
-                    dml::FieldType::Base(dml::ScalarType::String),
-                    dml::FieldArity::Required,
-                ) => true,
-                (
-                    Some(dml::ScalarValue::Int(_)),
-                    dml::FieldType::Base(dml::ScalarType::Int),
-                    dml::FieldArity::Required,
-                ) => true,
+                (Some(dml::ScalarValue::String(_)), Some(dml::ScalarType::String), dml::FieldArity::Required) => true,
+                (Some(dml::ScalarValue::Int(_)), Some(dml::ScalarType::Int), dml::FieldArity::Required) => true,
                 (
                     Some(dml::ScalarValue::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::Int),
+                    Some(dml::ScalarType::Int),
                     dml::FieldArity::Required,
                 ) => {
-                    let name_eq = name == "autoincrement";
+                    let name_eq = name == "autoincrement" || name == DB_GENERATED;
                     let type_eq = return_type == &dml::ScalarType::Int;
-                    let args_eq = args.is_empty();
+                    let args_eq = args.is_empty() || name == DB_GENERATED;
 
                     name_eq && type_eq && args_eq
                 }
-                (None, dml::FieldType::Base(dml::ScalarType::Int), dml::FieldArity::Required) => true,
-                (None, dml::FieldType::Base(dml::ScalarType::String), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::Int), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::String), dml::FieldArity::Required) => true,
                 _ => false,
             };
 
@@ -171,7 +205,8 @@ impl<'a> Validator<'a> {
         datamodel: &dml::Datamodel,
         model: &dml::Model,
     ) -> Result<(), DatamodelError> {
-        if model.is_embedded {
+        // Relation fields on composite types are rejected altogether.
+        if model.is_embedded && !model.is_composite_type {
             for field in model.fields() {
                 if !field.is_generated {
                     if let dml::FieldType::Relation(rel) = &field.field_type {
@@ -195,6 +230,242 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    /// Ensures that composite types only contain values, and that fields using a composite type
+    /// are not lists, since each of them is stored in a single column.
+    fn validate_composite_type_fields(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            let span = || ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+
+            if model.is_composite_type && field.field_type.is_relation() {
+                return Err(DatamodelError::new_model_validation_error(
+                    "Composite types can only have scalar, enum and composite type fields.",
+                    &model.name,
+                    span(),
+                ));
+            }
+
+            if field.field_type.is_composite_type() && field.arity == FieldArity::List {
+                return Err(DatamodelError::new_model_validation_error(
+                    "Lists of composite types are not supported.",
+                    &model.name,
+                    span(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that views neither have relation fields nor are
+    /// the target of relation fields, since they are read-only.
+    fn validate_views_have_no_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let points_to_view = datamodel.find_model(&rel.to).map(|m| m.is_view).unwrap_or(false);
+
+                if model.is_view || points_to_view {
+                    return Err(DatamodelError::new_model_validation_error(
+                        "Views cannot be part of relations.",
+                        &model.name,
+                        ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the `fields` and `references` of relations over several
+    /// scalar fields point to existing scalar fields.
+    fn validate_relation_fields_exist(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        let missing_scalar_fields = |model: &dml::Model, names: &[String]| -> Vec<String> {
+            names
+                .iter()
+                .filter(|name| match model.find_field(name) {
+                    Some(field) => field.field_type.is_relation(),
+                    None => true,
+                })
+                .cloned()
+                .collect()
+        };
+
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) if !rel.fields.is_empty() => rel,
+                _ => continue,
+            };
+            let span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+
+            let missing_fields = missing_scalar_fields(model, &rel.fields);
+            if !missing_fields.is_empty() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    &format!(
+                        "The argument `fields` must refer only to existing scalar fields. The following fields do not exist in model `{}`: {}",
+                        model.name,
+                        missing_fields.join(", ")
+                    ),
+                    "relation",
+                    span,
+                ));
+            }
+
+            let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+            let missing_references = missing_scalar_fields(related_model, &rel.to_fields);
+            if !missing_references.is_empty() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    &format!(
+                        "The argument `references` must refer only to existing scalar fields. The following fields do not exist in model `{}`: {}",
+                        related_model.name,
+                        missing_references.join(", ")
+                    ),
+                    "relation",
+                    span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that custom join tables are only given on many-to-many relations,
+    /// and that both sides of the relation agree on the table.
+    fn validate_relation_tables(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) if rel.table.is_some() || rel.column.is_some() => rel,
+                _ => continue,
+            };
+            let span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+            let related_field = datamodel.related_field(&model.name, &rel.to, &rel.name, &field.name);
+
+            let related_rel = match related_field.map(|f| (f.arity, &f.field_type)) {
+                Some((FieldArity::List, dml::FieldType::Relation(related_rel))) if field.arity == FieldArity::List => {
+                    related_rel
+                }
+                _ => {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        "The arguments `table` and `column` are only allowed on many-to-many relations.",
+                        "relation",
+                        span,
+                    ))
+                }
+            };
+
+            if let (Some(table), Some(related_table)) = (&rel.table, &related_rel.table) {
+                if table != related_table {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        &format!(
+                            "Both sides of a many-to-many relation must use the same join table, but the related field uses `{}`.",
+                            related_table
+                        ),
+                        "relation",
+                        span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the schema given in `@@schema` is listed in the `schemas` property of the datasource.
+    fn validate_schema_is_declared(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let schema = match &model.schema {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+
+        let declared_schemas = self.source.map(|source| source.schemas()).unwrap_or(&[]);
+
+        if declared_schemas.iter().any(|declared| declared == schema) {
+            return Ok(());
+        }
+
+        let span = ast_model
+            .directives
+            .iter()
+            .find(|directive| directive.name.name == "schema")
+            .map(|directive| directive.span)
+            .unwrap_or(ast_model.span);
+
+        let message = if declared_schemas.is_empty() {
+            String::from("The `@@schema` directive requires the `schemas` property to be set on the datasource.")
+        } else {
+            format!(
+                "The schema `{}` is not listed in the `schemas` property of the datasource.",
+                schema
+            )
+        };
+
+        Err(DatamodelError::new_directive_validation_error(&message, "schema", span))
+    }
+
+    /// Ensures that the datasource supports the conditions and methods of the indexes of the model.
+    fn validate_index_arguments_supported(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        let source = match self.source {
+            Some(source) => source,
+            None => return Ok(()),
+        };
+
+        for index in &model.indexes {
+            let directive_name = match index.tpe {
+                dml::IndexType::Unique => "unique",
+                dml::IndexType::Normal => "index",
+            };
+
+            if index.predicate.is_some() && !source.supports_partial_indexes() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    &format!(
+                        "The `where` argument is not supported by the `{}` connector.",
+                        source.connector_type()
+                    ),
+                    directive_name,
+                    index_argument_span(ast_model, directive_name, "where"),
+                ));
+            }
+
+            if let Some(algorithm) = index.algorithm {
+                if !source.index_algorithms().contains(&algorithm) {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        &format!(
+                            "The index type `{}` is not supported by the `{}` connector.",
+                            algorithm,
+                            source.connector_type()
+                        ),
+                        directive_name,
+                        index_argument_span(ast_model, directive_name, "type"),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Elegantly checks if any relations in the model are ambigious.
     fn validate_relations_not_ambiguous(
         &self,
@@ -320,3 +591,15 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 }
+
+/// The span of the first argument with the given name on an index directive of the model.
+fn index_argument_span(ast_model: &ast::Model, directive_name: &str, argument_name: &str) -> ast::Span {
+    ast_model
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == directive_name)
+        .flat_map(|directive| directive.arguments.iter())
+        .find(|argument| argument.name.name == argument_name)
+        .map(|argument| argument.span)
+        .unwrap_or(ast_model.span)
+}
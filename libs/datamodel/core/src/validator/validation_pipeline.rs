@@ -31,6 +31,17 @@ impl<'a> ValidationPipeline<'a> {
     /// * Resolve and check default values
     /// * Resolve and check all field types
     pub fn validate(&self, ast_schema: &ast::SchemaAst) -> Result<dml::Datamodel, ErrorCollection> {
+        let mut schema = self.lift_and_validate(ast_schema)?;
+
+        // TODO: Move consistency stuff into different module.
+        // Phase 5: Consistency fixes. These don't fail.
+        self.standardiser.standardise(ast_schema, &mut schema)?;
+
+        Ok(schema)
+    }
+
+    /// Runs all phases but the standardisation, so the datamodel only contains what is declared in the AST.
+    pub(crate) fn lift_and_validate(&self, ast_schema: &ast::SchemaAst) -> Result<dml::Datamodel, ErrorCollection> {
         let mut all_errors = ErrorCollection::new();
 
         // Phase 0 is parsing.
@@ -56,17 +67,7 @@ impl<'a> ValidationPipeline<'a> {
             all_errors.append(&mut err);
         }
 
-        // Early return so that the standardiser does not have to deal with invalid schemas
-        if all_errors.has_errors() {
-            return Err(all_errors);
-        }
-
-        // TODO: Move consistency stuff into different module.
-        // Phase 5: Consistency fixes. These don't fail.
-        if let Err(mut err) = self.standardiser.standardise(ast_schema, &mut schema) {
-            all_errors.append(&mut err);
-        }
-
+        // The standardiser does not have to deal with invalid schemas.
         if all_errors.has_errors() {
             Err(all_errors)
         } else {
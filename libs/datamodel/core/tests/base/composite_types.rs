@@ -0,0 +1,101 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::ScalarType, dml, error::DatamodelError};
+
+#[test]
+fn parse_composite_type() {
+    let dml = r#"
+    /// A postal address.
+    type Address {
+        street String
+        zip String?
+    }
+
+    model User {
+        id Int @id
+        address Address
+        billingAddress Address?
+    }
+    "#;
+
+    let schema = parse(dml);
+    let address = schema.assert_has_model("Address");
+    address
+        .assert_is_embedded(true)
+        .assert_with_documentation("A postal address.");
+    assert!(address.is_composite_type);
+    address.assert_has_field("street").assert_base_type(&ScalarType::String);
+
+    let user = schema.assert_has_model("User");
+    assert!(!user.is_composite_type);
+    user.assert_has_field("address")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::Required);
+    user.assert_has_field("billingAddress")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::Optional);
+}
+
+#[test]
+fn composite_types_are_rendered_with_the_type_keyword() {
+    let dml = r#"type Address {
+  street String
+  zip    String?
+}
+
+model User {
+  id      Int     @id
+  address Address
+}"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn fail_on_relation_field_in_composite_type() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    type Address {
+        street String
+        user User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_model_validation_error(
+            "Composite types can only have scalar, enum and composite type fields.",
+            "Address",
+            Span::new(93, 102),
+        ),
+    );
+}
+
+#[test]
+fn fail_on_list_of_composite_type() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id Int @id
+        addresses Address[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "Lists of composite types are not supported.",
+        "User",
+        Span::new(93, 112),
+    ));
+}
@@ -0,0 +1,107 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::ScalarType, error::DatamodelError};
+
+#[test]
+fn parse_basic_view() {
+    let dml = r#"
+    /// All active users.
+    view ActiveUser {
+        id Int @id
+        name String
+
+        @@definition("SELECT id, name FROM User WHERE active")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let view = schema.assert_has_model("ActiveUser");
+    view.assert_is_view(true).assert_with_documentation("All active users.");
+    view.assert_has_field("name").assert_base_type(&ScalarType::String);
+    assert_eq!(
+        view.view_definition.as_deref(),
+        Some("SELECT id, name FROM User WHERE active")
+    );
+}
+
+#[test]
+fn views_do_not_need_an_id() {
+    let dml = r#"
+    view UserName {
+        name String
+
+        @@definition("SELECT name FROM User")
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("UserName").assert_is_view(true);
+}
+
+#[test]
+fn models_are_not_views() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("User").assert_is_view(false);
+}
+
+#[test]
+fn views_are_rendered_with_the_view_keyword() {
+    let dml = r#"view ActiveUser {
+  id   Int    @id
+  name String
+
+  @@definition("SELECT id, name FROM User")
+}"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn fail_on_definition_directive_in_model() {
+    let dml = r#"
+    model User {
+        id Int @id
+
+        @@definition("SELECT 1")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `@@definition` directive can only be used on views.",
+        "definition",
+        Span::new(48, 70),
+    ));
+}
+
+#[test]
+fn fail_on_relation_to_view() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        author Author
+    }
+
+    view Author {
+        id Int @id
+        name String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "Views cannot be part of relations.",
+        "Post",
+        Span::new(45, 58),
+    ));
+}
@@ -7,11 +7,14 @@ use datamodel_connector::ScalarFieldType;
 pub trait FieldAsserts {
     fn assert_base_type(&self, t: &ScalarType) -> &Self;
     fn assert_enum_type(&self, en: &str) -> &Self;
+    fn assert_composite_type(&self, t: &str) -> &Self;
     fn assert_connector_type(&self, sft: &ScalarFieldType) -> &Self;
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
     fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
+    fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
+    fn assert_relation_table(&self, table: Option<&str>, column: Option<&str>) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
@@ -27,6 +30,7 @@ pub trait FieldAsserts {
 pub trait ModelAsserts {
     fn assert_has_field(&self, t: &str) -> &dml::Field;
     fn assert_is_embedded(&self, t: bool) -> &Self;
+    fn assert_is_view(&self, t: bool) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
     fn assert_has_index(&self, def: IndexDefinition) -> &Self;
@@ -79,6 +83,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_composite_type(&self, t: &str) -> &Self {
+        if let dml::FieldType::CompositeType(composite_type) = &self.field_type {
+            assert_eq!(composite_type, t);
+        } else {
+            panic!("Composite type expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_relation_to(&self, t: &str) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.to, t);
@@ -119,6 +133,27 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_relation_base_fields(&self, t: &[&str]) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.fields, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
+    fn assert_relation_table(&self, table: Option<&str>, column: Option<&str>) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.table.as_deref(), table);
+            assert_eq!(info.column.as_deref(), column);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self {
         assert_eq!(self.arity, *arity);
 
@@ -211,6 +246,12 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_is_view(&self, t: bool) -> &Self {
+        assert_eq!(self.is_view, t);
+
+        self
+    }
+
     fn assert_with_db_name(&self, t: &str) -> &Self {
         assert_eq!(self.database_name, Some(String::from(t)));
 
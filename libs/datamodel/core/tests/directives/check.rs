@@ -0,0 +1,68 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, CheckDefinition};
+
+use crate::common::*;
+
+#[test]
+fn basic_check_must_work() {
+    let dml = r#"
+    model Product {
+        id    Int   @id
+        price Float
+
+        @@check("price >= 0")
+        @@check("price < 1000000", name: "price_is_sane")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+    assert_eq!(
+        product_model.checks,
+        vec![
+            CheckDefinition {
+                name: None,
+                expression: "price >= 0".to_string(),
+            },
+            CheckDefinition {
+                name: Some("price_is_sane".to_string()),
+                expression: "price < 1000000".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn must_error_on_checks_in_views() {
+    let dml = r#"
+    view Answer {
+        id Int @id
+
+        @@check("id > 0")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `@@check` directive cannot be used on views.",
+        "check",
+        Span::new(49, 64),
+    ));
+}
+
+#[test]
+fn check_directives_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Product {
+            id    Int   @id
+            price Float
+
+            @@check("price >= 0", name: "price_is_positive")
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains(r#"@@check("price >= 0", name: "price_is_positive")"#));
+    assert!(datamodel::parse_datamodel(&rendered).is_ok());
+}
@@ -1,4 +1,6 @@
-use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::Span, error::*, render_datamodel_to_string, IndexAlgorithm, IndexDefinition, IndexType, SortOrder,
+};
 
 use crate::common::*;
 
@@ -20,6 +22,10 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
@@ -41,6 +47,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
@@ -64,12 +74,20 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
@@ -107,3 +125,207 @@ fn index_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn sort_orders_conditions_and_types_must_work() {
+    let dml = r#"
+    model User {
+        id        Int       @id
+        firstName String
+        lastName  String
+        deletedAt DateTime?
+
+        @@index([firstName, lastName], sort: [ASC, DESC], where: "deletedAt IS NULL", type: HASH)
+        @@index([lastName], sort: [ASC], name: "ascending")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![SortOrder::Asc, SortOrder::Desc],
+        expression: None,
+        predicate: Some("deletedAt IS NULL".to_string()),
+        algorithm: Some(IndexAlgorithm::Hash),
+    });
+
+    // Sorting all fields ascending is the default.
+    user_model.assert_has_index(IndexDefinition {
+        name: Some("ascending".to_string()),
+        fields: vec!["lastName".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
+    });
+}
+
+#[test]
+fn expression_index_must_work() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String
+
+        @@index(name: "lower_email", expression: "lower(email)", type: GIN)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model.assert_has_index(IndexDefinition {
+        name: Some("lower_email".to_string()),
+        fields: vec![],
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: Some("lower(email)".to_string()),
+        predicate: None,
+        algorithm: Some(IndexAlgorithm::Gin),
+    });
+}
+
+#[test]
+fn must_error_when_sort_orders_do_not_match_the_fields() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@index([firstName, lastName], sort: [ASC])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `sort` argument must have one sort order for each field of the index.",
+        "index",
+        Span::new(108, 149),
+    ));
+}
+
+#[test]
+fn must_error_when_an_expression_index_has_no_name() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String
+
+        @@index(expression: "lower(email)")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "An index over an expression must have a name.",
+        "index",
+        Span::new(75, 108),
+    ));
+}
+
+#[test]
+fn index_options_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model User {
+            id        Int       @id
+            firstName String
+            lastName  String
+            deletedAt DateTime?
+
+            @@index([firstName, lastName], sort: [DESC, ASC], where: "deletedAt IS NULL", type: BRIN)
+            @@index(name: "lower_first_name", expression: "lower(firstName) DESC")
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(parse(&rendered), schema);
+}
+
+#[test]
+fn must_error_when_the_connector_does_not_support_partial_indexes() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model User {
+        id        Int       @id
+        deletedAt DateTime?
+
+        @@index([deletedAt], where: "deletedAt IS NULL")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `where` argument is not supported by the `mysql` connector.",
+        "index",
+        Span::new(201, 227),
+    ));
+}
+
+#[test]
+fn must_error_when_the_connector_does_not_support_the_index_type() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model User {
+        id    Int    @id
+        email String
+
+        @@unique([email], type: GIN)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The index type `GIN` is not supported by the `mysql` connector.",
+        "unique",
+        Span::new(184, 193),
+    ));
+}
+
+#[test]
+fn partial_indexes_must_work_on_sqlite() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model User {
+        id        Int       @id
+        deletedAt DateTime?
+
+        @@index([deletedAt], where: "deletedAt IS NULL")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["deletedAt".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expression: None,
+        predicate: Some("deletedAt IS NULL".to_string()),
+        algorithm: None,
+    });
+}
@@ -1,4 +1,5 @@
 pub mod builtin_directives;
+pub mod check;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
@@ -9,6 +10,7 @@ pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
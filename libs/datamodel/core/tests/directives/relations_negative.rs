@@ -205,3 +205,107 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_relation_fields_and_references_of_different_length() {
+    let dml = r#"
+    model User {
+        firstName String
+        lastName  String
+        posts     Post[]
+
+        @@id([firstName, lastName])
+    }
+
+    model Post {
+        id              Int    @id
+        authorFirstName String
+        author          User   @relation(fields: [authorFirstName], references: [firstName, lastName])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `fields` and `references` arguments of a relation must have the same length.",
+        "relation",
+        Span::new(269, 286),
+    ));
+}
+
+#[test]
+fn should_fail_on_relation_fields_that_do_not_exist() {
+    let dml = r#"
+    model User {
+        firstName String
+        lastName  String
+        posts     Post[]
+
+        @@id([firstName, lastName])
+    }
+
+    model Post {
+        id              Int    @id
+        authorFirstName String
+        author          User   @relation(fields: [authorFirstName, authorLastName], references: [firstName, lastName])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The argument `fields` must refer only to existing scalar fields. The following fields do not exist in model `Post`: authorLastName",
+        "relation",
+        Span::new(228, 338),
+    ));
+}
+
+#[test]
+fn should_fail_on_custom_join_tables_on_one_to_many_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(table: "user_posts", column: "user_id")
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The arguments `table` and `column` are only allowed on many-to-many relations.",
+        "relation",
+        Span::new(51, 113),
+    ));
+}
+
+#[test]
+fn should_fail_on_different_join_tables_on_both_sides_of_a_relation() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        roles Role[] @relation("user_roles", table: "user_roles", column: "user_id")
+    }
+
+    model Role {
+        id    Int    @id
+        users User[] @relation("user_roles", table: "role_users", column: "role_id")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_directive_validation_error(
+            "Both sides of a many-to-many relation must use the same join table, but the related field uses `role_users`.",
+            "relation",
+            Span::new(51, 127),
+        ),
+    );
+}
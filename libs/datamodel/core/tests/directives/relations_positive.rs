@@ -107,3 +107,64 @@ fn must_generate_back_relation_fields_for_named_relation_fields() {
         .assert_relation_name("AssignedTodos")
         .assert_relation_to("Todo");
 }
+
+#[test]
+fn allow_relations_over_several_scalar_fields() {
+    let dml = r#"
+    model User {
+        firstName String
+        lastName  String
+        posts     Post[]
+
+        @@id([firstName, lastName])
+    }
+
+    model Post {
+        id              Int    @id
+        authorFirstName String
+        authorLastName  String
+        author          User   @relation(fields: [authorFirstName, authorLastName], references: [firstName, lastName])
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("author")
+        .assert_relation_to("User")
+        .assert_relation_base_fields(&["authorFirstName", "authorLastName"])
+        .assert_relation_to_fields(&["firstName", "lastName"]);
+    schema
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_to("Post")
+        .assert_relation_base_fields(&[])
+        .assert_arity(&dml::FieldArity::List);
+}
+
+#[test]
+fn allow_custom_join_tables_on_many_to_many_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        roles Role[] @relation("user_roles", table: "user_roles", column: "user_id")
+    }
+
+    model Role {
+        id    Int    @id
+        users User[] @relation("user_roles", table: "user_roles", column: "role_id")
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("User")
+        .assert_has_field("roles")
+        .assert_relation_name("user_roles")
+        .assert_relation_table(Some("user_roles"), Some("user_id"));
+    schema
+        .assert_has_model("Role")
+        .assert_has_field("users")
+        .assert_relation_name("user_roles")
+        .assert_relation_table(Some("user_roles"), Some("role_id"));
+}
@@ -0,0 +1,115 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string};
+
+use crate::common::*;
+
+#[test]
+fn schema_directive_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/prisma"
+        schemas = ["public", "billing"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+    assert_eq!(schema.assert_has_model("Invoice").schema, Some("billing".to_string()));
+    assert_eq!(schema.assert_has_model("User").schema, None);
+}
+
+#[test]
+fn must_error_if_schema_is_not_declared_on_the_datasource() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/prisma"
+        schemas = ["public"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The schema `billing` is not listed in the `schemas` property of the datasource.",
+        "schema",
+        Span::new(185, 202),
+    ));
+}
+
+#[test]
+fn must_error_if_the_datasource_has_no_schemas() {
+    let dml = r#"
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `@@schema` directive requires the `schemas` property to be set on the datasource.",
+        "schema",
+        Span::new(51, 68),
+    ));
+}
+
+#[test]
+fn schema_directive_must_serialize_to_dml() {
+    let dml = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost/prisma"
+            schemas = ["billing"]
+        }
+
+        model Invoice {
+            id Int @id
+
+            @@schema("billing")
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains(r#"@@schema("billing")"#));
+}
+
+#[test]
+fn schemas_must_not_be_allowed_for_providers_other_than_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost/prisma"
+        schemas = ["billing"]
+    }
+    "#;
+
+    let res = datamodel::parse_configuration(dml);
+
+    if let Err(error) = res {
+        error.assert_is(DatamodelError::new_validation_error(
+            "The `schemas` property is not supported by the mysql provider.",
+            Span::new(107, 118),
+        ));
+    } else {
+        panic!("Expected error.")
+    }
+}
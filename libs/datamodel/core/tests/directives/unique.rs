@@ -20,6 +20,10 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
@@ -41,6 +45,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
@@ -64,12 +72,20 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     });
 }
 
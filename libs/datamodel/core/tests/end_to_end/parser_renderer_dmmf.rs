@@ -259,6 +259,39 @@ fn should_serialize_dmmf_without_relation_name_correctly() {
     assert_eq!(DML_WITHOUT_RELATION_NAME, rendered);
 }
 
+#[test]
+fn unsupported_fields_must_not_be_rendered_to_dmmf() {
+    let mut dml = datamodel::parse_datamodel(DATAMODEL_STRING_WITH_FUNCTIONS).unwrap();
+    dml.find_model_mut("User").unwrap().add_field(datamodel::Field::new(
+        "location",
+        datamodel::FieldType::Unsupported("point".to_string()),
+    ));
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf(&dml);
+    let dml2 = datamodel::json::dmmf::parse_from_dmmf(&dmmf);
+    let rendered = datamodel::render_datamodel_to_string(&dml2).unwrap();
+
+    assert_eq!(DATAMODEL_STRING_WITH_FUNCTIONS, rendered);
+}
+
+#[test]
+fn views_without_id_must_not_be_rendered_to_dmmf() {
+    let dml = datamodel::parse_datamodel(
+        r#"
+        view UserName {
+            name String
+
+            @@definition("SELECT name FROM User")
+        }
+        "#,
+    )
+    .unwrap();
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&dml);
+
+    assert!(dmmf["models"].as_array().unwrap().is_empty());
+}
+
 fn dmmf_roundtrip(input: &str) -> String {
     let dml = datamodel::parse_datamodel(input).unwrap();
     let config = datamodel::parse_configuration(input).unwrap();
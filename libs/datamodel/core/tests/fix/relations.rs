@@ -0,0 +1,189 @@
+fn fix(dml: &str) -> String {
+    let fixed = datamodel::fix_datamodel(dml).expect("Datamodel should parse");
+
+    if let Err(errors) = datamodel::parse_datamodel(&fixed) {
+        panic!("The fixed datamodel should be valid: {:?}\n{}", errors, fixed);
+    }
+
+    fixed
+}
+
+#[test]
+fn should_add_missing_back_relation_fields() {
+    let dml = r#"
+// The blog.
+model Blog {
+  id    Int    @id
+  // Who writes here.
+  owner User
+}
+
+model User {
+  id   Int    @id // the id
+  name String
+}
+"#;
+
+    let expected = r#"
+// The blog.
+model Blog {
+  id    Int    @id
+  // Who writes here.
+  owner User
+}
+
+model User {
+  id   Int    @id // the id
+  name String
+  blogs Blog[]
+}
+"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn should_add_optional_back_relation_fields_for_lists() {
+    let dml = r#"
+model Post {
+  id         Int        @id
+  categories Category[]
+}
+
+model Category {
+  id Int @id
+}
+"#;
+
+    let expected = r#"
+model Post {
+  id         Int        @id
+  categories Category[]
+}
+
+model Category {
+  id Int @id
+  post Post?
+}
+"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn should_name_ambiguous_relations() {
+    let dml = r#"
+model Message {
+  id        Int  @id
+  sender    User
+  recipient User @relation(onDelete: CASCADE)
+}
+
+model User {
+  id Int @id
+}
+"#;
+
+    let expected = r#"
+model Message {
+  id        Int  @id
+  sender    User @relation("MessageSender")
+  recipient User @relation("MessageRecipient", onDelete: CASCADE)
+}
+
+model User {
+  id Int @id
+  senderMessages Message[] @relation("MessageSender")
+  recipientMessages Message[] @relation("MessageRecipient")
+}
+"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn should_name_ambiguous_relations_with_an_unnamed_opposite_field() {
+    let dml = r#"
+model Message {
+  id        Int  @id
+  sender    User
+  recipient User
+}
+
+model User {
+  id       Int       @id
+  messages Message[]
+}
+"#;
+
+    let expected = r#"
+model Message {
+  id        Int  @id
+  sender    User @relation("MessageSender")
+  recipient User @relation("MessageRecipient")
+  user User?
+}
+
+model User {
+  id       Int       @id
+  messages Message[]
+  senderMessages Message[] @relation("MessageSender")
+  recipientMessages Message[] @relation("MessageRecipient")
+}
+"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn should_add_missing_foreign_key_fields() {
+    let dml = r#"
+model Post {
+  id       Int      @id
+  author   User?    @relation(fields: [authorId], references: [id])
+  reviewer User     @relation("Reviews", fields: [reviewerId], references: [id])
+}
+
+model User {
+  id      Int    @id
+  posts   Post[]
+  reviews Post[] @relation("Reviews")
+}
+"#;
+
+    let expected = r#"
+model Post {
+  id       Int      @id
+  author   User?    @relation(fields: [authorId], references: [id])
+  reviewer User     @relation("Reviews", fields: [reviewerId], references: [id])
+  authorId Int?
+  reviewerId Int
+}
+
+model User {
+  id      Int    @id
+  posts   Post[]
+  reviews Post[] @relation("Reviews")
+}
+"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn should_not_change_complete_datamodels() {
+    let dml = r#"
+model Post {
+  id       Int   @id
+  authorId Int
+  author   User  @relation(fields: [authorId], references: [id])
+}
+
+model User {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+    assert_eq!(fix(dml), dml);
+}
@@ -72,3 +72,32 @@ fn correctly_handle_server_side_uuid_function() {
             vec![],
         ));
 }
+
+#[test]
+fn correctly_handle_server_side_dbgenerated_function() {
+    let dml = r#"
+    model User {
+        id String @id @default(dbgenerated("gen_random_uuid()"))
+        signupDate DateTime @default(dbgenerated("now() - interval '1 day'"))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("id")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(ScalarValue::Expression(
+            String::from("dbgenerated"),
+            ScalarType::String,
+            vec![ScalarValue::String(String::from("gen_random_uuid()"))],
+        ));
+    user_model
+        .assert_has_field("signupDate")
+        .assert_base_type(&ScalarType::DateTime)
+        .assert_default_value(ScalarValue::Expression(
+            String::from("dbgenerated"),
+            ScalarType::DateTime,
+            vec![ScalarValue::String(String::from("now() - interval '1 day'"))],
+        ));
+}
@@ -0,0 +1,216 @@
+use datamodel::lint::{LintRule, LintWarning, Severity};
+
+fn lint(dml: &str) -> Vec<LintWarning> {
+    datamodel::lint_datamodel(dml).expect("Datamodel should be valid")
+}
+
+fn assert_warning(dml: &str, warning: &LintWarning, rule: LintRule, located_at: &str, message: &str) {
+    assert_eq!(warning.rule, rule);
+    assert_eq!(warning.severity, Severity::Warning);
+    assert_eq!(&dml[warning.span.start..warning.span.end], located_at);
+    assert_eq!(warning.message, message);
+}
+
+#[test]
+fn should_warn_about_reserved_table_names() {
+    let dml = r#"
+    linter rules {
+        reservedTableNames = "warn"
+    }
+
+    model User {
+        id Int @id
+    }
+
+    model Order {
+        id Int @id
+        @@map("orders")
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_warning(
+        dml,
+        &warnings[0],
+        LintRule::ReservedTableNames,
+        "User",
+        "The name of model \"User\" is a reserved SQL word. Consider renaming it, or choose a different table name with @@map.",
+    );
+}
+
+#[test]
+fn should_not_warn_about_reserved_table_names_by_default() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+    "#;
+
+    assert!(lint(dml).is_empty());
+}
+
+#[test]
+fn should_warn_about_relation_scalar_fields_without_index() {
+    let dml = r#"
+    model Account {
+        id Int @id
+    }
+
+    model Post {
+        id        Int     @id
+        accountId Int
+        account   Account @relation(fields: [accountId], references: [id])
+    }
+
+    model Comment {
+        id        Int     @id
+        accountId Int
+        account   Account @relation(fields: [accountId], references: [id])
+        @@index([accountId])
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_warning(
+        dml,
+        &warnings[0],
+        LintRule::RelationScalarIndexes,
+        "account",
+        "The foreign key of relation field \"account\" on model \"Post\" is not indexed. Consider adding @@index([accountId]).",
+    );
+}
+
+#[test]
+fn should_warn_about_inconsistent_naming_conventions() {
+    let dml = r#"
+    model Account {
+        id          Int    @id
+        firstName   String
+        lastName    String
+        created_at  DateTime
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_warning(
+        dml,
+        &warnings[0],
+        LintRule::NamingConventions,
+        "created_at",
+        "Field \"created_at\" on model \"Account\" uses snake_case, while most fields use camelCase.",
+    );
+}
+
+#[test]
+fn should_warn_about_optional_relations_with_required_scalar_fields() {
+    let dml = r#"
+    model Account {
+        id Int @id
+    }
+
+    model Post {
+        id        Int      @id
+        accountId Int
+        account   Account? @relation(fields: [accountId], references: [id])
+        @@index([accountId])
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_warning(
+        dml,
+        &warnings[0],
+        LintRule::OptionalRelationScalars,
+        "account",
+        "The relation field \"account\" on model \"Post\" is optional, but its foreign key field \"accountId\" is required.",
+    );
+}
+
+#[test]
+fn should_warn_about_unused_enums() {
+    let dml = r#"
+    model Account {
+        id   Int  @id
+        role Role
+    }
+
+    enum Role {
+        ADMIN
+        MEMBER
+    }
+
+    enum Color {
+        RED
+        GREEN
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_warning(
+        dml,
+        &warnings[0],
+        LintRule::UnusedEnums,
+        "Color",
+        "Enum \"Color\" is not used by any field.",
+    );
+}
+
+#[test]
+fn should_configure_rules_in_linter_blocks() {
+    let dml = r#"
+    linter rules {
+        unusedEnums        = "off"
+        reservedTableNames = "error"
+    }
+
+    model User {
+        id Int @id
+    }
+
+    enum Color {
+        RED
+        GREEN
+    }
+    "#;
+
+    let warnings = lint(dml);
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].rule, LintRule::ReservedTableNames);
+    assert_eq!(warnings[0].severity, Severity::Error);
+}
+
+#[test]
+fn should_fail_on_unknown_rules_and_severities() {
+    let dml = r#"
+    linter rules {
+        unknownRule = "off"
+        unusedEnums = "loud"
+    }
+
+    model Account {
+        id Int @id
+    }
+    "#;
+
+    let errors = datamodel::lint_datamodel(dml).unwrap_err();
+    let messages: Vec<String> = errors.to_iter().map(|error| format!("{}", error)).collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Error validating: Lint rule \"unknownRule\" is not known.",
+            "\"loud\" is not a valid value for lint severity.",
+        ]
+    );
+}
@@ -3,7 +3,9 @@ pub mod common;
 pub mod config;
 pub mod directives;
 pub mod end_to_end;
+pub mod fix;
 pub mod functions;
+pub mod lint;
 pub mod parsing;
 pub mod reformat;
 pub mod renderer;
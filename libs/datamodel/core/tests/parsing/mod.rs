@@ -1 +1,2 @@
+pub mod multiple_files;
 pub mod nice_errors;
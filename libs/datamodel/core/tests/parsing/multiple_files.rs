@@ -0,0 +1,80 @@
+use crate::common::*;
+use datamodel::ast::{SourceFile, SourceMap, Span};
+use datamodel::error::DatamodelError;
+
+#[test]
+fn types_must_resolve_across_files() {
+    let files = SourceMap::new(vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id\n  posts Post[]\n}"),
+        SourceFile::new("post.prisma", "model Post {\n  id Int @id\n  author User\n}\n"),
+    ]);
+
+    let datamodel = datamodel::parse_datamodel_files(&files).unwrap();
+
+    datamodel.assert_has_model("User").assert_has_field("posts");
+    datamodel.assert_has_model("Post").assert_has_field("author");
+}
+
+#[test]
+fn errors_must_be_located_in_their_file() {
+    let post = "model Post {\n  id Int @id\n  author Author\n}\n";
+    let files = SourceMap::new(vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id\n}"),
+        SourceFile::new("post.prisma", post),
+    ]);
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+    errors.assert_length(1);
+
+    let start = post.find("Author").unwrap();
+
+    assert_eq!(files.file(errors.errors[0].span()).name, "post.prisma");
+    assert_eq!(
+        errors.errors[0],
+        DatamodelError::new_type_not_found_error("Author", Span::new(start, start + 6).in_file(1))
+    );
+}
+
+#[test]
+fn parser_errors_must_be_located_in_their_file() {
+    let files = SourceMap::new(vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id\n}\n"),
+        SourceFile::new("post.prisma", "model Post {\n  id Int @id\n  author User @@@\n}\n"),
+    ]);
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+
+    assert!(!errors.errors.is_empty());
+    for error in errors.to_iter() {
+        assert_eq!(files.file(error.span()).name, "post.prisma");
+    }
+}
+
+#[test]
+fn declarations_must_not_continue_across_files() {
+    let files = SourceMap::new(vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id"),
+        SourceFile::new("post.prisma", "}\nmodel Post {\n  id Int @id\n}\n"),
+    ]);
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+
+    let file_names: Vec<&str> = errors
+        .to_iter()
+        .map(|error| files.file(error.span()).name.as_str())
+        .collect();
+
+    assert!(file_names.contains(&"user.prisma"), "{:?}", file_names);
+}
+
+#[test]
+fn pretty_errors_must_name_their_file() {
+    let files = SourceMap::new(vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id\n}\n"),
+        SourceFile::new("post.prisma", "model Post {\n  id Int @id\n  author Author\n}\n"),
+    ]);
+
+    let error = datamodel::parse_datamodel_files_or_pretty_error(&files).unwrap_err();
+
+    assert!(error.contains("post.prisma:3"), "{}", error);
+}
@@ -20,6 +20,7 @@ fn nice_error_for_missing_model_keyword() {
             "enum declaration",
             "source definition",
             "generator definition",
+            "linter definition",
         ],
         Span::new(5, 5),
     ));
@@ -45,6 +46,7 @@ fn nice_error_for_missing_model_keyword_2() {
             "enum declaration",
             "source definition",
             "generator definition",
+            "linter definition",
         ],
         Span::new(47, 47),
     ));
@@ -156,16 +158,119 @@ fn nice_error_broken_field_type_legacy_required() {
 }
 
 #[test]
-fn nice_error_legacy_model_decl() {
+fn must_report_errors_of_all_malformed_declarations() {
     let dml = r#"
-    type User {
+    model User {
         id Int @id
-    }"#;
+        name
+    }
 
-    let error = parse_error(dml);
+    enum Role {
+        A-dmin
+    }
+    "#;
 
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
-    ));
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_parser_error(&vec!["field type"], Span::new(54, 54)),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_parser_error(
+            &vec!["End of block (\"}\")", "enum field declaration"],
+            Span::new(82, 82),
+        ),
+    );
+}
+
+#[test]
+fn must_validate_well_formed_declarations_next_to_malformed_ones() {
+    let dml = r#"
+    model User {
+        id Int @id @
+    }
+
+    model Post {
+        id Int @id
+        author Author
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_parser_error(&vec!["directive"], Span::new(43, 43)),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_type_not_found_error("Author", Span::new(97, 103)),
+    );
+}
+
+#[test]
+fn must_recover_at_the_next_declaration_if_a_block_is_not_closed() {
+    let dml = "model User {\n  id Int @id\n\nmodel Post {\n  id Int @id\n}\n";
+
+    let (ast, errors) = datamodel::parse_schema_ast_with_recovery(dml);
+
+    errors.assert_length(1);
+    assert_eq!(errors.errors[0].span(), Span::new(26, 26));
+    assert_eq!(ast.models().len(), 1);
+    assert_eq!(ast.models()[0].name.name, "Post");
+}
+
+#[test]
+fn must_not_end_a_malformed_declaration_at_a_brace_in_a_string() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @default("}") @
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(1);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_parser_error(&vec!["directive"], Span::new(82, 82)),
+    );
+}
+
+#[test]
+fn must_not_report_references_to_malformed_declarations_as_unknown_types() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name
+    }
+
+    enum Role {
+        A-dmin
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+        role   Role
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_parser_error(&vec!["field type"], Span::new(54, 54)),
+    );
 }
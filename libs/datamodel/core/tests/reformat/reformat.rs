@@ -53,3 +53,251 @@ datasource pg {
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_reformat_comments_in_config_blocks() {
+    let input = r#"
+        datasource db {
+          // The provider.
+          provider = "postgresql" // trailing
+          url = env( // in function
+            "DATABASE_URL")
+        }
+
+        generator client {
+          provider = "prisma-client-js"
+          binaryTargets = ["native", // local
+            "debian-openssl-1.1.x"]
+        }
+    "#;
+
+    let expected = r#"
+datasource db {
+  // The provider.
+  provider = "postgresql"        // trailing
+  url      = env("DATABASE_URL") // in function
+}
+
+generator client {
+  provider      = "prisma-client-js"
+  binaryTargets = ["native", "debian-openssl-1.1.x"] // local
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_reformat_comments_after_field_directives() {
+    let input = r#"
+        model Blog {
+          id Int @id
+          // The owner.
+          owner User @unique
+          // The name.
+          name String
+        }
+
+        type Email = String @unique
+        // Last comment."#;
+
+    let expected = r#"
+model Blog {
+  id    Int    @id
+  // The owner.
+  owner User   @unique
+  // The name.
+  name  String
+}
+
+type Email = String @unique
+// Last comment."#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_reformat_keeps_block_directives_in_place() {
+    let input = r#"
+        model User {
+          @@map("users")
+          id Int @id
+          email String // trailing
+          @@unique([email]) // login
+          name String
+          @@index([name])
+        }
+    "#;
+
+    let expected = r#"
+model User {
+  @@map("users")
+  id    Int    @id
+  email String // trailing
+  @@unique([email]) // login
+  name String
+  @@index([name])
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_reformat_canonical_sorts_block_directives() {
+    let input = r#"
+        model User {
+          @@map("users")
+          id Int @id
+          email String // trailing
+          @@unique([email]) // login
+          name String
+          @@index([name])
+        }
+    "#;
+
+    let expected = r#"
+model User {
+  id    Int    @id
+  email String // trailing
+  name  String
+
+  @@index([name])
+  @@map("users")
+  @@unique([email]) // login
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_canonical_to(input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_reformat_canonical_moves_comments_above_block_directives() {
+    let input = r#"
+        model User {
+          // the table
+          @@map("users")
+          id Int @id
+
+          // unrelated
+
+          @@index([id])
+        }
+    "#;
+
+    let expected = r#"
+model User {
+  id Int @id
+
+  // unrelated
+
+  @@index([id])
+  // the table
+  @@map("users")
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_canonical_to(input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+/// The schemas in the raw string literals of the datamodel test suites.
+fn test_suite_schemas() -> Vec<String> {
+    let mut schemas = Vec::new();
+    let mut dirs = vec![std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests"))];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir).expect("unable to read test directory") {
+            let path = entry.expect("unable to read test directory").path();
+
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().map(|ext| ext == "rs").unwrap_or(false) {
+                let source = std::fs::read_to_string(&path).expect("unable to read test file");
+
+                for literal in source.split("r#\"").skip(1) {
+                    let schema = literal.split("\"#").next().unwrap();
+                    let is_schema = ["model ", "enum ", "datasource ", "generator "]
+                        .iter()
+                        .any(|keyword| schema.contains(keyword));
+
+                    // Only schemas the parser accepts can be reformatted.
+                    if is_schema && datamodel::parse_schema_ast(schema).is_ok() {
+                        schemas.push(schema.to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    schemas
+}
+
+#[test]
+fn test_reformat_is_idempotent() {
+    let mut corpus = test_suite_schemas();
+    corpus.push(include_str!("../../../../../datamodel_v2.prisma").to_owned());
+    corpus.push(
+        r#"
+        datasource db {
+          provider = "postgresql" // trailing
+          url = "postgresql://localhost:5432/db"
+        }
+
+        /// A user.
+        model User {
+          id Int @id // the id
+          // A comment.
+          posts Post[]
+          @@map("users")
+        }
+
+        model Post {
+          id Int @id
+          title String
+          author User @relation(references: [id]) // the author
+          @@unique([title]) // one post per title
+          @@index([id])
+        }
+
+        enum Role {
+          USER // default
+          ADMIN
+          @@map("roles")
+        }
+        "#
+        .to_owned(),
+    );
+
+    for input in &corpus {
+        for canonical in &[false, true] {
+            let reformat = |input: &str| {
+                let mut buf = Vec::new();
+                if *canonical {
+                    datamodel::ast::reformat::Reformatter::reformat_canonical_to(input, &mut buf, 2);
+                } else {
+                    datamodel::ast::reformat::Reformatter::reformat_to(input, &mut buf, 2);
+                }
+                String::from_utf8(buf).expect("unable to convert to string")
+            };
+
+            let once = reformat(input);
+            let twice = reformat(&once);
+            assert_eq!(once, twice);
+
+            if datamodel::parse_datamodel(input).is_ok() {
+                assert!(datamodel::parse_datamodel(&once).is_ok());
+            }
+        }
+    }
+}
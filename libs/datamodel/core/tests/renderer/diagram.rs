@@ -0,0 +1,99 @@
+use crate::common::*;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id      Int      @id
+        email   String   @unique
+        name    String?
+        profile Profile?
+        posts   Post[]
+    }
+
+    model Profile {
+        id     Int  @id
+        userId Int  @unique
+        user   User @relation(fields: [userId], references: [id])
+    }
+
+    model Post {
+        id         Int        @id
+        authorId   Int
+        author     User       @relation(fields: [authorId], references: [id])
+        categories Category[]
+    }
+
+    model Category {
+        id    Int    @id
+        posts Post[]
+    }
+"#;
+
+#[test]
+fn should_render_a_mermaid_er_diagram() {
+    let expected = r#"erDiagram
+  User {
+    Int id PK
+    String email UK
+    String name "optional"
+  }
+  Profile {
+    Int id PK
+    Int userId FK, UK
+  }
+  Post {
+    Int id PK
+    Int authorId FK
+  }
+  Category {
+    Int id PK
+  }
+  User ||--o| Profile : "profile"
+  User ||--o{ Post : "posts"
+  Post }o--o{ Category : "categories"
+"#;
+
+    assert_eq!(datamodel::diagram::render_to_mermaid(&parse(DATAMODEL)), expected);
+}
+
+#[test]
+fn should_render_a_dot_er_diagram() {
+    let expected = r#"digraph datamodel {
+  node [shape=record];
+  User [label="{User|id : Int PK\lemail : String UK\lname : String?\l}"];
+  Profile [label="{Profile|id : Int PK\luserId : Int FK, UK\l}"];
+  Post [label="{Post|id : Int PK\lauthorId : Int FK\l}"];
+  Category [label="{Category|id : Int PK\l}"];
+  User -> Profile [label="profile", dir=both, arrowtail=teetee, arrowhead=teeodot];
+  User -> Post [label="posts", dir=both, arrowtail=teetee, arrowhead=crowodot];
+  Post -> Category [label="categories", dir=both, arrowtail=crowodot, arrowhead=crowodot];
+}
+"#;
+
+    assert_eq!(datamodel::diagram::render_to_dot(&parse(DATAMODEL)), expected);
+}
+
+#[test]
+fn should_draw_relations_declared_on_one_side_only() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Post {
+        id     Int   @id
+        author User?
+    }
+    "#;
+
+    let expected = r#"erDiagram
+  User {
+    Int id PK
+  }
+  Post {
+    Int id PK
+  }
+  Post }o--o| User : "author"
+"#;
+
+    assert_eq!(datamodel::diagram::render_to_mermaid(&parse(dml)), expected);
+}
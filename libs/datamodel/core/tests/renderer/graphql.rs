@@ -0,0 +1,85 @@
+use crate::common::*;
+
+#[test]
+fn should_render_the_datamodel_as_graphql_sdl() {
+    let dml = r#"
+    /// A user of the blog.
+    model User {
+        id        Int      @id @default(autoincrement())
+        email     String   @unique
+        /// Shown on posts.
+        name      String?
+        role      Role     @default(USER)
+        posts     Post[]
+        createdAt DateTime @default(now())
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+    "#;
+
+    let expected = r#"scalar DateTime
+
+enum Role {
+  USER
+  ADMIN
+}
+
+"""
+A user of the blog.
+"""
+type User {
+  id: Int!
+  email: String!
+  """
+  Shown on posts.
+  """
+  name: String
+  role: Role!
+  posts: [Post!]!
+  createdAt: DateTime!
+}
+
+type Post {
+  id: Int!
+  authorId: Int!
+  author: User!
+}
+"#;
+
+    assert_eq!(datamodel::graphql::render_to_sdl(&parse(dml)), expected);
+}
+
+#[test]
+fn should_not_render_generated_back_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+    }
+    "#;
+
+    let expected = r#"type User {
+  id: Int!
+}
+
+type Post {
+  id: Int!
+  author: User!
+}
+"#;
+
+    assert_eq!(datamodel::graphql::render_to_sdl(&parse(dml)), expected);
+}
@@ -0,0 +1,96 @@
+use crate::common::*;
+use serde_json::json;
+
+#[test]
+fn should_render_create_and_update_inputs_of_models() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432/db"
+    }
+
+    /// A user of the blog.
+    model User {
+        id        Int      @id @default(autoincrement())
+        email     String   @unique
+        name      String?
+        role      Role     @default(USER)
+        tags      String[]
+        posts     Post[]
+        updatedAt DateTime @updatedAt
+    }
+
+    model Post {
+        id       Int   @id
+        authorId Int
+        author   User  @relation(fields: [authorId], references: [id])
+        rating   Float @default(2.5)
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+    "#;
+
+    let json_schema = datamodel::json::json_schema::render_to_json_schema_value(&parse(dml));
+    let definitions = &json_schema["definitions"];
+
+    assert_eq!(json_schema["$schema"], "http://json-schema.org/draft-07/schema#");
+    assert_eq!(
+        definitions["Role"],
+        json!({ "type": "string", "enum": ["USER", "ADMIN"] })
+    );
+    assert_eq!(
+        definitions["UserCreateInput"],
+        json!({
+            "type": "object",
+            "description": "A user of the blog.",
+            "properties": {
+                "id": { "type": "integer" },
+                "email": { "type": "string" },
+                "name": { "type": ["string", "null"] },
+                "role": { "$ref": "#/definitions/Role", "default": "USER" },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "updatedAt": { "type": "string", "format": "date-time" },
+            },
+            "required": ["email"],
+            "additionalProperties": false,
+        })
+    );
+    assert_eq!(definitions["PostCreateInput"]["required"], json!(["id", "authorId"]));
+    assert_eq!(
+        definitions["PostCreateInput"]["properties"]["rating"],
+        json!({ "type": "number", "default": 2.5 })
+    );
+    assert_eq!(definitions["PostUpdateInput"].get("required"), None);
+    assert_eq!(
+        definitions["PostUpdateInput"]["properties"],
+        definitions["PostCreateInput"]["properties"]
+    );
+}
+
+#[test]
+fn should_render_composite_types_as_definitions() {
+    let dml = r#"
+    type Address {
+        street String
+        zip    String?
+    }
+
+    model User {
+        id      Int      @id
+        address Address?
+    }
+    "#;
+
+    let json_schema = datamodel::json::json_schema::render_to_json_schema_value(&parse(dml));
+    let definitions = &json_schema["definitions"];
+
+    assert_eq!(definitions["Address"]["required"], json!(["street"]));
+    assert_eq!(definitions.get("AddressCreateInput"), None);
+    assert_eq!(
+        definitions["UserCreateInput"]["properties"]["address"],
+        json!({ "anyOf": [{ "$ref": "#/definitions/Address" }, { "type": "null" }] })
+    );
+}
@@ -1 +1,5 @@
+pub mod diagram;
+pub mod graphql;
+pub mod json_schema;
 pub mod simplification;
+pub mod unsupported;
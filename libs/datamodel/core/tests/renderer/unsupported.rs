@@ -0,0 +1,26 @@
+use datamodel::dml;
+
+#[test]
+fn unsupported_fields_are_rendered_commented_out() {
+    let input = r#"
+        model Place {
+            id Int @id
+        }
+    "#;
+
+    let expected = r#"model Place {
+  id          Int                  @id
+  // location Unsupported("point")
+}"#;
+
+    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    dml.find_model_mut("Place").unwrap().add_field(dml::Field::new(
+        "location",
+        dml::FieldType::Unsupported("point".to_string()),
+    ));
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, expected);
+}
@@ -108,3 +108,90 @@ fn should_fail_on_custom_related_types() {
         ast::Span::new(25, 29),
     ));
 }
+
+#[test]
+fn should_fail_on_unknown_native_types() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.Varchar2
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type Varchar2 is not supported by this connector.",
+        "db.Varchar2",
+        ast::Span::new(150, 161),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_with_missing_arguments() {
+    let dml = r#"
+    datasource mysql {
+      provider = "mysql"
+      url = "mysql://"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.VarChar
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type VarChar expects 1 arguments, but received 0.",
+        "db.VarChar",
+        ast::Span::new(143, 153),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_of_another_scalar_type() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.Integer
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type Integer can not be used on a field of type String.",
+        "db.Integer",
+        ast::Span::new(150, 160),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_without_a_datasource() {
+    let dml = r#"
+    model Blog {
+        id    Int    @id
+        title String @db.Text
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native types can only be used with a datasource.",
+        "db.Text",
+        ast::Span::new(65, 72),
+    ));
+}
@@ -118,44 +118,129 @@ fn should_be_able_to_define_custom_enum_types() {
 }
 
 #[test]
-#[ignore]
-fn should_handle_type_mappings() {
+fn should_handle_native_types() {
     let dml = r#"
+        datasource pg {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+
         model Blog {
-            id     Int    @id
-            bigInt BigInt
+            id        Int      @id
+            bigInt    Int      @db.BigInt
+            title     String   @db.VarChar(255)
+            price     Float    @db.Decimal(10, 2)
+            createdAt DateTime @db.Timestamptz
         }
     "#;
 
     let datamodel = parse(dml);
 
-    let user_model = datamodel.assert_has_model("Blog");
+    let blog_model = datamodel.assert_has_model("Blog");
 
-    user_model
+    blog_model
         .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint", vec![]));
+    blog_model
+        .assert_has_field("title")
+        .assert_connector_type(&ScalarFieldType::new(
+            "VarChar",
+            ScalarType::String,
+            "varchar(255)",
+            vec![255],
+        ));
+    blog_model
+        .assert_has_field("price")
+        .assert_connector_type(&ScalarFieldType::new(
+            "Decimal",
+            ScalarType::Float,
+            "decimal(10,2)",
+            vec![10, 2],
+        ));
+    blog_model
+        .assert_has_field("createdAt")
+        .assert_connector_type(&ScalarFieldType::new(
+            "Timestamptz",
+            ScalarType::DateTime,
+            "timestamptz",
+            vec![],
+        ));
 }
 
 #[test]
-#[ignore]
-fn should_handle_type_specifications() {
+fn should_resolve_native_type_aliases() {
     let dml = r#"
-        datasource pg {
-          provider = "postgres"
-          url = "postgresql://"
+        datasource mysql {
+          provider = "mysql"
+          url = "mysql://"
         }
-        
+
         model Blog {
-            id     Int @id
-            bigInt Int @pg.BigInt
+            id        Int     @id @db.Integer
+            published Boolean @db.Bool
         }
     "#;
 
     let datamodel = parse(dml);
 
-    let user_model = datamodel.assert_has_model("Blog");
+    let blog_model = datamodel.assert_has_model("Blog");
 
-    user_model
-        .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+    blog_model
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_connector_type(&ScalarFieldType::new("Int", ScalarType::Int, "int", vec![]));
+    blog_model
+        .assert_has_field("published")
+        .assert_connector_type(&ScalarFieldType::new("Boolean", ScalarType::Boolean, "boolean", vec![]));
+}
+
+#[test]
+fn should_apply_defaults_to_native_types() {
+    let dml = r#"
+        datasource sqlite {
+          provider = "sqlite"
+          url = "file:dev.db"
+        }
+
+        model Blog {
+            id    Int    @id @default(autoincrement()) @db.Integer
+            title String @default("Untitled") @db.Text
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let blog_model = datamodel.assert_has_model("Blog");
+
+    blog_model
+        .assert_has_field("id")
+        .assert_default_value(ScalarValue::Expression(
+            String::from("autoincrement"),
+            ScalarType::Int,
+            Vec::new(),
+        ));
+    blog_model
+        .assert_has_field("title")
+        .assert_default_value(ScalarValue::String(String::from("Untitled")));
+}
+
+#[test]
+fn should_render_native_types() {
+    let dml = r#"
+        datasource pg {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+
+        model Blog {
+            id    Int    @id
+            title String @db.VarChar(255)
+            price Float  @db.Decimal(10, 2)
+        }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert!(rendered.contains("title String @db.VarChar(255)"));
+    assert!(rendered.contains("price Float  @db.Decimal(10, 2)"));
 }
@@ -28,6 +28,33 @@ impl Inflector {
         panic!("Invariant violation: Inflector should always fall back to catch-all case -s.")
     }
 
+    /// Joins the words of `s` in PascalCase. Words are separated by any character that is not an ASCII letter or
+    /// digit, and the casing within a word is kept, e.g. `order-items` and `order_Items` become `OrderItems`.
+    pub fn pascal_case(&self, s: &str) -> String {
+        Self::words(s)
+            .map(|word| Self::with_first_char(word, char::to_ascii_uppercase))
+            .collect()
+    }
+
+    /// Joins the words of `s` in camelCase, e.g. `user_ID` becomes `userID`.
+    pub fn camel_case(&self, s: &str) -> String {
+        Self::with_first_char(&self.pascal_case(s), char::to_ascii_lowercase)
+    }
+
+    fn words(s: &str) -> impl Iterator<Item = &str> {
+        s.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+    }
+
+    fn with_first_char(word: &str, f: fn(&char) -> char) -> String {
+        let mut chars = word.chars();
+
+        match chars.next() {
+            Some(first) => std::iter::once(f(&first)).chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
     pub fn new(mode: Mode) -> Inflector {
         let mut rules = vec![];
 
@@ -217,4 +244,22 @@ mod test {
             assert_eq!(inflector.pluralize(singular), expected_plural);
         });
     }
+
+    #[test]
+    fn test_case_conversion() {
+        let examples = vec![
+            ("order-items", "OrderItems", "orderItems"),
+            ("order_Items", "OrderItems", "orderItems"),
+            ("UserID", "UserID", "userID"),
+            ("2fa enabled", "2faEnabled", "2faEnabled"),
+            ("__", "", ""),
+        ];
+
+        let inflector = Inflector::new(Mode::Anglicized);
+
+        examples.into_iter().for_each(|(s, expected_pascal, expected_camel)| {
+            assert_eq!(inflector.pascal_case(s), expected_pascal);
+            assert_eq!(inflector.camel_case(s), expected_camel);
+        });
+    }
 }
@@ -47,9 +47,13 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
+            // Views without an id can not be queried.
+            .filter(|model| !model.is_view || model.has_id())
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
+                schema: model.schema.clone(),
                 fields: self.convert_fields(model),
                 manifestation: model.database_name.clone(),
                 indexes: self.convert_indexes(model),
@@ -57,9 +61,11 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    /// Fields of types Prisma does not support only exist in the database, so they are left out.
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
+            .filter(|field| !field.field_type.is_unsupported())
             .map(|field| match field.type_identifier() {
                 TypeIdentifier::Relation => {
                     let relation = self
@@ -98,6 +104,7 @@ impl<'a> DatamodelConverter<'a> {
                     behaviour: field.behaviour(),
                     default_value: field.default_value(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    composite_type: field.composite_type(),
                 }),
             })
             .collect()
@@ -121,6 +128,8 @@ impl<'a> DatamodelConverter<'a> {
         model
             .indexes
             .iter()
+            // Expression and partial indexes can not identify records.
+            .filter(|i| i.is_plain())
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
                 fields: i.fields.clone(),
@@ -276,15 +285,34 @@ impl TempRelationHolder {
     }
 
     pub fn table_name(&self) -> String {
-        format!("_{}", self.name())
+        self.relation_info(&self.field_a)
+            .and_then(|info| info.table.clone())
+            .or_else(|| self.relation_info(&self.field_b).and_then(|info| info.table.clone()))
+            .unwrap_or_else(|| format!("_{}", self.name()))
+    }
+
+    /// Whether the relation table was named explicitly instead of following the `_RelationName` convention.
+    pub fn has_custom_table(&self) -> bool {
+        self.table_name() != format!("_{}", self.name())
     }
 
     pub fn model_a_column(&self) -> String {
-        "A".to_string()
+        self.relation_info(&self.field_a)
+            .and_then(|info| info.column.clone())
+            .unwrap_or_else(|| Relation::MODEL_A_DEFAULT_COLUMN.to_string())
     }
 
     pub fn model_b_column(&self) -> String {
-        "B".to_string()
+        self.relation_info(&self.field_b)
+            .and_then(|info| info.column.clone())
+            .unwrap_or_else(|| Relation::MODEL_B_DEFAULT_COLUMN.to_string())
+    }
+
+    fn relation_info<'a>(&self, field: &'a dml::Field) -> Option<&'a dml::RelationInfo> {
+        match &field.field_type {
+            dml::FieldType::Relation(info) => Some(info),
+            _ => None,
+        }
     }
 
     pub fn is_one_to_one(&self) -> bool {
@@ -339,36 +367,40 @@ trait DatamodelFieldExtensions {
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
+    fn composite_type(&self) -> Option<String>;
     fn default_value(&self) -> Option<PrismaValue>;
 }
 
 impl DatamodelFieldExtensions for dml::Field {
     fn type_identifier(&self) -> TypeIdentifier {
-        match self.field_type {
+        match &self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
-            dml::FieldType::Base(scalar) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => match self.default_value {
-                    Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "cuid" => {
-                        TypeIdentifier::GraphQLID
-                    }
-                    Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "uuid" => {
-                        TypeIdentifier::UUID
-                    }
-                    Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "autoincrement" => {
-                        TypeIdentifier::Int
-                    }
-                    _ => TypeIdentifier::String,
-                },
-            },
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
+            // Composite values are stored and queried as JSON documents.
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
+            // Native types only affect the column, they are queried like their scalar type.
+            dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => {
+                match self.field_type.scalar_type().unwrap() {
+                    dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+                    dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                    dml::ScalarType::Decimal => TypeIdentifier::Float,
+                    dml::ScalarType::Float => TypeIdentifier::Float,
+                    dml::ScalarType::Int => TypeIdentifier::Int,
+                    dml::ScalarType::String => match self.default_value {
+                        Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "cuid" => {
+                            TypeIdentifier::GraphQLID
+                        }
+                        Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "uuid" => {
+                            TypeIdentifier::UUID
+                        }
+                        Some(datamodel::common::ScalarValue::Expression(ref expr, _, _)) if expr == "autoincrement" => {
+                            TypeIdentifier::Int
+                        }
+                        _ => TypeIdentifier::String,
+                    },
+                }
             }
+            dml::FieldType::Unsupported(_) => unreachable!("Unsupported fields are not converted."),
         }
     }
 
@@ -444,6 +476,13 @@ impl DatamodelFieldExtensions for dml::Field {
         }
     }
 
+    fn composite_type(&self) -> Option<String> {
+        match self.field_type {
+            dml::FieldType::CompositeType(ref name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
     fn default_value(&self) -> Option<PrismaValue> {
         self.default_value.as_ref().and_then(|v| match v {
             datamodel::common::ScalarValue::Boolean(x) => Some(PrismaValue::Boolean(*x)),
@@ -116,6 +116,7 @@ impl FieldTemplate {
                     behaviour: st.behaviour,
                     model,
                     default_value: st.default_value,
+                    composite_type: st.composite_type,
                 };
 
                 Field::Scalar(Arc::new(scalar))
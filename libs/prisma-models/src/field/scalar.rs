@@ -27,6 +27,7 @@ pub struct ScalarFieldTemplate {
     pub behaviour: Option<FieldBehaviour>,
     pub default_value: Option<PrismaValue>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
 }
 
 #[derive(DebugStub)]
@@ -42,6 +43,9 @@ pub struct ScalarField {
     pub behaviour: Option<FieldBehaviour>,
     pub default_value: Option<PrismaValue>,
 
+    /// The name of the composite type this field holds, if any. Composite values are stored as JSON documents.
+    pub composite_type: Option<String>,
+
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
 
@@ -62,6 +66,7 @@ impl Hash for ScalarField {
         self.internal_enum.hash(state);
         self.behaviour.hash(state);
         self.default_value.hash(state);
+        self.composite_type.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
     }
@@ -79,6 +84,7 @@ impl PartialEq for ScalarField {
             && self.internal_enum == other.internal_enum
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
+            && self.composite_type == other.composite_type
             && self.is_unique == other.is_unique
             && self.model() == other.model()
     }
@@ -183,6 +189,17 @@ impl ScalarField {
         }
     }
 
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
+
+    /// The composite type model of this field, if the field holds a composite type.
+    pub fn composite_type(&self) -> Option<ModelRef> {
+        self.composite_type
+            .as_ref()
+            .map(|name| self.internal_data_model().find_model(name).unwrap())
+    }
+
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
         let arity = match (self.is_list, self.is_required) {
             (true, _) => FieldArity::List,
@@ -13,6 +13,8 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub schema: Option<String>,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub indexes: Vec<IndexTemplate>,
@@ -22,6 +24,10 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    /// Views are read-only: no write operations are generated for them.
+    pub is_view: bool,
+    /// The database schema of the model's table, when it is not the default one.
+    pub schema: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -36,6 +42,8 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
+            schema: self.schema,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
             manifestation: self.manifestation,
@@ -124,6 +132,14 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The database schema the table of this model lives in.
+    pub fn db_schema(&self) -> String {
+        match self.schema {
+            Some(ref schema) => schema.clone(),
+            None => self.internal_data_model().db_name.clone(),
+        }
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
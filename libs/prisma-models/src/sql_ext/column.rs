@@ -1,5 +1,5 @@
-use crate::{Field, RelationField, ScalarField};
-use quaint::ast::Column;
+use crate::{Field, PrismaValue, RelationField, ScalarField, TypeIdentifier};
+use quaint::ast::{Column, ParameterizedValue};
 
 pub trait AsColumn {
     fn as_column(&self) -> Column<'static>;
@@ -17,12 +17,8 @@ impl AsColumn for Field {
 impl AsColumn for RelationField {
     fn as_column(&self) -> Column<'static> {
         let model = self.model();
-        let internal_data_model = model.internal_data_model();
         let db_name = self.db_name();
-        let parts = (
-            (internal_data_model.db_name.clone(), model.db_name().to_string()),
-            db_name.clone(),
-        );
+        let parts = ((model.db_schema(), model.db_name().to_string()), db_name.clone());
 
         parts.into()
     }
@@ -30,10 +26,38 @@ impl AsColumn for RelationField {
 
 impl AsColumn for ScalarField {
     fn as_column(&self) -> Column<'static> {
-        let db = self.internal_data_model().db_name.clone();
-        let table = self.model().db_name().to_string();
+        let model = self.model();
+        let db = model.db_schema();
+        let table = model.db_name().to_string();
         let col = self.db_name().to_string();
 
         Column::from(((db, table), col))
     }
 }
+
+pub trait ColumnValue {
+    /// Converts the given value into a parameter for the column of the field.
+    fn column_value(&self, value: PrismaValue) -> ParameterizedValue<'static>;
+}
+
+impl ColumnValue for Field {
+    fn column_value(&self, value: PrismaValue) -> ParameterizedValue<'static> {
+        match self {
+            Field::Scalar(ref sf) => sf.column_value(value),
+            Field::Relation(_) => value.into(),
+        }
+    }
+}
+
+impl ColumnValue for ScalarField {
+    fn column_value(&self, value: PrismaValue) -> ParameterizedValue<'static> {
+        match (self.type_identifier, value) {
+            // Composite values are kept as JSON strings in the query engine, but stored as JSON documents.
+            (TypeIdentifier::Json, PrismaValue::String(s)) if self.is_composite() => match serde_json::from_str(&s) {
+                Ok(json) => ParameterizedValue::Json(json),
+                Err(_) => s.into(),
+            },
+            (_, value) => value.into(),
+        }
+    }
+}
@@ -158,6 +158,31 @@ fn unique_works() {
         .assert_unique();
 }
 
+#[test]
+fn composite_type_fields_work() {
+    let datamodel = convert(
+        r#"
+            type Address {
+                street String
+            }
+
+            model Test {
+                id String @id @default(cuid())
+                address Address?
+            }
+        "#,
+    );
+
+    let composite_type = datamodel.assert_model("Address");
+    assert!(composite_type.is_embedded);
+
+    let field = datamodel.assert_model("Test").assert_scalar_field("address");
+    field.assert_type_identifier(TypeIdentifier::Json).assert_optional();
+
+    assert!(field.is_composite());
+    assert_eq!(field.composite_type().unwrap().name, "Address");
+}
+
 #[test]
 fn uuid_fields_must_work() {
     let datamodel = convert(
@@ -313,6 +338,34 @@ fn many_to_many_relations() {
         }));
 }
 
+#[test]
+fn many_to_many_relations_with_custom_join_tables() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id Int @id
+                roles Role[] @relation("user_roles", table: "user_roles", column: "user_id")
+            }
+
+            model Role {
+                id Int @id
+                users User[] @relation("user_roles", table: "user_roles", column: "role_id")
+            }
+        "#,
+    );
+
+    datamodel
+        .assert_relation("user_roles")
+        .assert_model_a("Role")
+        .assert_model_b("User")
+        .assert_manifestation(RelationLinkManifestation::RelationTable(RelationTable {
+            table: "user_roles".to_string(),
+            model_a_column: "role_id".to_string(),
+            model_b_column: "user_id".to_string(),
+            id_column: None,
+        }));
+}
+
 #[test]
 fn implicit_relation_fields() {
     let datamodel = convert(
@@ -1,8 +1,8 @@
 //! Database description.
 
 use failure::Fail;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fmt;
 
 pub mod mysql;
@@ -15,6 +15,9 @@ pub enum SqlSchemaDescriberError {
     /// An unknown error occurred.
     #[fail(display = "unknown")]
     UnknownError,
+    /// Querying the database failed.
+    #[fail(display = "{}", _0)]
+    QueryError(String),
 }
 
 /// The result type.
@@ -28,7 +31,131 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     /// Get the databases metadata.
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
-    async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+    async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
+        self.describe_filtered(schema, &TableMatcher::default()).await
+    }
+    /// Describe a database schema, skipping the tables and views the filter excludes.
+    async fn describe_filtered(&self, schema: &str, filter: &TableMatcher) -> SqlSchemaDescriberResult<SqlSchema>;
+    /// Describe the `default_schema` together with additional schemas, merged into one description.
+    /// Tables, enums, sequences and views outside of the default schema carry their schema name as `namespace`,
+    /// so they are identified by their namespace and name.
+    async fn describe_with_namespaces(
+        &self,
+        default_schema: &str,
+        namespaces: &[String],
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        self.describe_with_filter(default_schema, namespaces, &TableFilter::default())
+            .await
+    }
+    /// Describe like `describe_with_namespaces`, skipping the tables and views the filter excludes.
+    /// Foreign keys to tables that were filtered out are kept.
+    async fn describe_with_filter(
+        &self,
+        default_schema: &str,
+        namespaces: &[String],
+        filter: &TableFilter,
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        let matcher = filter.matcher();
+        let mut result = self.describe_filtered(default_schema, &matcher).await?;
+        let mut described_namespaces: Vec<&str> = vec![default_schema];
+
+        for namespace in namespaces {
+            if described_namespaces.contains(&namespace.as_str()) {
+                continue;
+            }
+
+            described_namespaces.push(namespace);
+            let described = self.describe_filtered(namespace, &matcher).await?;
+
+            for mut table in described.tables {
+                table.namespace = Some(namespace.clone());
+                result.tables.push(table);
+            }
+
+            for mut r#enum in described.enums {
+                r#enum.namespace = Some(namespace.clone());
+                result.enums.push(r#enum);
+            }
+
+            for mut sequence in described.sequences {
+                sequence.namespace = Some(namespace.clone());
+                result.sequences.push(sequence);
+            }
+
+            for mut view in described.views {
+                view.namespace = Some(namespace.clone());
+                result.views.push(view);
+            }
+        }
+
+        // Foreign keys are described relative to the schema of their table. Make them relative to
+        // the default schema, which is represented by `None`.
+        for table in result.tables.iter_mut() {
+            let table_namespace = table.namespace.clone();
+
+            for foreign_key in table.foreign_keys.iter_mut() {
+                foreign_key.referenced_schema = match foreign_key.referenced_schema.take() {
+                    Some(ref schema) if schema == default_schema => None,
+                    Some(schema) => Some(schema),
+                    None => table_namespace.clone(),
+                };
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Glob patterns selecting the tables to describe. `*` matches any sequence of characters, `?` a single character.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableFilter {
+    /// Only tables matching one of these patterns are described. Empty means all tables.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Tables matching one of these patterns are never described.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl TableFilter {
+    /// Compiles the patterns of the filter, to match table names against them.
+    pub fn matcher(&self) -> TableMatcher {
+        TableMatcher {
+            include: self.include.iter().map(|pattern| glob_regex(pattern)).collect(),
+            exclude: self.exclude.iter().map(|pattern| glob_regex(pattern)).collect(),
+        }
+    }
+}
+
+/// A compiled [TableFilter](struct.TableFilter.html). The default matcher includes all tables.
+#[derive(Clone, Debug, Default)]
+pub struct TableMatcher {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl TableMatcher {
+    /// Whether the table with the given name passes the filter.
+    pub fn includes(&self, table: &str) -> bool {
+        let matches_any = |patterns: &[Regex]| patterns.iter().any(|pattern| pattern.is_match(table));
+
+        (self.include.is_empty() || matches_any(&self.include)) && !matches_any(&self.exclude)
+    }
+}
+
+fn glob_regex(pattern: &str) -> Regex {
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).expect("compile glob regex")
 }
 
 #[derive(Serialize, Deserialize)]
@@ -47,6 +174,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -71,6 +201,13 @@ impl SqlSchema {
         }
     }
 
+    /// Get a table of the given namespace, `None` being the default schema.
+    pub fn get_table_in_namespace(&self, namespace: Option<&str>, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|x| x.namespace.as_deref() == namespace && x.name == name)
+    }
+
     pub fn table_bang(&self, name: &str) -> &Table {
         self.table(&name).unwrap()
     }
@@ -80,11 +217,24 @@ impl SqlSchema {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
+    /// Get a view of the given namespace, `None` being the default schema.
+    pub fn get_view_in_namespace(&self, namespace: Option<&str>, name: &str) -> Option<&View> {
+        self.views
+            .iter()
+            .find(|x| x.namespace.as_deref() == namespace && x.name == name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -103,6 +253,15 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub checks: Vec<CheckConstraint>,
+    /// The schema the table lives in, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// The comment on the table, if there is one.
+    #[serde(default)]
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -145,10 +304,23 @@ impl Table {
 
     pub fn is_column_unique(&self, column_name: &String) -> bool {
         self.indices.iter().any(|index| {
-            index.tpe == IndexType::Unique && index.columns.len() == 1 && index.columns.contains(column_name)
+            index.tpe == IndexType::Unique
+                && index.columns.len() == 1
+                && index.columns.contains(column_name)
+                && index.predicate.is_none()
         })
     }
 }
+/// A check constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint name, if the database knows one.
+    pub name: Option<String>,
+    /// The boolean SQL expression every row must satisfy, without the surrounding `CHECK (...)`.
+    pub expression: String,
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -169,6 +341,89 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Sort order of each column, in the order of `columns`. Empty if all columns are sorted ascending.
+    #[serde(default)]
+    pub sort_orders: Vec<SortOrder>,
+    /// The indexed expressions, e.g. `lower(email)`, for indexes over expressions instead of plain
+    /// columns. `columns` is empty for these.
+    #[serde(default)]
+    pub expression: Option<String>,
+    /// The condition of a partial index, without the surrounding `WHERE`.
+    #[serde(default)]
+    pub predicate: Option<String>,
+    /// The index method. `None` means the default method of the database.
+    #[serde(default)]
+    pub algorithm: Option<IndexAlgorithm>,
+}
+
+impl Index {
+    /// Whether the index covers plain columns in ascending order with the default method over all rows.
+    pub fn is_plain(&self) -> bool {
+        self.sort_orders.is_empty() && self.expression.is_none() && self.predicate.is_none() && self.algorithm.is_none()
+    }
+
+    /// Whether the column at the given position of the index is sorted descending.
+    pub fn is_descending(&self, position: usize) -> bool {
+        self.sort_orders.get(position) == Some(&SortOrder::Desc)
+    }
+}
+
+/// The sort order of an index column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    /// The sort orders of an index, normalized to an empty list if all columns are sorted ascending.
+    pub fn normalize(sort_orders: Vec<SortOrder>) -> Vec<SortOrder> {
+        if sort_orders.iter().all(|order| *order == SortOrder::Asc) {
+            Vec::new()
+        } else {
+            sort_orders
+        }
+    }
+}
+
+/// The method of an index.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gist,
+    Gin,
+    SpGist,
+    Brin,
+}
+
+impl IndexAlgorithm {
+    /// Parse the method name the database reports, e.g. `btree` or `HASH`. The default `btree` method
+    /// gives `None` like unknown methods, so described indexes match indexes without a method.
+    pub fn from_db_name(name: &str) -> Option<IndexAlgorithm> {
+        match name.to_lowercase().as_str() {
+            "hash" => Some(IndexAlgorithm::Hash),
+            "gist" => Some(IndexAlgorithm::Gist),
+            "gin" => Some(IndexAlgorithm::Gin),
+            "spgist" => Some(IndexAlgorithm::SpGist),
+            "brin" => Some(IndexAlgorithm::Brin),
+            _ => None,
+        }
+    }
+
+    /// The method name in `CREATE INDEX` statements.
+    pub fn db_name(&self) -> &'static str {
+        match self {
+            IndexAlgorithm::BTree => "BTREE",
+            IndexAlgorithm::Hash => "HASH",
+            IndexAlgorithm::Gist => "GIST",
+            IndexAlgorithm::Gin => "GIN",
+            IndexAlgorithm::SpGist => "SPGIST",
+            IndexAlgorithm::Brin => "BRIN",
+        }
+    }
 }
 
 /// The primary key of a table.
@@ -200,6 +455,9 @@ pub struct Column {
     pub default: Option<String>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The comment on the column, if there is one.
+    #[serde(default)]
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -259,6 +517,8 @@ pub enum ColumnTypeFamily {
     TextSearch,
     /// Transaction ID types.
     TransactionId,
+    /// Enum types, with the name of the enum in `SqlSchema::enums`.
+    Enum(String),
     /// Unknown
     Unknown,
 }
@@ -278,6 +538,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::LogSequenceNumber => "logSequenceNumber",
             Self::TextSearch => "textSearch",
             Self::TransactionId => "transactionId",
+            Self::Enum(_) => "enum",
             Self::Unknown => "unknown",
         };
         write!(f, "{}", str)
@@ -328,6 +589,10 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The schema of the referenced table. `None` is the schema of the foreign key's table in the description of
+    /// a single schema, and the default schema in a description merged by `describe_with_namespaces`.
+    #[serde(default)]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -340,8 +605,11 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
-    pub values: HashSet<String>,
+    /// Possible enum values, in the order they were declared in.
+    pub values: Vec<String>,
+    /// The schema the enum lives in, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// A SQL sequence.
@@ -354,4 +622,50 @@ pub struct Sequence {
     pub initial_value: u32,
     /// Sequence allocation size.
     pub allocation_size: u32,
+    /// The schema the sequence lives in, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// A SQL view.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// View name.
+    pub name: String,
+    /// The SQL query defining the view, as returned by the database.
+    pub definition: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+    /// The schema the view lives in, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_filter_works() {
+        let filter = TableFilter {
+            include: vec!["billing_*".to_string(), "User".to_string()],
+            exclude: vec!["billing_?_archive".to_string()],
+        }
+        .matcher();
+
+        assert!(filter.includes("billing_invoices"));
+        assert!(filter.includes("User"));
+        assert!(filter.includes("billing_1_archive_old"));
+        assert!(!filter.includes("billing_1_archive"));
+        assert!(!filter.includes("Users"));
+        assert!(!filter.includes("shipping_orders"));
+        assert!(TableFilter::default().matcher().includes("anything"));
+    }
 }
@@ -16,7 +16,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata> {
-        let count = self.get_table_names(&schema).await.len();
+        let count = self.get_table_names(schema, &TableMatcher::default()).await.len();
         let size = self.get_size(&schema).await;
         Ok(SQLMetadata {
             table_count: count,
@@ -24,21 +24,25 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         })
     }
 
-    async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
+    async fn describe_filtered(&self, schema: &str, filter: &TableMatcher) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
 
-        let table_names = self.get_table_names(schema).await;
+        let table_names = self.get_table_names(schema, filter).await;
 
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(schema, table_name).await);
+            tables.push(self.get_table(schema, table_name).await?);
         }
 
+        let views = self.get_views(schema, filter).await;
+        let enums = self.get_enums(schema).await;
+
         Ok(SqlSchema {
             tables,
-            enums: vec![],
+            enums,
             sequences: vec![],
+            views,
         })
     }
 }
@@ -66,11 +70,10 @@ impl SqlSchemaDescriber {
         names
     }
 
-    async fn get_table_names(&self, schema: &str) -> Vec<String> {
+    async fn get_table_names(&self, schema: &str, filter: &TableMatcher) -> Vec<String> {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -85,12 +88,43 @@ impl SqlSchemaDescriber {
                     .and_then(|x| x.to_string())
                     .expect("get table name")
             })
+            .filter(|name| filter.includes(name))
             .collect();
 
         debug!("Found table names: {:?}", names);
         names
     }
 
+    async fn get_views(&self, schema: &str, filter: &TableMatcher) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let mut views = Vec::new();
+
+        for row in rows.into_iter() {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            if !filter.includes(&name) {
+                continue;
+            }
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let columns = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+                namespace: None,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql = "SELECT 
@@ -111,18 +145,133 @@ impl SqlSchemaDescriber {
         size.parse().unwrap()
     }
 
-    async fn get_table(&self, schema: &str, name: &str) -> Table {
+    async fn get_table(&self, schema: &str, name: &str) -> SqlSchemaDescriberResult<Table> {
         debug!("Getting table '{}'", name);
         let columns = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let (indices, primary_key) = self.get_indices(schema, name, &foreign_keys).await;
-        Table {
+        let checks = self.get_checks(schema, name).await?;
+        let comment = self.get_table_comment(schema, name).await;
+        Ok(Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            checks,
+            namespace: None,
+            comment,
+        })
+    }
+
+    async fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT table_comment table_comment FROM information_schema.tables
+            WHERE table_schema = ? AND table_name = ?";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .expect("querying for table comment");
+
+        // MySQL reports tables without a comment with an empty one.
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("table_comment").and_then(|x| x.to_string()))
+            .filter(|comment| !comment.is_empty())
+    }
+
+    async fn get_checks(&self, schema: &str, table: &str) -> SqlSchemaDescriberResult<Vec<CheckConstraint>> {
+        // Check constraints are only enforced and exposed from MySQL 8.0.16 on. Older versions
+        // don't have the `check_constraints` table, so they can't have any.
+        if !self.has_check_constraints_table().await? {
+            return Ok(Vec::new());
         }
+
+        let sql = "
+            SELECT
+                tc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.table_constraints AS tc
+            INNER JOIN information_schema.check_constraints AS cc ON
+                cc.constraint_schema = tc.constraint_schema
+                AND cc.constraint_name = tc.constraint_name
+            WHERE
+                tc.table_schema = ?
+                AND tc.table_name = ?
+                AND tc.constraint_type = 'CHECK'
+            ORDER BY tc.constraint_name
+        ";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .map_err(|err| SqlSchemaDescriberError::QueryError(format!("querying for check constraints: {}", err)))?;
+
+        let checks = rows
+            .into_iter()
+            .map(|row| CheckConstraint {
+                name: row.get("constraint_name").and_then(|x| x.to_string()),
+                expression: row
+                    .get("check_clause")
+                    .and_then(|x| x.to_string())
+                    .expect("get check clause"),
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", checks);
+        Ok(checks)
+    }
+
+    async fn has_check_constraints_table(&self) -> SqlSchemaDescriberResult<bool> {
+        let sql = "SELECT table_name table_name FROM information_schema.tables
+            WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'";
+        let rows = self.conn.query_raw(sql, &[]).await.map_err(|err| {
+            SqlSchemaDescriberError::QueryError(format!("querying for the check constraints table: {}", err))
+        })?;
+
+        Ok(rows.into_iter().next().is_some())
+    }
+
+    /// MySQL enums are defined inline on their column. Each `ENUM` and `SET` column is described
+    /// as an enum of its own, named after the table and the column.
+    async fn get_enums(&self, schema: &str) -> Vec<Enum> {
+        debug!("Getting enums");
+        let sql = "SELECT table_name table_name, column_name column_name, column_type column_type
+            FROM information_schema.columns
+            WHERE table_schema = ? AND data_type IN ('enum', 'set')
+            ORDER BY table_name, column_name";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for enums");
+
+        let enums = rows
+            .into_iter()
+            .map(|row| {
+                let table = row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table name");
+                let column = row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
+                let column_type = row
+                    .get("column_type")
+                    .and_then(|x| x.to_string())
+                    .expect("get column type");
+
+                Enum {
+                    name: enum_name(&table, &column),
+                    values: parse_enum_values(&column_type).into_iter().collect(),
+                    namespace: None,
+                }
+            })
+            .collect();
+
+        debug!("Found enums: {:?}", enums);
+        enums
     }
 
     async fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
@@ -130,7 +279,8 @@ impl SqlSchemaDescriber {
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_type full_data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_type full_data_type, column_default column_default, is_nullable is_nullable, extra extra,
+                column_comment column_comment
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -165,7 +315,11 @@ impl SqlSchemaDescriber {
                 } else {
                     ColumnArity::Nullable
                 };
-                let tpe = get_column_type(&data_type, &full_data_type, arity);
+                let column_name = col
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
+                let tpe = get_column_type(&data_type, &full_data_type, arity, table, &column_name);
                 let extra = col
                     .get("extra")
                     .and_then(|x| x.to_string())
@@ -176,13 +330,14 @@ impl SqlSchemaDescriber {
                     _ => false,
                 };
                 Column {
-                    name: col
-                        .get("column_name")
-                        .and_then(|x| x.to_string())
-                        .expect("get column name"),
+                    name: column_name,
                     tpe,
                     default: col.get("column_default").and_then(|x| x.to_string()),
                     auto_increment: auto_increment,
+                    comment: col
+                        .get("column_comment")
+                        .and_then(|x| x.to_string())
+                        .filter(|comment| !comment.is_empty()),
                 }
             })
             .collect();
@@ -282,6 +437,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: None,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                     };
@@ -320,7 +476,9 @@ impl SqlSchemaDescriber {
                 index_name AS index_name,
                 non_unique AS non_unique,
                 column_name AS column_name,
-                seq_in_index AS seq_in_index
+                seq_in_index AS seq_in_index,
+                collation AS collation,
+                index_type AS index_type
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ? AND table_name = ?
             ORDER BY index_name, seq_in_index
@@ -337,6 +495,8 @@ impl SqlSchemaDescriber {
 
         let mut primary_key: Option<PrimaryKey> = None;
         let mut indexes_map: BTreeMap<String, Index> = BTreeMap::new();
+        // Indexes with functional key parts, which have no column name.
+        let mut expression_indexes: Vec<String> = Vec::new();
 
         for row in rows {
             debug!("Got index row: {:#?}", row);
@@ -344,9 +504,18 @@ impl SqlSchemaDescriber {
             let pos = seq_in_index - 1;
             let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
             let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
-            let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
+            let column_name = row.get("column_name").and_then(|x| x.to_string());
+            let sort_order = match row.get("collation").and_then(|x| x.to_string()).as_deref() {
+                Some("D") => SortOrder::Desc,
+                _ => SortOrder::Asc,
+            };
+            let algorithm = row
+                .get("index_type")
+                .and_then(|x| x.to_string())
+                .and_then(|name| IndexAlgorithm::from_db_name(&name));
             let is_pk = index_name.to_lowercase() == "primary";
             if is_pk {
+                let column_name = column_name.expect("primary key column_name");
                 debug!("Column '{}' is part of the primary key", column_name);
                 match primary_key.as_mut() {
                     Some(pk) => {
@@ -368,29 +537,47 @@ impl SqlSchemaDescriber {
                     }
                 };
             } else {
-                if indexes_map.contains_key(&index_name) {
-                    indexes_map.get_mut(&index_name).map(|index: &mut Index| {
+                let index = indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                    name: index_name.clone(),
+                    columns: vec![],
+                    tpe: match is_unique {
+                        true => IndexType::Unique,
+                        false => IndexType::Normal,
+                    },
+                    sort_orders: vec![],
+                    expression: None,
+                    predicate: None,
+                    algorithm,
+                });
+
+                match column_name {
+                    Some(column_name) => {
                         index.columns.push(column_name);
-                    });
-                } else {
-                    indexes_map.insert(
-                        index_name.clone(),
-                        Index {
-                            name: index_name,
-                            columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
-                            },
-                        },
-                    );
+                        index.sort_orders.push(sort_order);
+                    }
+                    // Functional key parts have no column, the whole key list becomes the expression.
+                    None if !expression_indexes.contains(&index_name) => expression_indexes.push(index_name),
+                    None => (),
                 }
             }
         }
 
+        for index_name in expression_indexes {
+            let expression = self.get_index_expression(schema, table_name, &index_name).await;
+            let index = indexes_map
+                .get_mut(&index_name)
+                .expect("index with functional key parts");
+            index.columns = Vec::new();
+            index.sort_orders = Vec::new();
+            index.expression = Some(expression);
+        }
+
         let indices = indexes_map
             .into_iter()
-            .map(|(_k, v)| v)
+            .map(|(_k, mut v)| {
+                v.sort_orders = SortOrder::normalize(v.sort_orders);
+                v
+            })
             // Remove foreign keys, because they are introspected separately. But if there is a unique constraint on that column we need it to identify 1:1 relations
             .filter(|index| {
                 foreign_keys.iter().find(|fk| fk.columns == index.columns).is_none() || index.tpe == IndexType::Unique
@@ -400,9 +587,53 @@ impl SqlSchemaDescriber {
         debug!("Found table indices: {:?}, primary key: {:?}", indices, primary_key);
         (indices, primary_key)
     }
+
+    /// The key list of an index with functional key parts, as it would be written in `CREATE INDEX`.
+    /// Only MySQL 8.0.13 and later have functional key parts and the `expression` column.
+    async fn get_index_expression(&self, schema: &str, table_name: &str, index_name: &str) -> String {
+        let sql = "
+            SELECT
+                column_name AS column_name,
+                expression AS expression,
+                collation AS collation
+            FROM INFORMATION_SCHEMA.STATISTICS
+            WHERE table_schema = ? AND table_name = ? AND index_name = ?
+            ORDER BY seq_in_index
+            ";
+        debug!("describing index expression, SQL: {}", sql);
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table_name.into(), index_name.into()])
+            .await
+            .expect("querying for index expression");
+
+        let key_parts: Vec<String> = rows
+            .into_iter()
+            .map(|row| {
+                let key_part = match row.get("column_name").and_then(|x| x.to_string()) {
+                    Some(column_name) => format!("`{}`", column_name),
+                    None => format!(
+                        "({})",
+                        row.get("expression").and_then(|x| x.to_string()).expect("expression")
+                    ),
+                };
+                match row.get("collation").and_then(|x| x.to_string()).as_deref() {
+                    Some("D") => format!("{} DESC", key_part),
+                    _ => key_part,
+                }
+            })
+            .collect();
+
+        key_parts.join(", ")
+    }
 }
 
-fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
+fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity, table: &str, column: &str) -> ColumnType {
+    // A `SET` column holds any number of its values.
+    let arity = match data_type {
+        "set" => ColumnArity::List,
+        _ => arity,
+    };
     let family = match (data_type, full_data_type) {
         ("int", _) => ColumnTypeFamily::Int,
         ("smallint", _) => ColumnTypeFamily::Int,
@@ -425,9 +656,8 @@ fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) ->
         ("tinytext", _) => ColumnTypeFamily::String,
         ("mediumtext", _) => ColumnTypeFamily::String,
         ("longtext", _) => ColumnTypeFamily::String,
-        // XXX: Is this correct?
-        ("enum", _) => ColumnTypeFamily::String,
-        ("set", _) => ColumnTypeFamily::String,
+        ("enum", _) => ColumnTypeFamily::Enum(enum_name(table, column)),
+        ("set", _) => ColumnTypeFamily::Enum(enum_name(table, column)),
         ("binary", _) => ColumnTypeFamily::Binary,
         ("varbinary", _) => ColumnTypeFamily::Binary,
         ("blob", _) => ColumnTypeFamily::Binary,
@@ -451,3 +681,50 @@ fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) ->
         arity,
     }
 }
+
+/// The name under which the inline enum of a column is described.
+pub fn enum_name(table: &str, column: &str) -> String {
+    format!("{}_{}", table, column)
+}
+
+/// Parses the values out of a column type like `enum('a','b')` or `set('a','b')`. Quotes in
+/// values are escaped by doubling them.
+fn parse_enum_values(column_type: &str) -> Vec<String> {
+    let start = column_type.find('(').map(|idx| idx + 1).unwrap_or(0);
+    let end = column_type.rfind(')').unwrap_or_else(|| column_type.len());
+    let mut chars = column_type[start..end].chars().peekable();
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if in_quotes && chars.peek() == Some(&'\'') => {
+                current.push('\'');
+                chars.next();
+            }
+            '\'' if in_quotes => {
+                values.push(std::mem::replace(&mut current, String::new()));
+                in_quotes = false;
+            }
+            '\'' => in_quotes = true,
+            c if in_quotes => current.push(c),
+            _ => (),
+        }
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mysql_parse_enum_values_works() {
+        assert_eq!(parse_enum_values("enum('black','white')"), vec!["black", "white"]);
+        assert_eq!(parse_enum_values("set('a,b','it''s')"), vec!["a,b", "it's"]);
+        assert_eq!(parse_enum_values("enum('')"), vec![""]);
+    }
+}
@@ -4,7 +4,7 @@ use log::debug;
 use once_cell::sync::Lazy;
 use quaint::prelude::Queryable;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -20,7 +20,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata> {
-        let count = self.get_table_names(&schema).await.len();
+        let count = self.get_table_names(schema, &TableMatcher::default()).await.len();
         let size = self.get_size(&schema).await;
        Ok(SQLMetadata {
             table_count: count,
@@ -28,22 +28,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         })
     }
 
-    async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
+    async fn describe_filtered(&self, schema: &str, filter: &TableMatcher) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema).await?;
-        let table_names = self.get_table_names(schema).await;
+        let enums = self.get_enums(schema).await?;
+        let table_names = self.get_table_names(schema, filter).await;
 
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(schema, &table_name, &sequences).await);
+            tables.push(self.get_table(schema, table_name, &sequences, &enums).await);
         }
 
-        let enums = self.get_enums(schema).await?;
+        let views = self.get_views(schema, &enums, filter).await;
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 }
@@ -71,11 +73,10 @@ impl SqlSchemaDescriber {
         names
     }
 
-    async fn get_table_names(&self, schema: &str) -> Vec<String> {
+    async fn get_table_names(&self, schema: &str, filter: &TableMatcher) -> Vec<String> {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -90,12 +91,43 @@ impl SqlSchemaDescriber {
                     .and_then(|x| x.to_string())
                     .expect("get table name")
             })
+            .filter(|name| filter.includes(name))
             .collect();
 
         debug!("Found table names: {:?}", names);
         names
     }
 
+    async fn get_views(&self, schema: &str, enums: &[Enum], filter: &TableMatcher) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let mut views = Vec::new();
+
+        for row in rows.into_iter() {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            if !filter.includes(&name) {
+                continue;
+            }
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let columns = self.get_columns(schema, &name, enums).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+                namespace: None,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -116,22 +148,77 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    async fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>) -> Table {
+    async fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>, enums: &[Enum]) -> Table {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name).await;
+        let columns = self.get_columns(schema, name, enums).await;
         let (indices, primary_key) = self.get_indices(schema, name, sequences).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
+        let checks = self.get_checks(schema, name).await;
+        let comment = self.get_table_comment(schema, name).await;
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            checks,
+            namespace: None,
+            comment,
         }
     }
 
-    async fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, data_type, udt_name as full_column_type, column_default, is_nullable, is_identity, data_type
+    async fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT obj_description(cls.oid, 'pg_class') AS comment
+            FROM pg_class cls
+            INNER JOIN pg_namespace nsp ON nsp.oid = cls.relnamespace
+            WHERE nsp.nspname = $1 AND cls.relname = $2";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .expect("querying for table comment");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("comment").and_then(|x| x.to_string()))
+    }
+
+    async fn get_checks(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = "SELECT con.conname AS constraint_name, pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+            INNER JOIN pg_class rel ON rel.oid = con.conrelid
+            INNER JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+            WHERE nsp.nspname = $1 AND rel.relname = $2 AND con.contype = 'c'
+            ORDER BY con.conname";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let checks = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("constraint_name").and_then(|x| x.to_string());
+                let definition = row
+                    .get("definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get check constraint definition");
+
+                CheckConstraint {
+                    name,
+                    expression: strip_check_keyword(&definition),
+                }
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
+    async fn get_columns(&self, schema: &str, table: &str, enums: &[Enum]) -> Vec<Column> {
+        let sql = "SELECT column_name, data_type, udt_name as full_column_type, column_default, is_nullable, is_identity, data_type,
+                col_description(format('%I.%I', table_schema, table_name)::regclass, ordinal_position) AS column_comment
             FROM information_schema.columns
             WHERE table_schema = $1 AND table_name = $2
             ORDER BY column_name";
@@ -181,23 +268,32 @@ impl SqlSchemaDescriber {
                 } else {
                     ColumnArity::Nullable
                 };
-                let tpe = get_column_type(data_type.as_ref(), &full_data_type, arity);
+                let tpe = get_column_type(data_type.as_ref(), &full_data_type, arity, enums);
 
                 let default = col.get("column_default").and_then(|param_value| {
                     param_value
                         .to_string()
                         .map(|x| x.replace("\'", "").replace("::text", ""))
                 });
+                // Enum defaults are cast to the enum type, e.g. `'happy'::mood`.
+                let default = match &tpe.family {
+                    ColumnTypeFamily::Enum(name) => {
+                        default.map(|value| value.trim_end_matches(&format!("::{}", name)).to_owned())
+                    }
+                    _ => default,
+                };
                 let is_auto_increment = is_identity
                     || match default {
                         Some(ref val) => is_autoincrement(val, schema, table, &col_name),
                         _ => false,
                     };
+                let comment = col.get("column_comment").and_then(|x| x.to_string());
                 Column {
                     name: col_name,
                     tpe,
                     default,
                     auto_increment: is_auto_increment,
+                    comment,
                 }
             })
             .collect();
@@ -211,6 +307,7 @@ impl SqlSchemaDescriber {
                 con.oid as \"con_id\",
                 att2.attname as \"child_column\",
                 cl.relname as \"parent_table\",
+                parent_ns.nspname as \"parent_schema\",
                 att.attname as \"parent_column\",
                 con.confdeltype,
                 conname as constraint_name
@@ -236,6 +333,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id";
@@ -261,6 +360,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .filter(|parent_schema| parent_schema != schema);
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -291,6 +394,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                     };
@@ -322,15 +426,40 @@ impl SqlSchemaDescriber {
         sequences: &Vec<Sequence>,
     ) -> (Vec<Index>, Option<PrimaryKey>) {
         let sql = "SELECT indexInfos.relname as name,
-            array_agg(columnInfos.attname) as column_names,
+            -- the index columns in index order, expression keys have no column
+            ARRAY(
+                SELECT columnInfos.attname
+                FROM unnest(rawIndex.indkey::int2[]) WITH ORDINALITY AS keys(attnum, position)
+                -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
+                JOIN pg_attribute columnInfos
+                ON columnInfos.attrelid = tableInfos.oid AND columnInfos.attnum = keys.attnum
+                ORDER BY keys.position
+            ) as column_names,
+            -- the lowest bit of the per-key options is set for descending keys
+            ARRAY(
+                SELECT CASE WHEN (keys.option & 1) = 1 THEN 'DESC' ELSE 'ASC' END
+                FROM unnest(rawIndex.indoption::int2[]) WITH ORDINALITY AS keys(option, position)
+                ORDER BY keys.position
+            ) as sort_orders,
+            -- for indexes over expressions, the whole key list as it would be written in CREATE INDEX
+            CASE WHEN rawIndex.indexprs IS NULL THEN NULL ELSE (
+                SELECT string_agg(
+                    pg_get_indexdef(rawIndex.indexrelid, keys.position::int, true)
+                        || CASE WHEN (keys.option & 1) = 1 THEN ' DESC' ELSE '' END,
+                    ', ' ORDER BY keys.position
+                )
+                FROM unnest(rawIndex.indoption::int2[]) WITH ORDINALITY AS keys(option, position)
+            ) END as expression,
+            pg_get_expr(rawIndex.indpred, rawIndex.indrelid) as predicate,
+            accessMethod.amname as algorithm,
             rawIndex.indisunique as is_unique, rawIndex.indisprimary as is_primary_key
             FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
             pg_class tableInfos, pg_class indexInfos,
             -- pg_index stores indices: https://www.postgresql.org/docs/current/catalog-pg-index.html
             pg_index rawIndex,
-            -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
-            pg_attribute columnInfos,
+            -- pg_am stores the index methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am accessMethod,
             -- pg_namespace stores info about the schema
             pg_namespace schemaInfo
             WHERE
@@ -338,17 +467,15 @@ impl SqlSchemaDescriber {
             tableInfos.oid = rawIndex.indrelid
             -- find index info
             AND indexInfos.oid = rawIndex.indexrelid
-            -- find table columns
-            AND columnInfos.attrelid = tableInfos.oid
-            AND columnInfos.attnum = ANY(rawIndex.indkey)
+            -- find the index method
+            AND accessMethod.oid = indexInfos.relam
             -- we only consider ordinary tables
             AND tableInfos.relkind = 'r'
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
             AND tableInfos.relname = $2
-            GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique,
-            rawIndex.indisprimary";
+            ORDER BY indexInfos.relname";
         debug!("Getting indices: {}", sql);
         let rows = self
             .conn
@@ -374,13 +501,38 @@ impl SqlSchemaDescriber {
                 pk = Some(self.infer_primary_key(schema, table_name, columns, sequences).await);
             } else {
                 let is_unique = index.get("is_unique").and_then(|x| x.as_bool()).expect("is_unique");
+                let expression = index.get("expression").and_then(|x| x.to_string());
+                // The sort orders of expression indexes are part of the expression.
+                let sort_orders = match expression {
+                    Some(_) => Vec::new(),
+                    None => index
+                        .get("sort_orders")
+                        .and_then(|x| x.clone().into_vec::<String>())
+                        .expect("sort_orders")
+                        .iter()
+                        .map(|order| match order.as_str() {
+                            "DESC" => SortOrder::Desc,
+                            _ => SortOrder::Asc,
+                        })
+                        .collect(),
+                };
                 indices.push(Index {
                     name: index.get("name").and_then(|x| x.to_string()).expect("name"),
-                    columns,
+                    columns: match expression {
+                        Some(_) => Vec::new(),
+                        None => columns,
+                    },
                     tpe: match is_unique {
                         true => IndexType::Unique,
                         false => IndexType::Normal,
                     },
+                    sort_orders: SortOrder::normalize(sort_orders),
+                    expression,
+                    predicate: index.get("predicate").and_then(|x| x.to_string()),
+                    algorithm: index
+                        .get("algorithm")
+                        .and_then(|x| x.to_string())
+                        .and_then(|name| IndexAlgorithm::from_db_name(&name)),
                 })
             }
         }
@@ -459,6 +611,7 @@ impl SqlSchemaDescriber {
                         .get("sequence_name")
                         .and_then(|x| x.to_string())
                         .expect("get sequence_name"),
+                    namespace: None,
                 }
             })
             .collect();
@@ -473,35 +626,40 @@ impl SqlSchemaDescriber {
             FROM pg_type t
             JOIN pg_enum e ON t.oid = e.enumtypid
             JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            WHERE n.nspname = $1";
+            WHERE n.nspname = $1
+            ORDER BY t.typname, e.enumsortorder";
         let rows = self
             .conn
             .query_raw(&sql, &[schema.into()])
             .await
             .expect("querying for enums");
-        let mut enum_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut enums: Vec<Enum> = Vec::new();
         for row in rows.into_iter() {
             debug!("Got enum row: {:?}", row);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let value = row.get("value").and_then(|x| x.to_string()).expect("get value");
-            if !enum_values.contains_key(&name) {
-                enum_values.insert(name.clone(), HashSet::new());
+
+            // The rows are ordered by enum, and by the position of the value within the enum.
+            match enums.last_mut() {
+                Some(enm) if enm.name == name => enm.values.push(value),
+                _ => enums.push(Enum {
+                    name,
+                    values: vec![value],
+                    namespace: None,
+                }),
             }
-            let vals = enum_values.get_mut(&name).expect("get enum values");
-            vals.insert(value);
         }
 
-        let enums: Vec<Enum> = enum_values
-            .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
-            .collect();
         debug!("Found enums: {:?}", enums);
         Ok(enums)
     }
 }
 
-fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
+fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity, enums: &[Enum]) -> ColumnType {
+    // Array types are named after their element type, with a leading underscore.
+    let element_type = full_data_type.trim_start_matches('_');
     let family = match full_data_type {
+        _ if enums.iter().any(|e| e.name == element_type) => ColumnTypeFamily::Enum(element_type.to_owned()),
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
         "int8" => ColumnTypeFamily::Int,
@@ -540,9 +698,18 @@ fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -
         "_date" => ColumnTypeFamily::DateTime,
         "_float8" => ColumnTypeFamily::Float,
         "_float4" => ColumnTypeFamily::Float,
+        "_int2" => ColumnTypeFamily::Int,
         "_int4" => ColumnTypeFamily::Int,
+        "_int8" => ColumnTypeFamily::Int,
+        "_numeric" => ColumnTypeFamily::Float,
         "_text" => ColumnTypeFamily::String,
         "_varchar" => ColumnTypeFamily::String,
+        "_bpchar" => ColumnTypeFamily::String,
+        "_timestamp" => ColumnTypeFamily::DateTime,
+        "_timestamptz" => ColumnTypeFamily::DateTime,
+        "_uuid" => ColumnTypeFamily::Uuid,
+        "_json" => ColumnTypeFamily::Json,
+        "_jsonb" => ColumnTypeFamily::Json,
         _ => ColumnTypeFamily::Unknown,
     };
     ColumnType {
@@ -590,10 +757,54 @@ fn is_autoincrement(value: &str, schema_name: &str, table_name: &str, column_nam
         .unwrap_or(false)
 }
 
+/// Turns a definition as returned by `pg_get_constraintdef`, e.g. `CHECK ((price >= 0))`, into
+/// the bare expression.
+fn strip_check_keyword(definition: &str) -> String {
+    let definition = definition.trim().trim_end_matches(" NOT VALID");
+    let expression = definition.trim_start_matches("CHECK").trim();
+
+    // Postgres wraps the expression in parentheses of its own, on top of the ones of `CHECK (...)`.
+    strip_wrapping_parentheses(strip_wrapping_parentheses(expression)).to_string()
+}
+
+/// Removes one pair of parentheses around the whole expression, but not the ones of
+/// `(a > 0) AND (b > 0)`.
+fn strip_wrapping_parentheses(expression: &str) -> &str {
+    if !expression.starts_with('(') || !expression.ends_with(')') {
+        return expression;
+    }
+
+    let mut depth = 0;
+
+    for (idx, c) in expression.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+
+        if depth == 0 && idx < expression.len() - 1 {
+            return expression;
+        }
+    }
+
+    expression[1..expression.len() - 1].trim()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn postgres_strip_check_keyword_works() {
+        assert_eq!(strip_check_keyword("CHECK ((price >= 0))"), "price >= 0");
+        assert_eq!(strip_check_keyword("CHECK ((price >= 0)) NOT VALID"), "price >= 0");
+        assert_eq!(
+            strip_check_keyword("CHECK (((price >= 0) AND (price < 100)))"),
+            "(price >= 0) AND (price < 100)"
+        );
+    }
+
     #[test]
     fn postgres_is_autoincrement_works() {
         let schema_name = "prisma";
@@ -2,6 +2,7 @@
 use super::*;
 use failure::_core::convert::TryInto;
 use log::debug;
+use once_cell::sync::Lazy;
 use quaint::{ast::ParameterizedValue, prelude::Queryable};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -18,7 +19,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata> {
-        let count = self.get_table_names(&schema).await.len();
+        let count = self.get_table_names(schema, &TableMatcher::default()).await.len();
         let size = self.get_size(&schema).await;
         Ok(SQLMetadata {
             table_count: count,
@@ -26,9 +27,9 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         })
     }
 
-    async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
+    async fn describe_filtered(&self, schema: &str, filter: &TableMatcher) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
-        let table_names: Vec<String> = self.get_table_names(schema).await;
+        let table_names: Vec<String> = self.get_table_names(schema, filter).await;
 
         let mut tables = Vec::with_capacity(table_names.len());
 
@@ -36,12 +37,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(self.get_table(schema, table_name).await)
         }
 
+        let views = self.get_views(schema, filter).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views,
         })
     }
 }
@@ -70,7 +74,7 @@ impl SqlSchemaDescriber {
         names
     }
 
-    async fn get_table_names(&self, schema: &str) -> Vec<String> {
+    async fn get_table_names(&self, schema: &str, filter: &TableMatcher) -> Vec<String> {
         let sql = format!(r#"SELECT name FROM "{}".sqlite_master WHERE type='table'"#, schema);
         debug!("describing table names with query: '{}'", sql);
         let result_set = self.conn.query_raw(&sql, &[]).await.expect("get table names");
@@ -78,11 +82,43 @@ impl SqlSchemaDescriber {
             .into_iter()
             .map(|row| row.get("name").and_then(|x| x.to_string()).unwrap())
             .filter(|n| n != "sqlite_sequence")
+            .filter(|n| filter.includes(n))
             .collect();
         debug!("Found table names: {:?}", names);
         names
     }
 
+    async fn get_views(&self, schema: &str, filter: &TableMatcher) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get view name");
+            if !filter.includes(&name) {
+                continue;
+            }
+            // SQLite stores the whole `CREATE VIEW` statement, we only want the query.
+            let definition = row
+                .get("sql")
+                .and_then(|x| x.to_string())
+                .map(|sql| strip_create_view(&sql));
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+                namespace: None,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -100,15 +136,44 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
+        let checks = self.get_checks(schema, name).await;
         Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
+            checks,
+            namespace: None,
+            comment: None,
         }
     }
 
+    /// SQLite does not expose check constraints in any pragma, so they are parsed from the
+    /// `CREATE TABLE` statement stored in `sqlite_master`.
+    async fn get_checks(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name=?"#,
+            schema
+        );
+        debug!("describing table check constraints, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let checks = result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table| parse_check_constraints(&create_table))
+            .unwrap_or_else(Vec::new);
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
     async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
         let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, table);
         debug!("describing table columns, query: '{}'", sql);
@@ -138,6 +203,7 @@ impl SqlSchemaDescriber {
                     tpe,
                     default: default_value.clone(),
                     auto_increment: false,
+                    comment: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -275,6 +341,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
 
@@ -306,6 +373,7 @@ impl SqlSchemaDescriber {
 
         for row in filtered_rows {
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
+            let is_partial = row.get("partial").and_then(|x| x.as_bool()).unwrap_or(false);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let mut index = Index {
                 name: name.clone(),
@@ -314,26 +382,83 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             };
 
-            let sql = format!(r#"PRAGMA "{}".index_info("{}");"#, schema, name);
+            // Unlike `index_info`, `index_xinfo` also describes the sort order and expression keys.
+            let sql = format!(r#"PRAGMA "{}".index_xinfo("{}");"#, schema, name);
             debug!("describing table index '{}', SQL: '{}'", name, sql);
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             debug!("Got index description results: {:?}", result_set);
+            let mut has_expression = false;
             for row in result_set.into_iter() {
+                // Auxiliary columns like the rowid are not part of the key.
+                if !row.get("key").and_then(|x| x.as_bool()).expect("get key") {
+                    continue;
+                }
+
                 let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
-                let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+                let sort_order = match row.get("desc").and_then(|x| x.as_bool()).expect("get desc") {
+                    true => SortOrder::Desc,
+                    false => SortOrder::Asc,
+                };
+                // Expression keys have no column name.
+                let col_name = match row.get("name").and_then(|x| x.to_string()) {
+                    Some(col_name) => col_name,
+                    None => {
+                        has_expression = true;
+                        continue;
+                    }
+                };
                 if index.columns.len() <= pos {
                     index.columns.resize(pos + 1, "".to_string());
+                    index.sort_orders.resize(pos + 1, SortOrder::Asc);
                 }
                 index.columns[pos] = col_name;
+                index.sort_orders[pos] = sort_order;
             }
 
+            if has_expression || is_partial {
+                let create_index = self.get_create_index(schema, &name).await;
+                let (key_list, predicate) = parse_create_index(&create_index);
+                if has_expression {
+                    index.columns = Vec::new();
+                    index.sort_orders = Vec::new();
+                    index.expression = key_list;
+                }
+                index.predicate = predicate;
+            }
+
+            index.sort_orders = SortOrder::normalize(index.sort_orders);
             indices.push(index)
         }
 
         indices
     }
+
+    /// The `CREATE INDEX` statement stored in `sqlite_master`. SQLite does not expose index
+    /// expressions and partial index conditions in any pragma.
+    async fn get_create_index(&self, schema: &str, index: &str) -> String {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='index' AND name=?"#,
+            schema
+        );
+        debug!("describing index definition, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[index.into()])
+            .await
+            .expect("querying for index definition");
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .expect("get index definition")
+    }
 }
 
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
@@ -370,6 +495,158 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
     }
 }
 
+/// Returns the query part of a `CREATE VIEW name AS query` statement.
+fn strip_create_view(create_view: &str) -> String {
+    match CREATE_VIEW_AS_REGEX.find(create_view) {
+        Some(m) => create_view[m.end()..].trim().to_string(),
+        None => create_view.to_string(),
+    }
+}
+
+static CREATE_VIEW_AS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bAS\b").expect("compile regex"));
+
+/// Extracts the `[CONSTRAINT name] CHECK (expression)` clauses of a `CREATE TABLE` statement,
+/// both at the column and at the table level.
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    let chars: Vec<char> = create_table.chars().collect();
+    let upper: Vec<char> = create_table.to_uppercase().chars().collect();
+    let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut checks = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut i = 0;
+
+    // Upper-casing can change the length of non-ASCII strings, in which case we give up.
+    if chars.len() != upper.len() {
+        return checks;
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+
+        if c == '\'' || c == '"' || c == '`' {
+            quote = Some(c);
+            i += 1;
+            continue;
+        }
+
+        let is_check_keyword = upper[i..].starts_with(&['C', 'H', 'E', 'C', 'K'])
+            && (i == 0 || !is_identifier_char(chars[i - 1]))
+            && chars.get(i + 5).map(|c| !is_identifier_char(*c)).unwrap_or(false);
+
+        if !is_check_keyword {
+            i += 1;
+            continue;
+        }
+
+        let open = match (i + 5..chars.len()).find(|j| !chars[*j].is_whitespace()) {
+            Some(open) if chars[open] == '(' => open,
+            _ => {
+                i += 5;
+                continue;
+            }
+        };
+
+        let mut depth = 0;
+        let mut close = None;
+        let mut inner_quote: Option<char> = None;
+        for j in open..chars.len() {
+            match (inner_quote, chars[j]) {
+                (Some(q), c) if c == q => inner_quote = None,
+                (Some(_), _) => (),
+                (None, c) if c == '\'' || c == '"' || c == '`' => inner_quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = Some(j);
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        let close = match close {
+            Some(close) => close,
+            None => break,
+        };
+
+        let expression: String = chars[open + 1..close].iter().collect();
+        let preceding: String = chars[..i].iter().collect();
+        let mut preceding_words = preceding.split_whitespace().rev();
+        let name = match (preceding_words.next(), preceding_words.next()) {
+            (Some(name), Some(keyword)) if keyword.eq_ignore_ascii_case("constraint") => {
+                Some(name.trim_matches(|c| c == '"' || c == '`' || c == '\'').to_string())
+            }
+            _ => None,
+        };
+
+        checks.push(CheckConstraint {
+            name,
+            expression: expression.trim().to_string(),
+        });
+
+        i = close + 1;
+    }
+
+    checks
+}
+
+/// Splits a `CREATE INDEX name ON table (key list) [WHERE condition]` statement into the key list and
+/// the condition of a partial index.
+fn parse_create_index(create_index: &str) -> (Option<String>, Option<String>) {
+    let chars: Vec<char> = create_index.chars().collect();
+    let mut depth = 0;
+    let mut open = None;
+    let mut close = None;
+    let mut quote: Option<char> = None;
+
+    for (i, c) in chars.iter().enumerate() {
+        match (quote, *c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, c) if c == '\'' || c == '"' || c == '`' || c == '[' => quote = Some(if c == '[' { ']' } else { c }),
+            (None, '(') => {
+                if depth == 0 {
+                    open = Some(i);
+                }
+                depth += 1;
+            }
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let (open, close) = match (open, close) {
+        (Some(open), Some(close)) => (open, close),
+        _ => return (None, None),
+    };
+
+    let key_list: String = chars[open + 1..close].iter().collect();
+    let rest: String = chars[close + 1..].iter().collect();
+    let rest = rest.trim();
+    let predicate = match rest.get(..5) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("where") => Some(rest[5..].trim().to_string()),
+        _ => None,
+    };
+
+    (Some(key_list.trim().to_string()), predicate)
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -385,3 +662,32 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqlite_parse_check_constraints_works() {
+        let create_table = r#"CREATE TABLE "Product" (
+            "id" INTEGER PRIMARY KEY,
+            "price" REAL CHECK ("price" >= 0),
+            "name" TEXT DEFAULT 'check (me)',
+            CONSTRAINT "name_not_empty" CHECK (length("name") > 0)
+        )"#;
+
+        assert_eq!(
+            parse_check_constraints(create_table),
+            vec![
+                CheckConstraint {
+                    name: None,
+                    expression: r#""price" >= 0"#.to_string(),
+                },
+                CheckConstraint {
+                    name: Some("name_not_empty".to_string()),
+                    expression: r#"length("name") > 0"#.to_string(),
+                },
+            ]
+        );
+    }
+}
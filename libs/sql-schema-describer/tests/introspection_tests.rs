@@ -63,6 +63,7 @@ fn is_required_must_work() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -73,6 +74,7 @@ fn is_required_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
 
@@ -117,6 +119,7 @@ fn foreign_keys_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 ];
 
@@ -141,8 +144,12 @@ fn foreign_keys_must_work() {
                             columns: vec!["city".to_string()],
                             referenced_columns: vec!["id".to_string()],
                             referenced_table: "City".to_string(),
+                            referenced_schema: None,
                             on_delete_action,
                         }],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
             }.boxed()
@@ -196,6 +203,7 @@ fn multi_column_foreign_keys_must_work() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
 
                 },
                 Column {
@@ -207,6 +215,7 @@ fn multi_column_foreign_keys_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
 
@@ -231,8 +240,12 @@ fn multi_column_foreign_keys_must_work() {
                             columns: vec!["city".to_string(), "city_name".to_string()],
                             referenced_columns: vec!["id".to_string(), "name".to_string()],
                             referenced_table: "City".to_string(),
+                            referenced_schema: None,
                             on_delete_action,
                         },],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
 
@@ -266,6 +279,7 @@ fn names_with_hyphens_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 }];
                 assert_eq!(user_table.columns, expected_columns);
             }
@@ -320,6 +334,7 @@ fn composite_primary_keys_must_work() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
 
                 },
                 Column {
@@ -331,6 +346,7 @@ fn composite_primary_keys_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -347,6 +363,9 @@ fn composite_primary_keys_must_work() {
                             sequence: None,
                         }),
                         foreign_keys: vec![],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
             }
@@ -386,6 +405,7 @@ fn indices_must_work() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
 
                 },
                 Column {
@@ -398,6 +418,7 @@ fn indices_must_work() {
 
                     default,
                     auto_increment: true,
+                    comment: None,
                 },
             ];
             let pk_sequence = match db_type {
@@ -405,6 +426,7 @@ fn indices_must_work() {
                     name: "User_id_seq".to_string(),
                     allocation_size: 1,
                     initial_value: 1,
+                    namespace: None,
                 }),
                 _ => None,
             };
@@ -417,12 +439,19 @@ fn indices_must_work() {
                             name: "count".to_string(),
                             columns: vec!["count".to_string()],
                             tpe: IndexType::Normal,
+                            sort_orders: vec![],
+                            expression: None,
+                            predicate: None,
+                            algorithm: None,
                         },],
                         primary_key: Some(PrimaryKey {
                             columns: vec!["id".to_string()],
                             sequence: pk_sequence,
                         }),
                         foreign_keys: vec![],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
             }
@@ -463,6 +492,7 @@ fn column_uniqueness_must_be_detected() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
 
                 },
                 Column {
@@ -475,18 +505,27 @@ fn column_uniqueness_must_be_detected() {
 
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             let mut expected_indices = vec![Index {
                 name: "uniq".to_string(),
                 columns: vec!["uniq2".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             }];
                 match db_type {
                     DbType::MySql => expected_indices.push(Index {
                         name: "uniq1".to_string(),
                         columns: vec!["uniq1".to_string()],
                         tpe: IndexType::Unique,
+                        sort_orders: vec![],
+                        expression: None,
+                        predicate: None,
+                        algorithm: None,
                     }),
                     DbType::Postgres => expected_indices.insert(
                         0,
@@ -494,12 +533,20 @@ fn column_uniqueness_must_be_detected() {
                             name: "User_uniq1_key".to_string(),
                             columns: vec!["uniq1".to_string()],
                             tpe: IndexType::Unique,
+                            sort_orders: vec![],
+                            expression: None,
+                            predicate: None,
+                            algorithm: None,
                         },
                     ),
                     DbType::Sqlite => expected_indices.push(Index {
                         name: "sqlite_autoindex_User_1".to_string(),
                         columns: vec!["uniq1".to_string()],
                         tpe: IndexType::Unique,
+                        sort_orders: vec![],
+                        expression: None,
+                        predicate: None,
+                        algorithm: None,
                     }),
                 };
                 assert_eq!(
@@ -510,6 +557,9 @@ fn column_uniqueness_must_be_detected() {
                         indices: expected_indices,
                         primary_key: None,
                         foreign_keys: vec![],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
                 assert!(
@@ -552,6 +602,7 @@ fn defaults_must_work() {
 
                     default: Some(default),
                     auto_increment: false,
+                    comment: None,
                 }];
                 assert_eq!(
                     user_table,
@@ -561,6 +612,9 @@ fn defaults_must_work() {
                         indices: vec![],
                         primary_key: None,
                         foreign_keys: vec![],
+                        checks: vec![],
+                        namespace: None,
+                        comment: None,
                     }
                 );
             }.boxed()
@@ -70,6 +70,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -81,6 +82,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -92,6 +94,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -102,6 +105,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -113,6 +117,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -124,6 +129,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -135,6 +141,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -146,6 +153,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -157,6 +165,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -168,6 +177,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -179,6 +189,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -190,6 +201,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -201,6 +213,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -212,6 +225,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -223,6 +237,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some("CURRENT_TIMESTAMP".to_string()),
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -234,6 +249,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -245,6 +261,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -256,6 +273,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -267,6 +285,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -278,6 +297,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -289,6 +309,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -300,6 +321,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -311,6 +333,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -322,6 +345,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -333,6 +357,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -344,6 +369,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -355,6 +381,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -366,6 +393,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -377,6 +405,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -388,6 +417,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -399,6 +429,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -410,6 +441,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -421,6 +453,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -432,6 +465,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -443,6 +477,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -454,6 +489,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -465,6 +501,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -476,6 +513,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -487,6 +525,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -502,6 +541,9 @@ async fn all_mysql_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -543,6 +585,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -553,6 +596,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -563,6 +607,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -573,6 +618,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -584,6 +630,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -597,6 +644,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -604,6 +652,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -611,6 +660,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -618,9 +668,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -647,7 +701,11 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            sort_orders: vec![],
+            expression: None,
+            predicate: None,
+            algorithm: None,
         }]
     );
 }
@@ -685,7 +743,11 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
         &[Index {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            sort_orders: vec![],
+            expression: None,
+            predicate: None,
+            algorithm: None,
         }]
     );
 }
@@ -723,6 +785,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -754,8 +817,66 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
     );
 }
+
+#[tokio::test]
+async fn mysql_comments_must_work() {
+    let db_name = "mysql_comments_must_work";
+
+    let sql = format!(
+        "CREATE TABLE `{}`.`User` (
+            id INTEGER PRIMARY KEY,
+            name TEXT COMMENT 'The full name.'
+        ) COMMENT 'All our users.'",
+        db_name
+    );
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    assert_eq!(table.comment.as_deref(), Some("All our users."));
+    assert_eq!(table.column_bang("name").comment.as_deref(), Some("The full name."));
+    assert_eq!(table.column_bang("id").comment, None);
+}
+
+#[tokio::test]
+async fn mysql_enum_and_set_columns_must_work() {
+    let db_name = "mysql_enum_and_set_columns_must_work";
+
+    let sql = format!(
+        "CREATE TABLE `{}`.`User` (
+            id INTEGER PRIMARY KEY,
+            mood ENUM('sad', 'ok', 'happy') NOT NULL,
+            hobbies SET('chess', 'golf')
+        )",
+        db_name
+    );
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    let mood = table.column_bang("mood");
+    assert_eq!(mood.tpe.family, ColumnTypeFamily::Enum("User_mood".into()));
+    assert_eq!(mood.tpe.arity, ColumnArity::Required);
+
+    let hobbies = table.column_bang("hobbies");
+    assert_eq!(hobbies.tpe.family, ColumnTypeFamily::Enum("User_hobbies".into()));
+    assert_eq!(hobbies.tpe.arity, ColumnArity::List);
+
+    let values: Vec<String> = vec!["sad".into(), "ok".into(), "happy".into()];
+    assert_eq!(
+        schema.get_enum("User_mood"),
+        Some(&Enum {
+            name: "User_mood".into(),
+            values,
+            namespace: None,
+        })
+    );
+}
@@ -1,7 +1,6 @@
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
-use std::collections::HashSet;
 
 mod common;
 mod postgres;
@@ -77,6 +76,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -87,6 +87,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -97,6 +98,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -107,6 +109,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -117,6 +120,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -127,6 +131,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -137,6 +142,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -147,6 +153,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".into(),
@@ -157,6 +164,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -167,6 +175,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -178,6 +187,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".into(),
@@ -189,6 +199,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".into(),
@@ -200,6 +211,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "int_col".into(),
@@ -211,6 +223,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".into(),
@@ -222,6 +235,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: Some(format!("nextval(\"{}\".\"User_primary_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "string1_col".into(),
@@ -233,6 +247,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "string2_col".into(),
@@ -244,6 +259,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -255,6 +271,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -266,6 +283,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: Some(format!("nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "bit_col".into(),
@@ -277,6 +295,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -288,6 +307,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "box_col".into(),
@@ -299,6 +319,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".into(),
@@ -310,6 +331,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "circle_col".into(),
@@ -321,6 +343,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "interval_col".into(),
@@ -332,6 +355,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "line_col".into(),
@@ -343,6 +367,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -354,6 +379,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -365,6 +391,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "path_col".into(),
@@ -376,6 +403,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -387,6 +415,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -398,6 +427,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -409,6 +439,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -423,6 +454,7 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             )),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "serial_col".into(),
@@ -434,6 +466,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: Some(format!("nextval(\"{}\".\"User_serial_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "time_col".into(),
@@ -445,6 +478,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_with_zone_col".into(),
@@ -456,6 +490,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -467,6 +502,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_with_zone_col".into(),
@@ -478,6 +514,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -489,6 +526,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -500,6 +538,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "txid_col".into(),
@@ -511,6 +550,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".into(),
@@ -522,6 +562,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -533,6 +574,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -544,6 +586,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -557,6 +600,10 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -564,9 +611,13 @@ async fn all_postgres_column_types_must_work() {
                     name: "User_primary_col_seq".into(),
                     initial_value: 1,
                     allocation_size: 1,
+                    namespace: None,
                 },),
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -607,6 +658,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -617,6 +669,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -627,6 +680,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -637,6 +691,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -647,6 +702,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".into(),
@@ -658,6 +714,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -671,6 +728,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -678,6 +736,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -685,6 +744,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -692,6 +752,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -699,9 +760,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -718,12 +783,13 @@ async fn postgres_enums_must_work() {
     let schema = inspector.describe(SCHEMA).await.expect("describing");
     let got_enum = schema.get_enum("mood").expect("get enum");
 
-    let values: HashSet<String> = ["happy".into(), "ok".into(), "sad".into()].iter().cloned().collect();
+    let values: Vec<String> = vec!["sad".into(), "ok".into(), "happy".into()];
     assert_eq!(
         got_enum,
         &Enum {
             name: "mood".into(),
             values,
+            namespace: None,
         }
     );
 }
@@ -742,6 +808,110 @@ async fn postgres_sequences_must_work() {
             name: "test".into(),
             initial_value: 1,
             allocation_size: 1,
+            namespace: None,
         },
     );
 }
+
+#[tokio::test]
+async fn postgres_comments_must_work() {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"User\" (id SERIAL PRIMARY KEY, name TEXT);
+        COMMENT ON TABLE \"{0}\".\"User\" IS 'All our users.';
+        COMMENT ON COLUMN \"{0}\".\"User\".\"name\" IS 'The full name.'",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_comments_must_work").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    assert_eq!(table.comment.as_deref(), Some("All our users."));
+    assert_eq!(table.column_bang("name").comment.as_deref(), Some("The full name."));
+    assert_eq!(table.column_bang("id").comment, None);
+}
+
+#[tokio::test]
+async fn postgres_enum_columns_must_work() {
+    let sql = format!(
+        "CREATE TYPE \"{0}\".\"mood\" AS ENUM ('sad', 'ok', 'happy');
+        CREATE TABLE \"{0}\".\"User\" (
+            id INTEGER PRIMARY KEY,
+            mood \"{0}\".\"mood\" NOT NULL DEFAULT 'ok',
+            moods \"{0}\".\"mood\"[]
+        );",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_enum_columns_must_work").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    let mood = table.column_bang("mood");
+    assert_eq!(mood.tpe.family, ColumnTypeFamily::Enum("mood".into()));
+    assert_eq!(mood.tpe.arity, ColumnArity::Required);
+    assert_eq!(mood.default.as_deref(), Some("ok"));
+
+    let moods = table.column_bang("moods");
+    assert_eq!(moods.tpe.family, ColumnTypeFamily::Enum("mood".into()));
+    assert_eq!(moods.tpe.arity, ColumnArity::List);
+}
+
+#[tokio::test]
+async fn postgres_partial_expression_and_sorted_indexes_must_work() {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"User\" (id INTEGER PRIMARY KEY, email TEXT, name TEXT, deleted_at TIMESTAMP);
+        CREATE UNIQUE INDEX \"active_email\" ON \"{0}\".\"User\" (email) WHERE deleted_at IS NULL;
+        CREATE INDEX \"lower_email\" ON \"{0}\".\"User\" (lower(email) DESC);
+        CREATE INDEX \"name_and_email\" ON \"{0}\".\"User\" (name DESC, email);
+        CREATE INDEX \"name_hash\" ON \"{0}\".\"User\" USING HASH (name);",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_partial_expression_and_sorted_indexes_must_work").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    assert_eq!(
+        table.indices,
+        vec![
+            Index {
+                name: "active_email".into(),
+                columns: vec!["email".into()],
+                tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expression: None,
+                predicate: Some("(deleted_at IS NULL)".into()),
+                algorithm: None,
+            },
+            Index {
+                name: "lower_email".into(),
+                columns: vec![],
+                tpe: IndexType::Normal,
+                sort_orders: vec![],
+                expression: Some("lower(email) DESC".into()),
+                predicate: None,
+                algorithm: None,
+            },
+            Index {
+                name: "name_and_email".into(),
+                columns: vec!["name".into(), "email".into()],
+                tpe: IndexType::Normal,
+                sort_orders: vec![SortOrder::Desc, SortOrder::Asc],
+                expression: None,
+                predicate: None,
+                algorithm: None,
+            },
+            Index {
+                name: "name_hash".into(),
+                columns: vec!["name".into()],
+                tpe: IndexType::Normal,
+                sort_orders: vec![],
+                expression: None,
+                predicate: None,
+                algorithm: Some(IndexAlgorithm::Hash),
+            },
+        ]
+    );
+    assert!(!table.is_column_unique(&"email".to_string()));
+}
@@ -6,7 +6,6 @@ use log::{debug, LevelFilter};
 use pretty_assertions::assert_eq;
 use quaint::connector::{Queryable, Sqlite as SqliteDatabaseClient};
 use sql_schema_describer::*;
-use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -17,9 +16,7 @@ const SCHEMA: &str = "DatabaseInspectorTest";
 
 #[test]
 fn database_schema_is_serializable() {
-    let mut enum_values = HashSet::new();
-    enum_values.insert("option1".to_string());
-    enum_values.insert("option2".to_string());
+    let enum_values = vec!["option1".to_string(), "option2".to_string()];
     let schema = SqlSchema {
         tables: vec![
             Table {
@@ -34,6 +31,7 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -44,6 +42,7 @@ fn database_schema_is_serializable() {
                         },
                         default: Some("default value".to_string()),
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -54,12 +53,17 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    sort_orders: vec![],
+                    expression: None,
+                    predicate: None,
+                    algorithm: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
@@ -69,9 +73,13 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
             Table {
                 name: "table2".to_string(),
@@ -84,6 +92,7 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -91,17 +100,23 @@ fn database_schema_is_serializable() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
+                namespace: None,
+                comment: None,
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
             values: enum_values,
+            namespace: None,
         }],
         sequences: vec![Sequence {
             name: "sequence1".to_string(),
             initial_value: 1,
             allocation_size: 32,
+            namespace: None,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -129,13 +144,18 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                comment: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -177,6 +197,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        comment: None,
     })
     .collect();
     let schema = SqlSchema {
@@ -186,9 +207,13 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -217,6 +242,7 @@ fn database_schema_is_serializable_for_every_column_arity() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         })
         .collect();
     let schema = SqlSchema {
@@ -226,9 +252,13 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -257,6 +287,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         arity: ColumnArity::Nullable,
                     },
                     auto_increment: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -267,6 +298,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         arity: ColumnArity::Nullable,
                     },
                     auto_increment: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -277,6 +309,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         arity: ColumnArity::Nullable,
                     },
                     auto_increment: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -287,6 +320,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         arity: ColumnArity::Nullable,
                     },
                     auto_increment: false,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -297,6 +331,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         arity: ColumnArity::Nullable,
                     },
                     auto_increment: false,
+                    comment: None,
                     default: None,
                 },
             ],
@@ -307,6 +342,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -314,6 +350,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -321,6 +358,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -328,6 +366,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
@@ -335,13 +374,18 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
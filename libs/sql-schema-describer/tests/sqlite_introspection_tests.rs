@@ -32,6 +32,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -42,6 +43,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -52,6 +54,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -62,6 +65,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -77,6 +81,9 @@ async fn sqlite_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -115,6 +122,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -125,6 +133,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -135,6 +144,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -145,6 +155,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -155,6 +166,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -165,6 +177,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -178,6 +191,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -185,6 +199,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -192,6 +207,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -199,6 +215,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -206,9 +223,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            checks: vec![],
+            namespace: None,
+            comment: None,
         }
     );
 }
@@ -242,3 +263,86 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
         }
     );
 }
+
+#[tokio::test]
+async fn sqlite_views_must_be_described_and_not_treated_as_tables() {
+    let mut migration = Migration::new().schema(SCHEMA);
+    migration.create_table("User", move |t| {
+        t.add_column("id", types::primary());
+        t.add_column("name", types::text());
+    });
+    let full_sql = format!(
+        "{};CREATE VIEW \"{}\".\"UserName\" AS SELECT name FROM \"User\"",
+        migration.make::<barrel::backend::Sqlite>(),
+        SCHEMA
+    );
+
+    let inspector = get_sqlite_describer(&full_sql, "sqlite_views_must_be_described_and_not_treated_as_tables").await;
+    let result = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert!(result.get_table("UserName").is_none());
+
+    let view = result.get_view("UserName").expect("couldn't get UserName view");
+
+    assert_eq!(view.definition.as_deref(), Some("SELECT name FROM \"User\""));
+    assert_eq!(view.columns.len(), 1);
+    assert_eq!(view.columns[0].name, "name");
+}
+
+#[tokio::test]
+async fn sqlite_check_constraints_must_work() {
+    let full_sql = format!(
+        "CREATE TABLE \"{}\".\"Product\" (
+            id INTEGER PRIMARY KEY,
+            price REAL CHECK (price >= 0),
+            CONSTRAINT price_is_sane CHECK (price < 1000000)
+        )",
+        SCHEMA
+    );
+
+    let inspector = get_sqlite_describer(&full_sql, "sqlite_check_constraints_must_work").await;
+    let result = inspector.describe(SCHEMA).await.expect("describing");
+    let table = result.get_table("Product").expect("couldn't get Product table");
+
+    assert_eq!(
+        table.checks,
+        vec![
+            CheckConstraint {
+                name: None,
+                expression: "price >= 0".to_string(),
+            },
+            CheckConstraint {
+                name: Some("price_is_sane".to_string()),
+                expression: "price < 1000000".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn sqlite_partial_expression_and_sorted_indexes_must_work() {
+    let full_sql = format!(
+        "CREATE TABLE \"{0}\".\"User\" (id INTEGER PRIMARY KEY, email TEXT, name TEXT, deleted_at DATETIME);
+        CREATE UNIQUE INDEX \"{0}\".\"active_email\" ON \"User\" (email) WHERE deleted_at IS NULL;
+        CREATE INDEX \"{0}\".\"lower_email\" ON \"User\" (lower(email) DESC);
+        CREATE INDEX \"{0}\".\"name_and_email\" ON \"User\" (name DESC, email);",
+        SCHEMA
+    );
+
+    let inspector = get_sqlite_describer(&full_sql, "sqlite_partial_expression_and_sorted_indexes_must_work").await;
+    let result = inspector.describe(SCHEMA).await.expect("describing");
+    let table = result.get_table("User").expect("couldn't get User table");
+    let index = |name: &str| table.indices.iter().find(|index| index.name == name).unwrap();
+
+    let active_email = index("active_email");
+    assert_eq!(active_email.columns, vec!["email".to_string()]);
+    assert_eq!(active_email.predicate.as_deref(), Some("deleted_at IS NULL"));
+
+    let lower_email = index("lower_email");
+    assert!(lower_email.columns.is_empty());
+    assert_eq!(lower_email.expression.as_deref(), Some("lower(email) DESC"));
+
+    let name_and_email = index("name_and_email");
+    assert_eq!(name_and_email.columns, vec!["name".to_string(), "email".to_string()]);
+    assert_eq!(name_and_email.sort_orders, vec![SortOrder::Desc, SortOrder::Asc]);
+}
@@ -117,3 +117,13 @@ pub struct QueryValidationFailed {
     /// Location of the incorrect parsing, validation in a query. Represented by tuple or object with (line, character)
     pub query_position: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2010",
+    message = "Check constraint failed on the database: `${constraint_name}`"
+)]
+pub struct CheckConstraintViolation {
+    /// Name of the violated check constraint, or its expression if it has no name
+    pub constraint_name: String,
+}
@@ -35,9 +35,19 @@ impl DestructiveChangeDiagnostics {
         }
     }
 
+    pub fn add_error<T: Into<Option<MigrationError>>>(&mut self, error: T) {
+        if let Some(error) = error.into() {
+            self.errors.push(error)
+        }
+    }
+
     pub fn has_warnings(&self) -> bool {
         !self.warnings.is_empty()
     }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
 }
 
 /// A warning emitted by [DestructiveChangesChecker](trait.DestructiveChangesChecker.html). Warnings will
@@ -32,6 +32,14 @@ pub enum MigrationStep {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateModel {
     pub model: String,
+
+    /// Whether the model is declared as a composite type (`type` keyword).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_composite_type: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
@@ -7,6 +7,7 @@ fn full_CreateModel_must_work() {
     let json = r#"{"tag":"CreateModel","model":"Blog"}"#;
     let expected_struct = MigrationStep::CreateModel(CreateModel {
         model: "Blog".to_string(),
+        is_composite_type: false,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -12,6 +12,8 @@ mod sql_schema_calculator;
 mod sql_schema_differ;
 
 pub use error::*;
+pub use sql_database_migration_inferrer::{calculate_sql_schema, infer_sql_migration};
+pub use sql_database_step_applier::render_migration_sql;
 pub use sql_migration::*;
 
 use migration_connector::*;
@@ -83,6 +85,13 @@ impl SqlMigrationConnector {
             SqlFamily::Sqlite => Arc::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(Arc::clone(&conn))),
         };
 
+        let supports_check_constraints = match sql_family {
+            SqlFamily::Mysql => mysql_supports_check_constraints(conn.as_ref())
+                .await
+                .map_err(|err| err.into_connector_error(&connection_info))?,
+            SqlFamily::Postgres | SqlFamily::Sqlite => true,
+        };
+
         let migration_persistence = Arc::new(SqlMigrationPersistence {
             connection_info: connection_info.clone(),
             connection: Arc::clone(&conn),
@@ -93,6 +102,7 @@ impl SqlMigrationConnector {
             connection_info: connection_info.clone(),
             describer: Arc::clone(&describer),
             schema_name: schema_name.to_string(),
+            supports_check_constraints,
         });
 
         let database_migration_step_applier = Arc::new(SqlDatabaseStepApplier {
@@ -11,20 +11,29 @@ pub struct SqlDatabaseMigrationInferrer {
     pub connection_info: ConnectionInfo,
     pub describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
     pub schema_name: String,
+    /// MySQL before 8.0.16 parses check constraints and throws them away.
+    pub supports_check_constraints: bool,
 }
 
 #[async_trait::async_trait]
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
     async fn infer(
         &self,
-        _previous: &Datamodel,
+        previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let fut = async {
-            let current_database_schema: SqlSchema = self.describe(&self.schema_name).await?;
-            let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
-            infer(
+            let namespaces = namespaces_of_datamodels(&[previous, next]);
+            let mut current_database_schema: SqlSchema = self.describe(&self.schema_name, &namespaces).await?;
+            let applied_database_schema = self.calculate_sql_schema(previous)?;
+            let expected_database_schema = self.calculate_sql_schema(next)?;
+            use_applied_view_definitions(
+                &mut current_database_schema,
+                &applied_database_schema,
+                &expected_database_schema,
+            );
+            infer_sql_migration(
                 &current_database_schema,
                 &expected_database_schema,
                 &self.schema_name,
@@ -42,9 +51,9 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let result: SqlResult<SqlMigration> = (|| {
-            let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
-            let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
-            infer(
+            let current_database_schema: SqlSchema = self.calculate_sql_schema(previous)?;
+            let expected_database_schema = self.calculate_sql_schema(next)?;
+            infer_sql_migration(
                 &current_database_schema,
                 &expected_database_schema,
                 &self.schema_name,
@@ -57,16 +66,107 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
 }
 
 impl SqlDatabaseMigrationInferrer {
-    async fn describe(&self, schema: &str) -> SqlResult<SqlSchema> {
-        Ok(self.describer.describe(&schema).await?)
+    async fn describe(&self, schema: &str, namespaces: &[String]) -> SqlResult<SqlSchema> {
+        Ok(self.describer.describe_with_namespaces(schema, namespaces).await?)
     }
 
     fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
+
+    /// The database schema the datamodel results in on this database. Check constraints are left
+    /// out when the database would not keep them, so they are not added again on every migration.
+    fn calculate_sql_schema(&self, datamodel: &Datamodel) -> SqlResult<SqlSchema> {
+        let mut sql_schema = calculate_sql_schema(datamodel, &self.schema_name, self.sql_family())?;
+
+        if !self.supports_check_constraints {
+            for table in sql_schema.tables.iter_mut() {
+                table.checks.clear();
+            }
+        }
+
+        Ok(sql_schema)
+    }
 }
 
-fn infer(
+/// Check constraints are only kept from MySQL 8.0.16 on, which introduced the `CHECK_CONSTRAINTS`
+/// table. MariaDB has it as well.
+pub(crate) async fn mysql_supports_check_constraints(conn: &(dyn Queryable + Send + Sync)) -> SqlResult<bool> {
+    let sql = "SELECT table_name table_name FROM information_schema.tables
+        WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'";
+    let rows = conn.query_raw(sql, &[]).await?;
+
+    Ok(rows.into_iter().next().is_some())
+}
+
+/// The non-default database schemas the models of the given datamodels live in.
+fn namespaces_of_datamodels(datamodels: &[&Datamodel]) -> Vec<String> {
+    let mut namespaces: Vec<String> = Vec::new();
+
+    for schema in datamodels
+        .iter()
+        .flat_map(|datamodel| datamodel.models())
+        .filter_map(|model| model.schema.as_ref())
+    {
+        if !namespaces.contains(schema) {
+            namespaces.push(schema.clone());
+        }
+    }
+
+    namespaces
+}
+
+/// Databases store view definitions in a rewritten form, which can not be compared with the definitions in
+/// the datamodel. The described views therefore get the definitions they were applied with, so a view is
+/// only recreated when its definition changes in the datamodel. Views that were created outside of
+/// migrations are taken as they are.
+fn use_applied_view_definitions(current: &mut SqlSchema, applied: &SqlSchema, expected: &SqlSchema) {
+    for view in current.views.iter_mut() {
+        let namespace = view.namespace.clone();
+        let applied_view = applied
+            .get_view_in_namespace(namespace.as_deref(), &view.name)
+            .or_else(|| expected.get_view_in_namespace(namespace.as_deref(), &view.name));
+
+        if let Some(applied_view) = applied_view {
+            view.definition = applied_view.definition.clone();
+        }
+    }
+}
+
+/// Models placed explicitly in the default schema must compare equal to the described tables, which
+/// represent the default schema as `None`.
+fn normalize_namespaces(mut sql_schema: SqlSchema, schema_name: &str) -> SqlSchema {
+    for table in sql_schema.tables.iter_mut() {
+        if table.namespace.as_deref() == Some(schema_name) {
+            table.namespace = None;
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_schema.as_deref() == Some(schema_name) {
+                foreign_key.referenced_schema = None;
+            }
+        }
+    }
+
+    for view in sql_schema.views.iter_mut() {
+        if view.namespace.as_deref() == Some(schema_name) {
+            view.namespace = None;
+        }
+    }
+
+    sql_schema
+}
+
+/// Calculates the database schema a datamodel results in, without looking at a database.
+pub fn calculate_sql_schema(datamodel: &Datamodel, schema_name: &str, sql_family: SqlFamily) -> SqlResult<SqlSchema> {
+    Ok(normalize_namespaces(
+        SqlSchemaCalculator::calculate(datamodel, sql_family)?,
+        schema_name,
+    ))
+}
+
+/// Infers the migration between two database schemas. This does not need a database connection.
+pub fn infer_sql_migration(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
     schema_name: &str,
@@ -99,30 +199,60 @@ fn infer_database_migration_steps_and_fix(
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(from, to, sql_family);
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
-        fix_stupid_sqlite(diff, &from, &to, &schema_name)?
+        fix_stupid_sqlite(diff, from, to, schema_name)?
     } else {
-        let steps = delay_foreign_key_creation(diff);
-        fix_id_column_type_change(&from, &to, schema_name, steps)?
+        let mut steps = create_new_namespaces(from, to);
+        steps.append(&mut delay_foreign_key_creation(diff));
+        fix_id_column_type_change(from, to, schema_name, sql_family, steps)?
     };
 
-    Ok((SqlSchemaDiffer::diff(&from, &to).into_steps(), corrected_steps))
+    Ok((
+        SqlSchemaDiffer::diff(from, to, sql_family).into_steps(),
+        corrected_steps,
+    ))
+}
+
+/// Tables can only be created in a database schema that exists.
+fn create_new_namespaces(from: &SqlSchema, to: &SqlSchema) -> Vec<SqlMigrationStep> {
+    let mut new_namespaces: Vec<&String> = Vec::new();
+
+    for namespace in to.tables.iter().filter_map(|table| table.namespace.as_ref()) {
+        let is_new = !from
+            .tables
+            .iter()
+            .any(|table| table.namespace.as_ref() == Some(namespace));
+
+        if is_new && !new_namespaces.contains(&namespace) {
+            new_namespaces.push(namespace);
+        }
+    }
+
+    new_namespaces
+        .into_iter()
+        .map(|namespace| SqlMigrationStep::RawSql {
+            raw: format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", namespace),
+        })
+        .collect()
 }
 
 fn fix_id_column_type_change(
     from: &SqlSchema,
     to: &SqlSchema,
     _schema_name: &str,
+    sql_family: SqlFamily,
     steps: Vec<SqlMigrationStep>,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
         .iter()
         .find(|step| match step {
             SqlMigrationStep::AlterTable(alter_table) => {
-                if let Ok(current_table) = from.table(&alter_table.table.name) {
+                if let Some(current_table) =
+                    from.get_table_in_namespace(alter_table.table.namespace.as_deref(), &alter_table.table.name)
+                {
                     let change_to_id_column = alter_table.changes.iter().find(|c| match c {
                         TableChange::AlterColumn(alter_column) => {
                             let current_column = current_table.column_bang(&alter_column.name);
@@ -150,14 +280,12 @@ fn fix_id_column_type_change(
     // TODO: There's probably a much more graceful way to handle this. But this would also involve a lot of data loss probably. Let's tackle that after P Day
     if has_id_type_change {
         let mut radical_steps = Vec::new();
-        let tables_to_drop: Vec<String> = from
-            .tables
-            .iter()
-            .filter(|t| t.name != "_Migration")
-            .map(|t| t.name.clone())
-            .collect();
-        radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to);
+        let tables_to_drop: Vec<&Table> = from.tables.iter().filter(|t| t.name != "_Migration").collect();
+        radical_steps.push(SqlMigrationStep::DropTables(DropTables {
+            names: tables_to_drop.iter().map(|t| t.name.clone()).collect(),
+            namespaces: tables_to_drop.iter().map(|t| t.namespace.clone()).collect(),
+        }));
+        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), to, sql_family);
         let mut steps_from_empty = delay_foreign_key_creation(diff_from_empty);
         radical_steps.append(&mut steps_from_empty);
 
@@ -246,6 +374,9 @@ fn fix_stupid_sqlite(
                 )?);
                 fixed_tables.push(table.clone());
             }
+            SqlMigrationStep::SetComment(_) => {
+                // SQLite does not store comments.
+            }
             x => result.push(x),
         }
     }
@@ -263,6 +394,9 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         TableChange::DropColumn(_) => true,
         TableChange::AlterColumn(_) => true,
         TableChange::DropForeignKey(_) => true,
+        // SQLite can't alter the constraints of an existing table.
+        TableChange::AddCheck(_) => true,
+        TableChange::DropCheck(_) => true,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
@@ -319,6 +453,7 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
 
     result.push(SqlMigrationStep::DropTable(DropTable {
         name: current.name.clone(),
+        namespace: None,
     }));
     result.push(SqlMigrationStep::RenameTable {
         name: name_of_temporary_table,
@@ -331,6 +466,7 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
             .map(|index| {
                 SqlMigrationStep::CreateIndex(CreateIndex {
                     table: next.name.clone(),
+                    namespace: None,
                     index: index.clone(),
                 })
             })
@@ -14,14 +14,14 @@ pub struct SqlDatabaseStepApplier {
 #[async_trait::async_trait]
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
     async fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
-        self.apply_next_step(&database_migration.corrected_steps, index)
+        self.apply_next_step(database_migration, &database_migration.corrected_steps, index)
             .instrument(tracing::debug_span!("ApplySqlStep", index))
             .await
             .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info))
     }
 
     async fn unapply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
-        self.apply_next_step(&database_migration.rollback, index)
+        self.apply_next_step(database_migration, &database_migration.rollback, index)
             .instrument(tracing::debug_span!("UnapplySqlStep", index))
             .await
             .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info))
@@ -38,7 +38,12 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
 }
 
 impl SqlDatabaseStepApplier {
-    async fn apply_next_step(&self, steps: &[SqlMigrationStep], index: usize) -> SqlResult<bool> {
+    async fn apply_next_step(
+        &self,
+        database_migration: &SqlMigration,
+        steps: &[SqlMigrationStep],
+        index: usize,
+    ) -> SqlResult<bool> {
         let has_this_one = steps.get(index).is_some();
         if !has_this_one {
             return Ok(false);
@@ -47,7 +52,7 @@ impl SqlDatabaseStepApplier {
         let step = &steps[index];
         tracing::debug!(?step);
 
-        let sql_string = render_raw_sql(&step, self.sql_family(), &self.schema_name);
+        let sql_string = render_raw_sql(step, database_migration, self.sql_family(), &self.schema_name);
         tracing::debug!(index, %sql_string);
 
         let result = self.conn.query_raw(&sql_string, &[]).await;
@@ -74,15 +79,44 @@ fn render_steps_pretty(
         .iter()
         .map(|step| PrettySqlMigrationStep {
             step: step.clone(),
-            raw: render_raw_sql(&step, sql_family, schema_name),
+            raw: render_raw_sql(step, database_migration, sql_family, schema_name),
         })
         .collect();
     Ok(steps)
 }
 
-fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> String {
+/// Renders the corrected steps of a migration to SQL statements, without a database connection.
+pub fn render_migration_sql(
+    database_migration: &SqlMigration,
+    sql_family: SqlFamily,
+    schema_name: &str,
+) -> Vec<String> {
+    database_migration
+        .corrected_steps
+        .iter()
+        .map(|step| render_raw_sql(step, database_migration, sql_family, schema_name))
+        .collect()
+}
+
+fn render_raw_sql(
+    step: &SqlMigrationStep,
+    database_migration: &SqlMigration,
+    sql_family: SqlFamily,
+    schema_name: &str,
+) -> String {
     let schema_name = schema_name.to_string();
     let renderer = SqlRenderer::for_family(&sql_family);
+    // Tables and views outside of the default schema are qualified with their own schema.
+    let table_schema =
+        |namespace: &Option<String>| -> String { namespace.clone().unwrap_or_else(|| schema_name.clone()) };
+    // Rollback steps refer to the enums of the schema before the migration.
+    let enums: Vec<Enum> = database_migration
+        .after
+        .enums
+        .iter()
+        .chain(database_migration.before.enums.iter())
+        .cloned()
+        .collect();
 
     match step {
         SqlMigrationStep::CreateTable(CreateTable { table }) => {
@@ -90,7 +124,7 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             let primary_columns = table.primary_key_columns();
             let mut lines = Vec::new();
             for column in cloned_columns.clone() {
-                let col_sql = renderer.render_column(&schema_name, &table, &column, false);
+                let col_sql = renderer.render_column(&schema_name, table, &column, false, &enums);
                 lines.push(format!("  {}", col_sql));
             }
             let primary_key_was_already_set_in_column_line = lines.join(",").contains(&"PRIMARY KEY");
@@ -103,20 +137,30 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                     .collect();
                 lines.push(format!("  PRIMARY KEY ({})", column_names.join(",")))
             }
+            for check in table.checks.iter() {
+                lines.push(format!("  {}", render_check(renderer, check)));
+            }
             format!(
                 "CREATE TABLE {} (\n{}\n){};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(&table_schema(&table.namespace), &table.name),
                 lines.join(",\n"),
                 create_table_suffix(sql_family),
             )
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => {
-            format!("DROP TABLE {};", renderer.quote_with_schema(&schema_name, &name))
+        SqlMigrationStep::DropTable(DropTable { name, namespace }) => {
+            format!(
+                "DROP TABLE {};",
+                renderer.quote_with_schema(&table_schema(namespace), name)
+            )
         }
-        SqlMigrationStep::DropTables(DropTables { names }) => {
+        SqlMigrationStep::DropTables(DropTables { names, namespaces }) => {
             let fully_qualified_names: Vec<String> = names
                 .iter()
-                .map(|name| renderer.quote_with_schema(&schema_name, &name))
+                .enumerate()
+                .map(|(position, name)| {
+                    let namespace = namespaces.get(position).cloned().flatten();
+                    renderer.quote_with_schema(&table_schema(&namespace), name)
+                })
                 .collect();
             format!("DROP TABLE {};", fully_qualified_names.join(","))
         }
@@ -136,7 +180,7 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             for change in changes.clone() {
                 match change {
                     TableChange::AddColumn(AddColumn { column }) => {
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
+                        let col_sql = renderer.render_column(&schema_name, table, &column, true, &enums);
                         lines.push(format!("ADD COLUMN {}", col_sql));
                     }
                     TableChange::DropColumn(DropColumn { name }) => {
@@ -147,7 +191,7 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
+                        let col_sql = renderer.render_column(&schema_name, table, &column, true, &enums);
                         lines.push(format!("ADD COLUMN {}", col_sql));
                     }
                     TableChange::DropForeignKey(DropForeignKey { constraint_name }) => match sql_family {
@@ -157,16 +201,39 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                         }
                         _ => (),
                     },
+                    TableChange::AddCheck(AddCheck { check }) => {
+                        lines.push(format!("ADD {}", render_check(renderer, &check)));
+                    }
+                    // Unnamed checks only exist on SQLite, where the table gets rebuilt instead.
+                    TableChange::DropCheck(DropCheck { check }) => match (sql_family, &check.name) {
+                        (SqlFamily::Mysql, Some(name)) => lines.push(format!("DROP CHECK {}", renderer.quote(name))),
+                        (SqlFamily::Postgres, Some(name)) => {
+                            lines.push(format!("DROP CONSTRAINT {}", renderer.quote(name)))
+                        }
+                        _ => (),
+                    },
                 }
             }
             format!(
                 "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(&table_schema(&table.namespace), &table.name),
                 lines.join(",\n")
             )
         }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
-            let Index { name, columns, tpe } = index;
+        SqlMigrationStep::CreateIndex(CreateIndex {
+            table,
+            namespace,
+            index,
+        }) => {
+            let Index {
+                name,
+                columns,
+                tpe,
+                expression,
+                predicate,
+                algorithm,
+                ..
+            } = index;
             let index_type = match tpe {
                 IndexType::Unique => "UNIQUE",
                 IndexType::Normal => "",
@@ -177,29 +244,51 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             };
             let table_reference = match sql_family {
                 SqlFamily::Sqlite => renderer.quote(&table),
-                _ => renderer.quote_with_schema(&schema_name, &table),
+                _ => renderer.quote_with_schema(&table_schema(namespace), table),
+            };
+            let key_list = match expression {
+                Some(expression) => expression.clone(),
+                None => columns
+                    .iter()
+                    .enumerate()
+                    .map(|(position, c)| match index.is_descending(position) {
+                        true => format!("{} DESC", renderer.quote(c)),
+                        false => renderer.quote(c),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(","),
+            };
+            // Postgres names the method before the key list, MySQL after it. SQLite has only one method.
+            let (method_before, method_after) = match (sql_family, algorithm) {
+                (SqlFamily::Postgres, Some(algorithm)) => (format!(" USING {}", algorithm.db_name()), String::new()),
+                (SqlFamily::Mysql, Some(algorithm)) => (String::new(), format!(" USING {}", algorithm.db_name())),
+                _ => (String::new(), String::new()),
+            };
+            let where_clause = match predicate {
+                Some(predicate) => format!(" WHERE {}", predicate),
+                None => String::new(),
             };
-            let columns: Vec<String> = columns.iter().map(|c| renderer.quote(c)).collect();
             format!(
-                "CREATE {} INDEX {} ON {}({})",
-                index_type,
-                index_name,
-                table_reference,
-                columns.join(",")
+                "CREATE {} INDEX {} ON {}{}({}){}{}",
+                index_type, index_name, table_reference, method_before, key_list, method_after, where_clause
             )
         }
-        SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
+        SqlMigrationStep::DropIndex(DropIndex { table, namespace, name }) => match sql_family {
             SqlFamily::Mysql => format!(
                 "DROP INDEX {} ON {}",
                 renderer.quote(&name),
                 renderer.quote_with_schema(&schema_name, &table),
             ),
-            SqlFamily::Postgres | SqlFamily::Sqlite => {
-                format!("DROP INDEX {}", renderer.quote_with_schema(&schema_name, &name),)
-            }
+            // Postgres indexes live in the schema of their table.
+            SqlFamily::Postgres => format!(
+                "DROP INDEX {}",
+                renderer.quote_with_schema(&table_schema(namespace), name)
+            ),
+            SqlFamily::Sqlite => format!("DROP INDEX {}", renderer.quote_with_schema(&schema_name, name)),
         },
         SqlMigrationStep::AlterIndex(AlterIndex {
             table,
+            namespace,
             index_name,
             index_new_name,
         }) => match sql_family {
@@ -211,15 +300,58 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             ),
             SqlFamily::Postgres => format!(
                 "ALTER INDEX {} RENAME TO {}",
-                renderer.quote_with_schema(&schema_name, index_name),
+                renderer.quote_with_schema(&table_schema(namespace), index_name),
                 renderer.quote(index_new_name)
             ),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
+        SqlMigrationStep::CreateView(CreateView { view }) => format!(
+            "CREATE VIEW {} AS {};",
+            renderer.quote_with_schema(&table_schema(&view.namespace), &view.name),
+            view.definition.as_deref().unwrap_or("").trim_end_matches(';')
+        ),
+        SqlMigrationStep::DropView(DropView { name, namespace }) => {
+            format!(
+                "DROP VIEW {};",
+                renderer.quote_with_schema(&table_schema(namespace), name)
+            )
+        }
+        SqlMigrationStep::SetComment(SetComment {
+            table,
+            namespace,
+            column,
+            comment,
+        }) => renderer.render_set_comment(
+            &renderer.quote_with_schema(&table_schema(namespace), table),
+            column.as_ref(),
+            comment.as_deref(),
+            &enums,
+        ),
+        SqlMigrationStep::CreateEnum(CreateEnum { name, values }) => {
+            let values: Vec<String> = values
+                .iter()
+                .map(|value| sql_renderer::render_string_literal(value))
+                .collect();
+            format!(
+                "CREATE TYPE {} AS ENUM ({});",
+                renderer.quote_with_schema(&schema_name, &name),
+                values.join(", ")
+            )
+        }
+        SqlMigrationStep::DropEnum(DropEnum { name }) => {
+            format!("DROP TYPE {};", renderer.quote_with_schema(&schema_name, name))
+        }
         SqlMigrationStep::RawSql { raw } => raw.to_string(),
     }
 }
 
+fn render_check(renderer: &dyn SqlRenderer, check: &CheckConstraint) -> String {
+    match &check.name {
+        Some(name) => format!("CONSTRAINT {} CHECK ({})", renderer.quote(name), check.expression),
+        None => format!("CHECK ({})", check.expression),
+    }
+}
+
 fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
     match sql_family {
         SqlFamily::Sqlite => "",
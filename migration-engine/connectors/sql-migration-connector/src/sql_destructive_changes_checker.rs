@@ -1,10 +1,10 @@
 use crate::{
-    AlterColumn, ConnectionInfo, DropColumn, DropTable, DropTables, SqlError, SqlMigration, SqlMigrationStep,
-    SqlResult, TableChange,
+    sql_renderer::SqlRenderer, AddCheck, AddColumn, AlterColumn, ConnectionInfo, CreateIndex, DropColumn, DropTable,
+    DropTables, SqlError, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::*;
 use quaint::{ast::*, prelude::Queryable};
-use sql_schema_describer::SqlSchemaDescriberBackend;
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, IndexType, SqlSchemaDescriberBackend};
 use std::sync::Arc;
 
 pub struct SqlDestructiveChangesChecker {
@@ -15,20 +15,33 @@ pub struct SqlDestructiveChangesChecker {
 }
 
 impl SqlDestructiveChangesChecker {
-    async fn check_table_drop(
-        &self,
-        table_name: &str,
-        diagnostics: &mut DestructiveChangeDiagnostics,
-    ) -> SqlResult<()> {
-        let query = Select::from_table((self.schema_name.as_str(), table_name)).value(count(asterisk()));
+    /// The schema the table lives in, which is the migrated schema unless the table has its own.
+    fn table_schema<'a>(&'a self, table: &'a sql_schema_describer::Table) -> &'a str {
+        table.namespace.as_deref().unwrap_or(&self.schema_name)
+    }
+
+    async fn count_rows_in_table(&self, namespace: Option<&str>, table_name: &str) -> SqlResult<i64> {
+        let schema_name = namespace.unwrap_or(&self.schema_name);
+        let query = Select::from_table((schema_name, table_name)).value(count(asterisk()));
         let result_set = self.database.query(query.into()).await?;
         let first_row = result_set.first().ok_or_else(|| {
-            SqlError::Generic("No row was returned when checking for existing rows in dropped table.".to_owned())
+            SqlError::Generic("No row was returned when checking for existing rows in table.".to_owned())
         })?;
         let rows_count: i64 = first_row.at(0).and_then(|value| value.as_i64()).ok_or_else(|| {
-            SqlError::Generic("No count was returned when checking for existing rows in dropped table.".to_owned())
+            SqlError::Generic("No count was returned when checking for existing rows in table.".to_owned())
         })?;
 
+        Ok(rows_count)
+    }
+
+    async fn check_table_drop(
+        &self,
+        namespace: Option<&str>,
+        table_name: &str,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let rows_count = self.count_rows_in_table(namespace, table_name).await?;
+
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
@@ -43,7 +56,7 @@ impl SqlDestructiveChangesChecker {
     }
 
     async fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name.as_str(), table.name.as_str()))
+        let query = Select::from_table((self.table_schema(table), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
             .so_that(column_name.is_not_null());
 
@@ -66,6 +79,99 @@ impl SqlDestructiveChangesChecker {
         Ok(values_count)
     }
 
+    async fn count_nulls_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
+        let query = Select::from_table((self.table_schema(table), table.name.as_str()))
+            .value(count(asterisk()))
+            .so_that(column_name.is_null());
+
+        let nulls_count: i64 = self
+            .database
+            .query(query.into())
+            .await
+            .map_err(SqlError::from)
+            .and_then(|result_set| {
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|count| count.as_i64())
+                    .ok_or_else(|| {
+                        SqlError::Generic("Unexpected result set shape when checking for null values.".to_owned())
+                    })
+            })?;
+
+        Ok(nulls_count)
+    }
+
+    /// Count the groups of rows that share the same non-null values in the given columns, i.e.
+    /// the number of values that would violate a unique constraint on these columns.
+    async fn count_duplicate_values(&self, columns: &[String], table: &sql_schema_describer::Table) -> SqlResult<i64> {
+        let renderer = SqlRenderer::for_family(&self.connection_info.sql_family());
+        let quoted_columns: Vec<String> = columns.iter().map(|column| renderer.quote(column)).collect();
+        let not_null_conditions: Vec<String> = quoted_columns
+            .iter()
+            .map(|column| format!("{} IS NOT NULL", column))
+            .collect();
+
+        let query = format!(
+            "SELECT COUNT(*) FROM (SELECT {columns} FROM {table} WHERE {conditions} GROUP BY {columns} HAVING COUNT(*) > 1) AS duplicates",
+            columns = quoted_columns.join(", "),
+            table = renderer.quote_with_schema(self.table_schema(table), &table.name),
+            conditions = not_null_conditions.join(" AND "),
+        );
+
+        let duplicates_count: i64 = self
+            .database
+            .query_raw(&query, &[])
+            .await
+            .map_err(SqlError::from)
+            .and_then(|result_set| {
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|count| count.as_i64())
+                    .ok_or_else(|| {
+                        SqlError::Generic("Unexpected result set shape when checking for duplicate values.".to_owned())
+                    })
+            })?;
+
+        Ok(duplicates_count)
+    }
+
+    /// Count the rows for which the check expression is false. Rows for which it is null satisfy
+    /// the check, as in the databases.
+    async fn count_rows_violating_check(
+        &self,
+        expression: &str,
+        table: &sql_schema_describer::Table,
+    ) -> SqlResult<i64> {
+        let renderer = SqlRenderer::for_family(&self.connection_info.sql_family());
+        let query = format!(
+            "SELECT COUNT(*) FROM {table} WHERE NOT ({expression})",
+            table = renderer.quote_with_schema(self.table_schema(table), &table.name),
+            expression = expression,
+        );
+
+        let violations_count: i64 = self
+            .database
+            .query_raw(&query, &[])
+            .await
+            .map_err(SqlError::from)
+            .and_then(|result_set| {
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|count| count.as_i64())
+                    .ok_or_else(|| {
+                        SqlError::Generic("Unexpected result set shape when checking for check violations.".to_owned())
+                    })
+            })?;
+
+        Ok(violations_count)
+    }
+
     /// Emit a warning when we drop a column that contains non-null values.
     async fn check_column_drop(
         &self,
@@ -103,7 +209,37 @@ impl SqlDestructiveChangesChecker {
             .column(&alter_column.name)
             .expect("unsupported column renaming");
 
-        if values_count > 0 {
+        if previous_column.tpe.arity == ColumnArity::Nullable && alter_column.column.tpe.arity == ColumnArity::Required
+        {
+            let nulls_count = self.count_nulls_in_column(&alter_column.name, previous_table).await?;
+
+            if nulls_count > 0 {
+                diagnostics.add_error(MigrationError {
+                    tpe: "NullValuesInRequiredColumn".to_owned(),
+                    description: format!(
+                        "You are about to make the column `{column_name}` on the `{table_name}` table required, but it contains {nulls_count} null values.",
+                        column_name = alter_column.name,
+                        table_name = &previous_table.name,
+                        nulls_count = nulls_count,
+                    ),
+                    field: Some(alter_column.name.clone()),
+                });
+            }
+        }
+
+        // Whether the values actually convert is not checked, so this is a warning and not an error.
+        if values_count > 0 && is_narrowing_type_change(&previous_column.tpe.family, &alter_column.column.tpe.family) {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to change the type of the column `{column_name}` on the `{table_name}` table from {previous_type} to {next_type}, but it contains {values_count} non-null values that may not be convertible.",
+                    column_name = alter_column.name,
+                    table_name = &previous_table.name,
+                    previous_type = previous_column.tpe.family,
+                    next_type = alter_column.column.tpe.family,
+                    values_count = values_count,
+                ),
+            });
+        } else if values_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
                                  "You are about to alter the column `{column_name}` on the `{table_name}` table, which still contains {values_count} non-null values. The data in that column will be lost.",
@@ -127,6 +263,124 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
+    /// Emit an error when we add a required column without a default value to a table that
+    /// already contains rows.
+    async fn check_add_column(
+        &self,
+        add_column: &AddColumn,
+        previous_table: &sql_schema_describer::Table,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let column = &add_column.column;
+
+        if !column.is_required() || column.default.is_some() || column.auto_increment {
+            return Ok(());
+        }
+
+        let rows_count = self
+            .count_rows_in_table(previous_table.namespace.as_deref(), &previous_table.name)
+            .await?;
+
+        if rows_count > 0 {
+            diagnostics.add_error(MigrationError {
+                tpe: "RequiredColumnWithoutDefault".to_owned(),
+                description: format!(
+                    "You are about to add the required column `{column_name}` without a default value to the `{table_name}` table, which is not empty ({rows_count} rows).",
+                    column_name = column.name,
+                    table_name = &previous_table.name,
+                    rows_count = rows_count,
+                ),
+                field: Some(column.name.clone()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Emit an error when we add a check constraint that existing rows violate.
+    async fn check_add_check(
+        &self,
+        add_check: &AddCheck,
+        previous_table: &sql_schema_describer::Table,
+        added_columns: &[&str],
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let check = &add_check.check;
+
+        // The existing rows can't be checked against columns that are added in the same migration.
+        if added_columns.iter().any(|column| check.expression.contains(column)) {
+            return Ok(());
+        }
+
+        let violations_count = self
+            .count_rows_violating_check(&check.expression, previous_table)
+            .await?;
+
+        if violations_count > 0 {
+            diagnostics.add_error(MigrationError {
+                tpe: "ExistingRowsViolateCheck".to_owned(),
+                description: format!(
+                    "You are about to add the check constraint `{expression}` to the `{table_name}` table, but {violations_count} existing rows violate it.",
+                    expression = check.expression,
+                    table_name = &previous_table.name,
+                    violations_count = violations_count,
+                ),
+                field: check.name.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Emit an error when we add a unique index on columns that contain duplicate values.
+    async fn check_create_index(
+        &self,
+        create_index: &CreateIndex,
+        database_migration: &SqlMigration,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        if create_index.index.tpe != IndexType::Unique {
+            return Ok(());
+        }
+
+        // Indexes on new tables or new columns cannot contain duplicates.
+        let previous_table = match database_migration
+            .before
+            .get_table_in_namespace(create_index.namespace.as_deref(), &create_index.table)
+        {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        if !create_index
+            .index
+            .columns
+            .iter()
+            .all(|column| previous_table.has_column(column))
+        {
+            return Ok(());
+        }
+
+        let duplicates_count = self
+            .count_duplicate_values(&create_index.index.columns, previous_table)
+            .await?;
+
+        if duplicates_count > 0 {
+            diagnostics.add_error(MigrationError {
+                tpe: "DuplicateValuesInUniqueIndex".to_owned(),
+                description: format!(
+                    "You are about to add a unique constraint on the columns ({columns}) of the `{table_name}` table, but there are {duplicates_count} duplicate values.",
+                    columns = create_index.index.columns.join(", "),
+                    table_name = &create_index.table,
+                    duplicates_count = duplicates_count,
+                ),
+                field: Some(create_index.index.columns.join(", ")),
+            });
+        }
+
+        Ok(())
+    }
+
     async fn check_impl(&self, database_migration: &SqlMigration) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
@@ -137,7 +391,7 @@ impl SqlDestructiveChangesChecker {
                     // check against the current state of the table.
                     let before_table = database_migration
                         .before
-                        .get_table(&alter_table.table.name)
+                        .get_table_in_namespace(alter_table.table.namespace.as_deref(), &alter_table.table.name)
                         .ok_or_else(|| {
                             SqlError::Generic(format!(
                                 "Internal Error: altering previously-unknown table {}",
@@ -145,6 +399,15 @@ impl SqlDestructiveChangesChecker {
                             ))
                         })?;
 
+                    let added_columns: Vec<&str> = alter_table
+                        .changes
+                        .iter()
+                        .filter_map(|change| match change {
+                            TableChange::AddColumn(add_column) => Some(add_column.column.name.as_str()),
+                            _ => None,
+                        })
+                        .collect();
+
                     for change in &alter_table.changes {
                         match *change {
                             TableChange::DropColumn(ref drop_column) => {
@@ -155,20 +418,34 @@ impl SqlDestructiveChangesChecker {
                                 self.check_alter_column(alter_column, before_table, &mut diagnostics)
                                     .await?
                             }
+                            TableChange::AddColumn(ref add_column) => {
+                                self.check_add_column(add_column, before_table, &mut diagnostics)
+                                    .await?
+                            }
+                            TableChange::AddCheck(ref add_check) => {
+                                self.check_add_check(add_check, before_table, &added_columns, &mut diagnostics)
+                                    .await?
+                            }
                             _ => (),
                         }
                     }
                 }
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
-                SqlMigrationStep::DropTable(DropTable { name }) => {
-                    self.check_table_drop(name, &mut diagnostics).await?;
+                SqlMigrationStep::DropTable(DropTable { name, namespace }) => {
+                    self.check_table_drop(namespace.as_deref(), name, &mut diagnostics)
+                        .await?;
                 }
-                SqlMigrationStep::DropTables(DropTables { names }) => {
-                    for name in names {
-                        self.check_table_drop(name, &mut diagnostics).await?;
+                SqlMigrationStep::DropTables(DropTables { names, namespaces }) => {
+                    for (position, name) in names.iter().enumerate() {
+                        let namespace = namespaces.get(position).and_then(Option::as_deref);
+                        self.check_table_drop(namespace, name, &mut diagnostics).await?;
                     }
                 }
+                SqlMigrationStep::CreateIndex(create_index) => {
+                    self.check_create_index(create_index, database_migration, &mut diagnostics)
+                        .await?;
+                }
                 // do nothing
                 _ => (),
             }
@@ -178,6 +455,26 @@ impl SqlDestructiveChangesChecker {
     }
 }
 
+/// Whether changing a column from the `previous` to the `next` type family can fail for existing
+/// values. Only the conversions the databases can always perform are considered safe:
+///
+/// - the same family, without looking at lengths or precisions,
+/// - `Int` to `Float`,
+/// - `Boolean` to `Int`,
+/// - any family to `String`.
+///
+/// Every other pair is narrowing, e.g. `Float` to `Int`, `String` to `Int` or `DateTime`, `Json` to
+/// `Binary` and changes between enums.
+fn is_narrowing_type_change(previous: &ColumnTypeFamily, next: &ColumnTypeFamily) -> bool {
+    match (previous, next) {
+        (previous, next) if previous == next => false,
+        (ColumnTypeFamily::Int, ColumnTypeFamily::Float) => false,
+        (ColumnTypeFamily::Boolean, ColumnTypeFamily::Int) => false,
+        (_, ColumnTypeFamily::String) => false,
+        _ => true,
+    }
+}
+
 #[async_trait::async_trait]
 impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
     async fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
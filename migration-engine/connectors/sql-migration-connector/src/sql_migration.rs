@@ -43,6 +43,11 @@ pub enum SqlMigrationStep {
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    CreateView(CreateView),
+    DropView(DropView),
+    SetComment(SetComment),
+    CreateEnum(CreateEnum),
+    DropEnum(DropEnum),
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -62,11 +67,18 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTables {
     pub names: Vec<String>,
+    /// The schema of each of the tables in `names`, when it is not the default schema.
+    /// Tables without an entry are in the default schema.
+    #[serde(default)]
+    pub namespaces: Vec<Option<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -83,6 +95,8 @@ pub enum TableChange {
     /// This is actually producing SQL only on MySQL, where we have to drop the foreign key
     /// constraint before any column that is part of it.
     DropForeignKey(DropForeignKey),
+    AddCheck(AddCheck),
+    DropCheck(DropCheck),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -106,21 +120,76 @@ pub struct DropForeignKey {
     pub constraint_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheck {
+    pub check: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheck {
+    pub check: CheckConstraint,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
+    /// The schema of the table, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
     pub index: Index,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
+    /// The schema of the table, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterIndex {
     pub table: String,
+    /// The schema of the table, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
     pub index_name: String,
     pub index_new_name: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateView {
+    pub view: View,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropView {
+    pub name: String,
+    /// The schema of the view, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEnum {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropEnum {
+    pub name: String,
+}
+
+/// Sets the comment on a table or on one of its columns. A `None` comment removes it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetComment {
+    pub table: String,
+    /// The schema of the table, when it is not the default schema.
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// The commented column, or `None` for the comment on the table itself.
+    pub column: Option<Column>,
+    pub comment: Option<String>,
+}
@@ -12,7 +12,8 @@ pub fn render_default(column: &Column) -> String {
     match &column.default {
         Some(value) => {
             let default = match column.tpe.family {
-                ColumnTypeFamily::String | ColumnTypeFamily::DateTime => {
+                _ if is_raw_default(value) => format!("DEFAULT {}", value),
+                ColumnTypeFamily::String | ColumnTypeFamily::DateTime | ColumnTypeFamily::Enum(_) => {
                     // TODO: find a better solution for this amazing hack. the default value must not be a String
                     if value.starts_with("'") {
                         format!("DEFAULT {}", value)
@@ -33,6 +34,16 @@ pub fn render_default(column: &Column) -> String {
     }
 }
 
+/// Whether a calculated default is raw SQL computed by the database, to be rendered as is: the
+/// parenthesized expression of `dbgenerated()`, or the current timestamp for `now()`.
+pub fn is_raw_default(value: &str) -> bool {
+    (value.starts_with('(') && value.ends_with(')')) || value.starts_with("CURRENT_TIMESTAMP")
+}
+
+pub fn render_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
 pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
         ForeignKeyAction::NoAction => "",
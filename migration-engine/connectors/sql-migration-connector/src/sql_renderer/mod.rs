@@ -10,6 +10,8 @@ use mysql_renderer::MySqlRenderer;
 use postgres_renderer::PostgresRenderer;
 use sqlite_renderer::SqliteRenderer;
 
+pub(crate) use common::{is_raw_default, render_string_literal};
+
 pub trait SqlRenderer {
     fn quote_with_schema(&self, schema: &str, name: &str) -> String {
         format!("{}.{}", self.quote(&schema), self.quote(&name),)
@@ -17,11 +19,23 @@ pub trait SqlRenderer {
 
     fn quote(&self, name: &str) -> String;
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String;
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        add_fk_prefix: bool,
+        enums: &[Enum],
+    ) -> String;
 
-    fn render_column_type(&self, t: &ColumnType) -> String;
+    /// Enum columns are rendered with the values of their enum from `enums`.
+    fn render_column_type(&self, t: &ColumnType, enums: &[Enum]) -> String;
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String;
+
+    /// Renders the statement setting the comment on the given, already quoted table, or on one of its columns.
+    fn render_set_comment(&self, table: &str, column: Option<&Column>, comment: Option<&str>, enums: &[Enum])
+        -> String;
 }
 
 impl dyn SqlRenderer {
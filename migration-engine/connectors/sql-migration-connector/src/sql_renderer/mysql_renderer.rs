@@ -7,32 +7,49 @@ impl super::SqlRenderer for MySqlRenderer {
         format!("`{}`", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        add_fk_prefix: bool,
+        enums: &[Enum],
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&column.tpe, enums);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
         let references_str = self.render_references(&schema_name, foreign_key);
         let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+        let comment_str = column
+            .comment
+            .as_deref()
+            .map(|comment| format!("COMMENT {}", render_mysql_string(comment)))
+            .unwrap_or_default();
 
         match foreign_key {
             Some(_) => {
                 let add = if add_fk_prefix { "ADD" } else { "" };
                 let fk_line = format!("{} FOREIGN KEY ({}) {}", add, column_name, references_str);
                 format!(
-                    "{} {} {} {},\n{}",
-                    column_name, tpe_str, nullability_str, default_str, fk_line
+                    "{} {} {} {} {},\n{}",
+                    column_name, tpe_str, nullability_str, default_str, comment_str, fk_line
                 )
             }
             None => format!(
-                "{} {} {} {} {}",
-                column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{} {} {} {} {} {}",
+                column_name, tpe_str, nullability_str, default_str, auto_increment_str, comment_str
             ),
         }
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, t: &ColumnType, enums: &[Enum]) -> String {
+        // Native types are rendered exactly as declared.
+        if !t.raw.is_empty() {
+            return t.raw.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
@@ -41,6 +58,20 @@ impl super::SqlRenderer for MySqlRenderer {
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar(191)"),
+            ColumnTypeFamily::Json => format!("json"),
+            // Enums are defined inline. Lists of enum values are stored in `SET` columns.
+            ColumnTypeFamily::Enum(name) => {
+                let enm = enums
+                    .iter()
+                    .find(|enm| &enm.name == name)
+                    .unwrap_or_else(|| panic!("Enum {} not found", name));
+                let values: Vec<String> = enm.values.iter().map(|value| render_string_literal(value)).collect();
+
+                match t.arity {
+                    ColumnArity::List => format!("SET({})", values.join(", ")),
+                    _ => format!("ENUM({})", values.join(", ")),
+                }
+            }
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -49,7 +80,7 @@ impl super::SqlRenderer for MySqlRenderer {
         match foreign_key {
             Some(fk) => format!(
                 "REFERENCES `{}`.`{}`(`{}`) {}",
-                schema_name,
+                fk.referenced_schema.as_deref().unwrap_or(schema_name),
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
                 render_on_delete(&fk.on_delete_action)
@@ -57,4 +88,28 @@ impl super::SqlRenderer for MySqlRenderer {
             None => "".to_string(),
         }
     }
+
+    /// Column comments are rendered in the column definitions. An empty comment removes the
+    /// comment of the table.
+    fn render_set_comment(
+        &self,
+        table: &str,
+        column: Option<&Column>,
+        comment: Option<&str>,
+        _enums: &[Enum],
+    ) -> String {
+        match column {
+            Some(_) => unreachable!("MySQL column comments are part of the column definition."),
+            None => format!(
+                "ALTER TABLE {} COMMENT = {}",
+                table,
+                render_mysql_string(comment.unwrap_or(""))
+            ),
+        }
+    }
+}
+
+/// MySQL treats backslashes in string literals as escape characters.
+fn render_mysql_string(value: &str) -> String {
+    render_string_literal(&value.replace('\\', "\\\\"))
 }
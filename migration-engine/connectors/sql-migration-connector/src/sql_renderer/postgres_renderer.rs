@@ -7,9 +7,16 @@ impl super::SqlRenderer for PostgresRenderer {
         format!("\"{}\"", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        enums: &[Enum],
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&column.tpe, enums);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -28,18 +35,25 @@ impl super::SqlRenderer for PostgresRenderer {
     }
 
     //Render the Arity here
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, t: &ColumnType, _enums: &[Enum]) -> String {
         let array = match t.arity {
             ColumnArity::List => "[]",
             _ => "",
         };
 
+        // Native types are rendered exactly as declared.
+        if !t.raw.is_empty() {
+            return format!("{} {}", t.raw, array);
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean {}", array),
             ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
             ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
             ColumnTypeFamily::Int => format!("integer {}", array),
             ColumnTypeFamily::String => format!("text {}", array),
+            ColumnTypeFamily::Enum(name) => format!("{} {}", self.quote(name), array),
+            ColumnTypeFamily::Json => format!("jsonb {}", array),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -48,7 +62,7 @@ impl super::SqlRenderer for PostgresRenderer {
         match foreign_key {
             Some(fk) => format!(
                 "REFERENCES \"{}\".\"{}\"(\"{}\") {}",
-                schema_name,
+                fk.referenced_schema.as_deref().unwrap_or(schema_name),
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
                 render_on_delete(&fk.on_delete_action)
@@ -56,4 +70,24 @@ impl super::SqlRenderer for PostgresRenderer {
             None => "".to_string(),
         }
     }
+
+    fn render_set_comment(
+        &self,
+        table: &str,
+        column: Option<&Column>,
+        comment: Option<&str>,
+        _enums: &[Enum],
+    ) -> String {
+        let comment = comment.map(render_string_literal).unwrap_or_else(|| "NULL".to_string());
+
+        match column {
+            Some(column) => format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                table,
+                self.quote(&column.name),
+                comment
+            ),
+            None => format!("COMMENT ON TABLE {} IS {}", table, comment),
+        }
+    }
 }
@@ -7,9 +7,16 @@ impl super::SqlRenderer for SqliteRenderer {
         format!("\"{}\"", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        _enums: &[Enum],
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&column.tpe, &[]);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -26,7 +33,12 @@ impl super::SqlRenderer for SqliteRenderer {
         )
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, t: &ColumnType, _enums: &[Enum]) -> String {
+        // Native types are rendered exactly as declared.
+        if !t.raw.is_empty() {
+            return t.raw.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
@@ -48,4 +60,14 @@ impl super::SqlRenderer for SqliteRenderer {
             None => "".to_string(),
         }
     }
+
+    fn render_set_comment(
+        &self,
+        _table: &str,
+        _column: Option<&Column>,
+        _comment: Option<&str>,
+        _enums: &[Enum],
+    ) -> String {
+        unreachable!("SQLite does not store comments.")
+    }
 }
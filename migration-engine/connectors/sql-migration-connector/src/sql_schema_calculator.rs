@@ -1,4 +1,4 @@
-use crate::SqlResult;
+use crate::{sql_schema_differ::normalize_sql_expression, SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
@@ -9,11 +9,12 @@ use sql_schema_describer::ColumnArity;
 
 pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<sql::SqlSchema> {
-        let calculator = SqlSchemaCalculator { data_model };
+    pub fn calculate(data_model: &Datamodel, sql_family: SqlFamily) -> SqlResult<sql::SqlSchema> {
+        let calculator = SqlSchemaCalculator { data_model, sql_family };
         calculator.calculate_internal()
     }
 
@@ -31,27 +32,127 @@ impl<'a> SqlSchemaCalculator<'a> {
             table.columns.sort_unstable_by_key(|col| col.name.clone());
         }
 
-        let enums = Vec::new();
+        let enums = self.calculate_enums();
         let sequences = Vec::new();
+        let views = self.calculate_views();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            views,
         })
     }
 
+    /// Postgres has enum types of their own. MySQL enums are defined inline on each column, and
+    /// described as one enum per column.
+    fn calculate_enums(&self) -> Vec<sql::Enum> {
+        match self.sql_family {
+            SqlFamily::Postgres => self
+                .data_model
+                .enums()
+                .map(|enm| sql::Enum {
+                    name: enum_db_name(enm),
+                    values: enm.values.iter().cloned().collect(),
+                    namespace: None,
+                })
+                .collect(),
+            SqlFamily::Mysql => self
+                .data_model
+                .models()
+                .filter(|model| !model.is_view)
+                .flat_map(|model| model.fields().map(move |field| (model, field)))
+                .filter_map(|(model, field)| match &field.field_type {
+                    FieldType::Enum(enum_name) => Some(sql::Enum {
+                        name: sql::mysql::enum_name(&model.db_name(), &field.db_name()),
+                        values: self.find_enum(enum_name).values.iter().cloned().collect(),
+                        namespace: None,
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            SqlFamily::Sqlite => Vec::new(),
+        }
+    }
+
+    /// Enum fields become enum columns where the database supports them, and string columns on
+    /// SQLite. The same goes for composite type fields and JSON columns.
+    fn column_type(&self, model: &Model, field: &Field) -> sql::ColumnType {
+        let enum_name = match &field.field_type {
+            FieldType::Enum(enum_name) => enum_name,
+            FieldType::CompositeType(_) => {
+                let family = match self.sql_family {
+                    SqlFamily::Sqlite => sql::ColumnTypeFamily::String,
+                    _ => sql::ColumnTypeFamily::Json,
+                };
+
+                return sql::ColumnType::pure(family, column_arity(field));
+            }
+            _ => return column_type(field),
+        };
+
+        let family = match self.sql_family {
+            SqlFamily::Postgres => sql::ColumnTypeFamily::Enum(enum_db_name(self.find_enum(enum_name))),
+            SqlFamily::Mysql => sql::ColumnTypeFamily::Enum(sql::mysql::enum_name(&model.db_name(), &field.db_name())),
+            SqlFamily::Sqlite => return column_type(field),
+        };
+
+        sql::ColumnType::pure(family, column_arity(field))
+    }
+
+    fn find_enum(&self, name: &str) -> &dml::Enum {
+        self.data_model
+            .find_enum(name)
+            .expect("Enum fields must refer to existing enums.")
+    }
+
+    fn calculate_views(&self) -> Vec<sql::View> {
+        self.data_model
+            .models()
+            .filter(|model| model.is_view)
+            .map(|model| {
+                let mut columns: Vec<sql::Column> = model
+                    .fields()
+                    .flat_map(|f| match &f.field_type {
+                        FieldType::Base(_) | FieldType::ConnectorSpecific(_) | FieldType::Enum(_) => {
+                            Some(sql::Column {
+                                name: f.db_name(),
+                                tpe: column_type(f),
+                                default: None,
+                                auto_increment: false,
+                                comment: None,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                columns.sort_unstable_by_key(|col| col.name.clone());
+
+                sql::View {
+                    name: model.db_name(),
+                    definition: model.view_definition.clone(),
+                    columns,
+                    namespace: model.schema.clone(),
+                }
+            })
+            .collect()
+    }
+
     fn calculate_model_tables(&self) -> SqlResult<Vec<ModelTable>> {
         self.data_model
             .models()
+            .filter(|model| !model.is_view && !model.is_composite_type)
             .map(|model| {
                 let columns = model
                     .fields()
                     .flat_map(|f| match &f.field_type {
-                        FieldType::Base(_) | FieldType::Enum(_) => Some(sql::Column {
+                        FieldType::Base(_)
+                        | FieldType::ConnectorSpecific(_)
+                        | FieldType::Enum(_)
+                        | FieldType::CompositeType(_) => Some(sql::Column {
                             name: f.db_name(),
-                            tpe: column_type(f),
-                            default: f.migration_value_new(&self.data_model),
+                            tpe: self.column_type(model, f),
+                            default: f.migration_value_new(&self.data_model, self.sql_family),
                             auto_increment: {
                                 match f.id_info {
                                     Some(IdInfo {
@@ -61,6 +162,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                     _ => false,
                                 }
                             },
+                            comment: f.documentation.clone(),
                         }),
                         _ => None,
                     })
@@ -77,6 +179,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                             name: format!("{}.{}", &model.db_name(), &f.db_name()),
                             columns: vec![f.db_name().clone()],
                             tpe: sql::IndexType::Unique,
+                            sort_orders: vec![],
+                            expression: None,
+                            predicate: None,
+                            algorithm: None,
                         })
                     } else {
                         None
@@ -106,15 +212,37 @@ impl<'a> SqlSchemaCalculator<'a> {
                         } else {
                             sql::IndexType::Normal
                         },
+                        sort_orders: index_definition.sort_orders.iter().map(sort_order).collect(),
+                        expression: index_definition.expression.clone(),
+                        predicate: index_definition.predicate.clone(),
+                        algorithm: index_definition.algorithm.as_ref().and_then(index_algorithm),
                     }
                 });
 
+                // Checks always get a name, so they can be found again when diffing.
+                let checks = model
+                    .checks
+                    .iter()
+                    .map(|check| sql::CheckConstraint {
+                        name: Some(
+                            check
+                                .name
+                                .clone()
+                                .unwrap_or_else(|| check_constraint_name(&model.db_name(), &check.expression)),
+                        ),
+                        expression: check.expression.clone(),
+                    })
+                    .collect();
+
                 let table = sql::Table {
                     name: model.db_name(),
                     columns,
                     indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                     primary_key: Some(primary_key),
                     foreign_keys: Vec::new(),
+                    checks,
+                    namespace: model.schema.clone(),
+                    comment: model.documentation.clone(),
                 };
 
                 Ok(ModelTable {
@@ -143,19 +271,42 @@ impl<'a> SqlSchemaCalculator<'a> {
 
                         let field = model.fields().find(|f| &f.db_name() == column_name).unwrap();
 
+                        if let FieldType::Relation(relation_info) = &field.field_type {
+                            if !relation_info.fields.is_empty() {
+                                let foreign_key = composite_foreign_key(model, related_model, relation_info);
+
+                                if relation.is_one_to_one() {
+                                    model_table.table.indices.push(sql::Index {
+                                        name: format!("{}_{}", model_table.table.name, foreign_key.columns.join("_")),
+                                        columns: foreign_key.columns.clone(),
+                                        tpe: sql::IndexType::Unique,
+                                        sort_orders: vec![],
+                                        expression: None,
+                                        predicate: None,
+                                        algorithm: None,
+                                    });
+                                }
+
+                                model_table.table.foreign_keys.push(foreign_key);
+                                continue;
+                            }
+                        }
+
                         let column = sql::Column {
                             name: column_name.to_string(),
-                            tpe: column_type_for_scalar_type(
-                                scalar_type_for_field(related_model.id_field()?),
-                                column_arity(&field),
-                            ),
+                            tpe: sql::ColumnType {
+                                arity: column_arity(field),
+                                ..column_type(related_model.id_field()?)
+                            },
                             default: None,
                             auto_increment: false,
+                            comment: field.documentation.clone(),
                         };
                         let foreign_key = sql::ForeignKey {
                             constraint_name: None,
                             columns: vec![column_name.to_string()],
                             referenced_table: related_model.db_name(),
+                            referenced_schema: related_model.schema.clone(),
                             referenced_columns: vec![related_model.id_field()?.db_name()],
                             on_delete_action: if column.is_required() {
                                 sql::ForeignKeyAction::Restrict
@@ -188,6 +339,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: vec![relation.model_a_column()],
                             referenced_table: relation.model_a.db_name(),
+                            referenced_schema: relation.model_a.schema.clone(),
                             referenced_columns: vec![relation.model_a.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
                         },
@@ -195,10 +347,30 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: vec![relation.model_b_column()],
                             referenced_table: relation.model_b.db_name(),
+                            referenced_schema: relation.model_b.schema.clone(),
                             referenced_columns: vec![relation.model_b.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
                         },
                     ];
+                    // Custom join tables use their two columns as primary key, like the tables introspection recognises.
+                    let (indices, primary_key) = if relation.has_custom_table() {
+                        let primary_key = sql::PrimaryKey {
+                            columns: vec![relation.model_a_column(), relation.model_b_column()],
+                            sequence: None,
+                        };
+                        (Vec::new(), Some(primary_key))
+                    } else {
+                        let index = sql::Index {
+                            name: format!("{}_AB_unique", relation.table_name()),
+                            columns: vec![relation.model_a_column(), relation.model_b_column()],
+                            tpe: sql::IndexType::Unique,
+                            sort_orders: vec![],
+                            expression: None,
+                            predicate: None,
+                            algorithm: None,
+                        };
+                        (vec![index], None)
+                    };
                     let table = sql::Table {
                         name: relation.table_name(),
                         columns: vec![
@@ -207,21 +379,22 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 tpe: column_type(relation.model_a.id_field()?),
                                 default: None,
                                 auto_increment: false,
+                                comment: None,
                             },
                             sql::Column {
                                 name: relation.model_b_column(),
                                 tpe: column_type(relation.model_b.id_field()?),
                                 default: None,
                                 auto_increment: false,
+                                comment: None,
                             },
                         ],
-                        indices: vec![sql::Index {
-                            name: format!("{}_AB_unique", relation.table_name()),
-                            columns: vec![relation.model_a_column(), relation.model_b_column()],
-                            tpe: sql::IndexType::Unique,
-                        }],
-                        primary_key: None,
+                        indices,
+                        primary_key,
                         foreign_keys,
+                        checks: Vec::new(),
+                        namespace: None,
+                        comment: None,
                     };
                     result.push(table);
                 }
@@ -272,7 +445,7 @@ pub trait FieldExtensions {
 
     fn migration_value(&self, datamodel: &Datamodel) -> ScalarValue;
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String>;
+    fn migration_value_new(&self, datamodel: &Datamodel, sql_family: SqlFamily) -> Option<String>;
 }
 
 impl FieldExtensions for Field {
@@ -298,7 +471,30 @@ impl FieldExtensions for Field {
             .unwrap_or_else(|| default_migration_value(&self.field_type, datamodel))
     }
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String> {
+    fn migration_value_new(&self, datamodel: &Datamodel, sql_family: SqlFamily) -> Option<String> {
+        // There is no sensible default document for a composite type.
+        if self.field_type.is_composite_type() {
+            return None;
+        }
+
+        // Defaults computed by the database are rendered as raw SQL, see `sql_renderer::is_raw_default`.
+        match &self.default_value {
+            Some(ScalarValue::Expression(name, _, args)) if name == functions::DB_GENERATED => {
+                return match args.first() {
+                    Some(ScalarValue::String(expression)) => Some(format!("({})", expression)),
+                    _ => None,
+                };
+            }
+            Some(ScalarValue::Expression(name, _, _)) if name == "now" => {
+                return match sql_family {
+                    // MySQL requires the precision of the default to match the one of the `datetime(3)` column.
+                    SqlFamily::Mysql => Some("CURRENT_TIMESTAMP(3)".to_string()),
+                    SqlFamily::Postgres | SqlFamily::Sqlite => Some("CURRENT_TIMESTAMP".to_string()),
+                };
+            }
+            _ => (),
+        }
+
         let value = match &self.default_value {
             Some(x) => match x {
                 ScalarValue::Expression(_, _, _) => default_migration_value(&self.field_type, datamodel),
@@ -317,6 +513,10 @@ impl FieldExtensions for Field {
             ScalarValue::Int(x) => format!("{}", x),
             ScalarValue::Float(x) => format!("{}", x),
             ScalarValue::Decimal(x) => format!("{}", x),
+            // A literal that would be taken for raw SQL is rendered as a quoted string literal.
+            ScalarValue::String(x) if crate::sql_renderer::is_raw_default(&x) => {
+                crate::sql_renderer::render_string_literal(&x)
+            }
             ScalarValue::String(x) => format!("{}", x),
 
             ScalarValue::DateTime(x) => {
@@ -349,6 +549,9 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Sca
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
             ScalarValue::DateTime(datetime)
         }
+        FieldType::ConnectorSpecific(native_type) => {
+            default_migration_value(&FieldType::Base(native_type.prisma_type()), datamodel)
+        }
         FieldType::Enum(ref enum_name) => {
             let inum = datamodel
                 .find_enum(&enum_name)
@@ -363,14 +566,47 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Sca
     }
 }
 
+fn sort_order(sort_order: &dml::SortOrder) -> sql::SortOrder {
+    match sort_order {
+        dml::SortOrder::Asc => sql::SortOrder::Asc,
+        dml::SortOrder::Desc => sql::SortOrder::Desc,
+    }
+}
+
+/// The default `BTREE` method is left out, like the describer does.
+fn index_algorithm(algorithm: &dml::IndexAlgorithm) -> Option<sql::IndexAlgorithm> {
+    match algorithm {
+        dml::IndexAlgorithm::BTree => None,
+        dml::IndexAlgorithm::Hash => Some(sql::IndexAlgorithm::Hash),
+        dml::IndexAlgorithm::Gist => Some(sql::IndexAlgorithm::Gist),
+        dml::IndexAlgorithm::Gin => Some(sql::IndexAlgorithm::Gin),
+        dml::IndexAlgorithm::SpGist => Some(sql::IndexAlgorithm::SpGist),
+        dml::IndexAlgorithm::Brin => Some(sql::IndexAlgorithm::Brin),
+    }
+}
+
+fn enum_db_name(enm: &dml::Enum) -> String {
+    enm.database_name.clone().unwrap_or_else(|| enm.name.clone())
+}
+
+/// Fields with a native type keep their exact database type in the raw column type.
 fn column_type(field: &Field) -> sql::ColumnType {
-    column_type_for_scalar_type(scalar_type_for_field(field), column_arity(field))
+    let column_type = column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field));
+
+    match &field.field_type {
+        FieldType::ConnectorSpecific(native_type) => sql::ColumnType {
+            raw: native_type.datasource_type().to_string(),
+            ..column_type
+        },
+        _ => column_type,
+    }
 }
 
-fn scalar_type_for_field(field: &Field) -> &ScalarType {
+fn scalar_type_for_field(field: &Field) -> ScalarType {
     match &field.field_type {
-        FieldType::Base(ref scalar) => &scalar,
-        FieldType::Enum(_) => &ScalarType::String,
+        FieldType::Base(scalar) => *scalar,
+        FieldType::ConnectorSpecific(native_type) => native_type.prisma_type(),
+        FieldType::Enum(_) => ScalarType::String,
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
             x, field.name
@@ -397,11 +633,61 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
     }
 }
 
+/// Relations over several scalar fields reuse the columns of these fields instead of adding one.
+fn composite_foreign_key(model: &Model, related_model: &Model, relation_info: &RelationInfo) -> sql::ForeignKey {
+    let column_names = |model: &Model, field_names: &[String]| -> Vec<String> {
+        field_names
+            .iter()
+            .map(|name| {
+                model
+                    .find_field(name)
+                    .unwrap_or_else(|| panic!("Field {} not found on model {}", name, model.name))
+                    .db_name()
+            })
+            .collect()
+    };
+    let is_required = relation_info
+        .fields
+        .iter()
+        .filter_map(|name| model.find_field(name))
+        .all(|field| field.is_required());
+
+    sql::ForeignKey {
+        constraint_name: None,
+        columns: column_names(model, &relation_info.fields),
+        referenced_table: related_model.db_name(),
+        referenced_schema: related_model.schema.clone(),
+        referenced_columns: column_names(related_model, &relation_info.to_fields),
+        on_delete_action: if is_required {
+            sql::ForeignKeyAction::Restrict
+        } else {
+            sql::ForeignKeyAction::SetNull
+        },
+    }
+}
+
+/// Unnamed checks are named after a hash of their expression, so reordering them or adding other
+/// checks doesn't rename them, and changing their expression does.
+fn check_constraint_name(table_name: &str, expression: &str) -> String {
+    // FNV-1a, because the hashers of the standard library are not guaranteed to be stable.
+    let hash = normalize_sql_expression(expression)
+        .bytes()
+        .fold(0x811c_9dc5u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+
+    format!("{}_check_{:08x}", table_name, hash)
+}
+
 fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_name: &str) {
     let index = sql::Index {
         name: format!("{}_{}", table.name, column_name),
         columns: vec![column_name.to_string()],
         tpe: sql::IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     };
 
     table.indices.push(index);
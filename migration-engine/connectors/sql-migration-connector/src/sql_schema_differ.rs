@@ -12,21 +12,32 @@ const MIGRATION_TABLE_NAME: &str = "_Migration";
 pub struct SqlSchemaDiffer<'a> {
     previous: &'a SqlSchema,
     next: &'a SqlSchema,
+    sql_family: SqlFamily,
 }
 
 #[derive(Debug, Clone)]
 pub struct SqlSchemaDiff {
     pub drop_tables: Vec<DropTable>,
     pub create_tables: Vec<CreateTable>,
+    pub create_enums: Vec<CreateEnum>,
+    pub drop_enums: Vec<DropEnum>,
     pub alter_tables: Vec<AlterTable>,
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub create_views: Vec<CreateView>,
+    pub drop_views: Vec<DropView>,
+    pub set_comments: Vec<SetComment>,
 }
 
 impl SqlSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
-        wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex)
+        // Order matters: views are dropped first and created last, because they depend on the
+        // tables they select from.
+        wrap_as_step(self.drop_views, SqlMigrationStep::DropView)
+            .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
+            // Order matters: enum types must exist before the tables using them.
+            .chain(wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum))
             // Order matters: we must create tables before `alter_table`s because we could
             // be adding foreign keys to the new tables there.
             .chain(wrap_as_step(self.create_tables, SqlMigrationStep::CreateTable))
@@ -34,15 +45,23 @@ impl SqlSchemaDiff {
             // drop foreign keys before the tables they are pointing to.
             .chain(wrap_as_step(self.alter_tables, SqlMigrationStep::AlterTable))
             .chain(wrap_as_step(self.drop_tables, SqlMigrationStep::DropTable))
+            .chain(wrap_as_step(self.drop_enums, SqlMigrationStep::DropEnum))
             .chain(wrap_as_step(self.create_indexes, SqlMigrationStep::CreateIndex))
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
+            // Comments are set once all tables and columns exist.
+            .chain(wrap_as_step(self.set_comments, SqlMigrationStep::SetComment))
+            .chain(wrap_as_step(self.create_views, SqlMigrationStep::CreateView))
             .collect()
     }
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
-    pub fn diff(previous: &SqlSchema, next: &SqlSchema) -> SqlSchemaDiff {
-        let differ = SqlSchemaDiffer { previous, next };
+    pub fn diff(previous: &SqlSchema, next: &SqlSchema, sql_family: SqlFamily) -> SqlSchemaDiff {
+        let differ = SqlSchemaDiffer {
+            previous,
+            next,
+            sql_family,
+        };
         differ.diff_internal()
     }
 
@@ -52,17 +71,53 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         SqlSchemaDiff {
             drop_tables: self.drop_tables(),
             create_tables: self.create_tables(),
+            create_enums: self.create_enums(),
+            drop_enums: self.drop_enums(),
             alter_tables: self.alter_tables(),
             create_indexes: self.create_indexes(&alter_indexes),
             drop_indexes: self.drop_indexes(&alter_indexes).collect(),
             alter_indexes,
+            create_views: self.create_views(),
+            drop_views: self.drop_views(),
+            set_comments: self.set_comments(),
         }
     }
 
+    /// Views without a definition are managed outside of migrations and never get created.
+    /// Views whose definition changed are dropped and created again.
+    fn create_views(&self) -> Vec<CreateView> {
+        self.next
+            .views
+            .iter()
+            .filter(|next_view| match matching_view(self.previous, next_view) {
+                Some(previous_view) => view_changed(previous_view, next_view),
+                None => next_view.definition.is_some(),
+            })
+            .map(|next_view| CreateView {
+                view: next_view.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_views(&self) -> Vec<DropView> {
+        self.previous
+            .views
+            .iter()
+            .filter(|previous_view| match matching_view(self.next, previous_view) {
+                Some(next_view) => view_changed(previous_view, next_view),
+                None => true,
+            })
+            .map(|previous_view| DropView {
+                name: previous_view.name.clone(),
+                namespace: previous_view.namespace.clone(),
+            })
+            .collect()
+    }
+
     fn create_tables(&self) -> Vec<CreateTable> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
-            if !self.previous.has_table(&next_table.name) && next_table.name != MIGRATION_TABLE_NAME {
+            if matching_table(self.previous, next_table).is_none() && next_table.name != MIGRATION_TABLE_NAME {
                 let create = CreateTable {
                     table: next_table.clone(),
                 };
@@ -72,12 +127,88 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         result
     }
 
+    /// Sets the comments of new tables and columns, and of existing ones whose comment changed.
+    /// MySQL column comments are rendered in the column definitions instead.
+    fn set_comments(&self) -> Vec<SetComment> {
+        let mut result = Vec::new();
+        for next_table in self.next.tables.iter().filter(|t| t.name != MIGRATION_TABLE_NAME) {
+            let previous_table = matching_table(self.previous, next_table);
+            let previous_table_comment = previous_table.and_then(|t| t.comment.as_ref());
+
+            if previous_table_comment != next_table.comment.as_ref() {
+                result.push(SetComment {
+                    table: next_table.name.clone(),
+                    namespace: next_table.namespace.clone(),
+                    column: None,
+                    comment: next_table.comment.clone(),
+                });
+            }
+
+            if self.sql_family == SqlFamily::Mysql {
+                continue;
+            }
+
+            for next_column in &next_table.columns {
+                let previous_column_comment = previous_table
+                    .and_then(|t| t.column(&next_column.name))
+                    .and_then(|c| c.comment.as_ref());
+
+                if previous_column_comment != next_column.comment.as_ref() {
+                    result.push(SetComment {
+                        table: next_table.name.clone(),
+                        namespace: next_table.namespace.clone(),
+                        column: Some(next_column.clone()),
+                        comment: next_column.comment.clone(),
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// Only Postgres has enum types of their own. MySQL enums are part of their column definitions.
+    /// Changes to the values of existing enums are not migrated yet. Datamodel enums always live in
+    /// the default schema, so the enums of other schemas are left alone.
+    fn create_enums(&self) -> Vec<CreateEnum> {
+        if self.sql_family != SqlFamily::Postgres {
+            return Vec::new();
+        }
+
+        self.next
+            .enums
+            .iter()
+            .filter(|next_enum| next_enum.namespace.is_none())
+            .filter(|next_enum| default_schema_enum(self.previous, &next_enum.name).is_none())
+            .map(|next_enum| CreateEnum {
+                name: next_enum.name.clone(),
+                values: next_enum.values.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_enums(&self) -> Vec<DropEnum> {
+        if self.sql_family != SqlFamily::Postgres {
+            return Vec::new();
+        }
+
+        self.previous
+            .enums
+            .iter()
+            .filter(|previous_enum| previous_enum.namespace.is_none())
+            .filter(|previous_enum| default_schema_enum(self.next, &previous_enum.name).is_none())
+            .map(|previous_enum| DropEnum {
+                name: previous_enum.name.clone(),
+            })
+            .collect()
+    }
+
     fn drop_tables(&self) -> Vec<DropTable> {
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if !self.next.has_table(&previous_table.name) && previous_table.name != MIGRATION_TABLE_NAME {
+            if matching_table(self.next, previous_table).is_none() && previous_table.name != MIGRATION_TABLE_NAME {
                 let drop = DropTable {
                     name: previous_table.name.clone(),
+                    namespace: previous_table.namespace.clone(),
                 };
                 result.push(drop);
             }
@@ -89,16 +220,18 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         // TODO: this does not diff primary key columns yet
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if let Ok(next_table) = self.next.table(&previous_table.name) {
+            if let Some(next_table) = matching_table(self.next, previous_table) {
                 let differ = TableDiffer {
                     previous: &previous_table,
                     next: &next_table,
                 };
 
                 let changes: Vec<TableChange> = Self::drop_foreign_keys(&differ)
+                    .chain(Self::drop_checks(&differ))
                     .chain(Self::drop_columns(&differ))
                     .chain(Self::add_columns(&differ))
-                    .chain(Self::alter_columns(&differ))
+                    .chain(Self::alter_columns(&differ, self.sql_family))
+                    .chain(Self::add_checks(&differ))
                     .collect();
 
                 if !changes.is_empty() {
@@ -131,7 +264,27 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
-    fn alter_columns<'a>(table_differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+    fn drop_checks<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.dropped_checks().map(|check| {
+            let change = DropCheck { check: check.clone() };
+
+            TableChange::DropCheck(change)
+        })
+    }
+
+    fn add_checks<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.added_checks().map(|check| {
+            let change = AddCheck { check: check.clone() };
+
+            TableChange::AddCheck(change)
+        })
+    }
+
+    /// MySQL column comments are part of the column definition, so changing them alters the column.
+    fn alter_columns<'a>(
+        table_differ: &'a TableDiffer<'schema>,
+        sql_family: SqlFamily,
+    ) -> impl Iterator<Item = TableChange> + 'a {
         table_differ.column_pairs().filter_map(move |column_differ| {
             let previous_fk = table_differ
                 .previous
@@ -139,7 +292,10 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let next_fk = table_differ.next.foreign_key_for_column(&column_differ.next.name);
 
-            if column_differ.differs_in_something() || foreign_key_changed(previous_fk, next_fk) {
+            let comment_changed =
+                sql_family == SqlFamily::Mysql && column_differ.previous.comment != column_differ.next.comment;
+
+            if column_differ.differs_in_something() || comment_changed || foreign_key_changed(previous_fk, next_fk) {
                 let change = AlterColumn {
                     name: column_differ.previous.name.clone(),
                     column: column_differ.next.clone(),
@@ -172,16 +328,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
             for index in &next_table.indices {
-                // TODO: must diff index settings
-                let previous_index_opt = self
-                    .previous
-                    .table(&next_table.name)
-                    .ok()
+                let previous_index_opt = matching_table(self.previous, next_table)
                     .and_then(|t| t.indices.iter().find(|i| i.name == index.name));
                 let index_was_altered = alter_indexes.iter().any(|altered| altered.index_new_name == index.name);
-                if previous_index_opt.is_none() && !index_was_altered {
+                let index_was_changed = previous_index_opt
+                    .map(|previous| index_changed(previous, index))
+                    .unwrap_or(true);
+                if index_was_changed && !index_was_altered {
                     let create = CreateIndex {
                         table: next_table.name.clone(),
+                        namespace: next_table.namespace.clone(),
                         index: index.clone(),
                     };
                     result.push(create);
@@ -194,15 +350,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     fn drop_indexes<'a>(&'a self, alter_indexes: &'a [AlterIndex]) -> impl Iterator<Item = DropIndex> + 'a {
         self.previous.tables.iter().flat_map(move |previous_table| {
             previous_table.indices.iter().filter_map(move |index| {
-                // TODO: must diff index settings
-                let next_index_opt = self
-                    .next
-                    .table(&previous_table.name)
-                    .ok()
+                let next_index_opt = matching_table(self.next, previous_table)
                     .and_then(|t| t.indices.iter().find(|i| i.name == index.name));
 
                 let index_was_altered = alter_indexes.iter().any(|altered| altered.index_name == index.name);
-                let index_was_dropped = next_index_opt.is_none() && !index_was_altered;
+                // Changed indexes are dropped and created again.
+                let index_was_changed = next_index_opt.map(|next| index_changed(index, next)).unwrap_or(true);
+                let index_was_dropped = index_was_changed && !index_was_altered;
 
                 if !index_was_dropped {
                     return None;
@@ -225,6 +379,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
                 let drop = DropIndex {
                     table: previous_table.name.clone(),
+                    namespace: previous_table.namespace.clone(),
                     name: index.name.clone(),
                 };
 
@@ -236,14 +391,10 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     /// An iterator over the tables that are present in both schemas.
     fn table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a {
         self.previous.tables.iter().filter_map(move |previous_table| {
-            self.next
-                .tables
-                .iter()
-                .find(move |next_table| next_table.name == previous_table.name)
-                .map(move |next_table| TableDiffer {
-                    previous: previous_table,
-                    next: next_table,
-                })
+            matching_table(self.next, previous_table).map(move |next_table| TableDiffer {
+                previous: previous_table,
+                next: next_table,
+            })
         })
     }
 
@@ -255,6 +406,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     index_name: previous_index.name.clone(),
                     index_new_name: renamed_index.name.clone(),
                     table: differ.next.name.clone(),
+                    namespace: differ.next.namespace.clone(),
                 })
             })
         });
@@ -263,6 +415,83 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     }
 }
 
+/// The table of the schema with the same namespace and name as the given one.
+fn matching_table<'a>(schema: &'a SqlSchema, table: &Table) -> Option<&'a Table> {
+    schema.get_table_in_namespace(table.namespace.as_deref(), &table.name)
+}
+
+/// The view of the schema with the same namespace and name as the given one.
+fn matching_view<'a>(schema: &'a SqlSchema, view: &View) -> Option<&'a View> {
+    schema.get_view_in_namespace(view.namespace.as_deref(), &view.name)
+}
+
+fn default_schema_enum<'a>(schema: &'a SqlSchema, name: &str) -> Option<&'a Enum> {
+    schema
+        .enums
+        .iter()
+        .find(|r#enum| r#enum.namespace.is_none() && r#enum.name == name)
+}
+
+/// Compare two indexes with the same name and return whether the index has to be recreated.
+fn index_changed(previous: &Index, next: &Index) -> bool {
+    previous.columns != next.columns
+        || previous.tpe != next.tpe
+        || previous.sort_orders != next.sort_orders
+        || previous.algorithm != next.algorithm
+        || !sql_expressions_match(previous.expression.as_deref(), next.expression.as_deref())
+        || !sql_expressions_match(previous.predicate.as_deref(), next.predicate.as_deref())
+}
+
+/// A view without a definition in the datamodel is never changed by migrations. The definitions of existing
+/// views are the ones they were applied with, as the databases rewrite the definitions they store.
+fn view_changed(previous: &View, next: &View) -> bool {
+    fn definition(view: &View) -> Option<&str> {
+        view.definition.as_ref().map(|d| d.trim_end().trim_end_matches(';'))
+    }
+
+    next.definition.is_some() && !sql_expressions_match(definition(previous), definition(next))
+}
+
+fn sql_expressions_match(previous: Option<&str>, next: Option<&str>) -> bool {
+    match (previous, next) {
+        (None, None) => true,
+        (Some(previous), Some(next)) => normalize_sql_expression(previous) == normalize_sql_expression(next),
+        _ => false,
+    }
+}
+
+/// Databases store SQL expressions in a normalized form, so the expression from the datamodel
+/// usually differs from the described one in casing, whitespace, parentheses, identifier quotes
+/// and Postgres type casts. These differences are removed before comparing expressions.
+pub(crate) fn normalize_sql_expression(expression: &str) -> String {
+    let mut normalized = String::with_capacity(expression.len());
+    let mut chars = expression
+        .chars()
+        .filter(|c| !c.is_whitespace() && !['(', ')', '"', '`'].contains(c))
+        .peekable();
+
+    while let Some(c) = chars.next() {
+        // Skip type casts like `::text`.
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+
+            while chars
+                .peek()
+                .map(|c| c.is_alphanumeric() || ['_', '[', ']'].contains(c))
+                .unwrap_or(false)
+            {
+                chars.next();
+            }
+
+            continue;
+        }
+
+        normalized.extend(c.to_lowercase());
+    }
+
+    normalized
+}
+
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether a
 /// migration needs to be applied.
 fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>) -> bool {
@@ -277,6 +506,7 @@ fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>)
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
     previous.referenced_table == next.referenced_table
+        && previous.referenced_schema == next.referenced_schema
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
@@ -1,3 +1,4 @@
+use crate::sql_renderer::is_raw_default;
 use sql_schema_describer::{Column, ColumnTypeFamily};
 
 #[derive(Debug)]
@@ -9,19 +10,33 @@ pub(crate) struct ColumnDiffer<'a> {
 impl<'a> ColumnDiffer<'a> {
     pub(crate) fn differs_in_something(&self) -> bool {
         self.previous.name != self.next.name
-            // TODO: compare the whole type
-            // || self.previous.tpe != self.next.tpe
             || self.previous.tpe.family != self.next.tpe.family
             || self.previous.tpe.arity != self.next.tpe.arity
+            || !self.native_types_match()
             || !self.defaults_match()
     }
 
+    /// Columns with a native type in the datamodel must have that type in the database. Columns
+    /// without one are only compared by their type family.
+    ///
+    /// The databases describe types by their own names, like `int8` for `bigint` on Postgres, and
+    /// without their arguments, so the type names are compared after resolving these aliases.
+    fn native_types_match(&self) -> bool {
+        if self.next.tpe.raw.is_empty() {
+            return true;
+        }
+
+        native_type_name(&self.previous.tpe.raw) == native_type_name(&self.next.tpe.raw)
+    }
+
     /// There are workarounds to cope with current migration and introspection limitations.
     ///
     /// - Since the values we set and introspect for timestamps are stringly typed, matching exactly the default value strings does not work on any database. Therefore we consider all datetime defaults as the same.
     ///
     /// - Postgres autoincrement fields get inferred with a default, which we want to ignore.
     ///
+    /// - Defaults computed by the database, like `dbgenerated()` expressions, are normalized by the database when described, so we only check that there is a default.
+    ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified, like strings containing escaped characters.
     fn defaults_match(&self) -> bool {
         if self.previous.auto_increment {
@@ -33,6 +48,10 @@ impl<'a> ColumnDiffer<'a> {
         let previous_value: Option<&str> = self.previous.default.as_ref().map(String::as_str);
         let next_value: Option<&str> = self.next.default.as_ref().map(String::as_str);
 
+        if next_value.map(is_raw_default).unwrap_or(false) {
+            return previous_value.is_some();
+        }
+
         match self.previous.tpe.family {
             ColumnTypeFamily::String => string_defaults_match(previous_value, next_value),
             ColumnTypeFamily::Float => float_default(previous_value) == float_default(next_value),
@@ -43,6 +62,38 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// The name of a database type without its arguments and array marker, with aliases resolved.
+fn native_type_name(raw: &str) -> String {
+    let name = raw
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_start_matches('_')
+        .trim_end_matches("[]")
+        .to_lowercase();
+
+    let canonical_name = match name.as_str() {
+        "int2" | "smallserial" => "smallint",
+        "int" | "int4" | "serial" => "integer",
+        "int8" | "bigserial" => "bigint",
+        "float4" => "real",
+        "float8" => "double precision",
+        "numeric" => "decimal",
+        // MySQL describes booleans as `tinyint(1)`.
+        "bool" | "tinyint" => "boolean",
+        "bpchar" | "character" => "char",
+        "character varying" => "varchar",
+        "timestamp without time zone" => "timestamp",
+        "timestamp with time zone" => "timestamptz",
+        "time without time zone" => "time",
+        "time with time zone" => "timetz",
+        other => other,
+    };
+
+    canonical_name.to_string()
+}
+
 fn float_default(s: Option<&str>) -> Option<f64> {
     s.and_then(|s| s.parse().ok())
 }
@@ -89,6 +140,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some("abc".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
@@ -96,6 +148,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some(r##""abc""##.to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         let col_c = Column {
@@ -103,6 +156,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some(r##"'abc'"##.to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -131,6 +185,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
             default: Some("2019-09-01T08:00:00Z".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
@@ -138,6 +193,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
             default: Some("2019-09-01 18:00:00 UTC".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -154,6 +210,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some("0.33".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
@@ -161,6 +218,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some("0.33000".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -174,6 +232,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some("0.34".to_owned()),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(!ColumnDiffer {
@@ -182,4 +241,98 @@ mod tests {
         }
         .defaults_match());
     }
+
+    #[test]
+    fn native_types_are_compared() {
+        let int4_column = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                raw: "int4".to_owned(),
+                family: ColumnTypeFamily::Int,
+                arity: ColumnArity::Required,
+            },
+            default: None,
+            auto_increment: false,
+            comment: None,
+        };
+
+        let int8_column = Column {
+            tpe: ColumnType {
+                raw: "int8".to_owned(),
+                ..int4_column.tpe.clone()
+            },
+            ..int4_column.clone()
+        };
+
+        let bigint_column = Column {
+            tpe: ColumnType {
+                raw: "bigint".to_owned(),
+                ..int4_column.tpe.clone()
+            },
+            ..int4_column.clone()
+        };
+
+        let int_column = Column {
+            tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+            ..int4_column.clone()
+        };
+
+        // `Int` to `Int @db.BigInt`
+        assert!(ColumnDiffer {
+            previous: &int4_column,
+            next: &bigint_column,
+        }
+        .differs_in_something());
+
+        assert!(!ColumnDiffer {
+            previous: &int8_column,
+            next: &bigint_column,
+        }
+        .differs_in_something());
+
+        assert!(!ColumnDiffer {
+            previous: &int4_column,
+            next: &int_column,
+        }
+        .differs_in_something());
+    }
+
+    #[test]
+    fn db_generated_defaults_match() {
+        let col_a = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: Some("md5((random())::text)".to_owned()),
+            auto_increment: false,
+            comment: None,
+        };
+
+        let col_b = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: Some("(md5(random()::text))".to_owned()),
+            auto_increment: false,
+            comment: None,
+        };
+
+        assert!(ColumnDiffer {
+            previous: &col_a,
+            next: &col_b,
+        }
+        .defaults_match());
+
+        let col_c = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+            comment: None,
+        };
+
+        assert!(!ColumnDiffer {
+            previous: &col_c,
+            next: &col_b,
+        }
+        .defaults_match());
+    }
 }
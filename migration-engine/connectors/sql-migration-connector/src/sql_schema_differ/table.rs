@@ -1,5 +1,5 @@
 use super::column::ColumnDiffer;
-use sql_schema_describer::{Column, ForeignKey, Index, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, Table};
 
 pub(crate) struct TableDiffer<'schema> {
     pub(crate) previous: &'schema Table,
@@ -41,6 +41,26 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    pub(crate) fn dropped_checks<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous.checks.iter().filter(move |previous_check| {
+            self.next
+                .checks
+                .iter()
+                .find(|next_check| checks_match(previous_check, next_check))
+                .is_none()
+        })
+    }
+
+    pub(crate) fn added_checks<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next.checks.iter().filter(move |next_check| {
+            self.previous
+                .checks
+                .iter()
+                .find(|previous_check| checks_match(previous_check, next_check))
+                .is_none()
+        })
+    }
+
     pub(crate) fn index_pairs<'a>(&'a self) -> impl Iterator<Item = (&'schema Index, &'schema Index)> + 'a {
         self.previous.indices.iter().filter_map(move |previous_index| {
             self.next
@@ -74,7 +94,22 @@ fn columns_match(a: &Column, b: &Column) -> bool {
     a.name == b.name
 }
 
-/// Compare two SQL indexes and return whether they only differ by name or type.
+/// Unnamed check constraints only exist on SQLite, where they are compared by expression only.
+fn checks_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    let expressions_match = super::sql_expressions_match(Some(&previous.expression), Some(&next.expression));
+
+    match (&previous.name, &next.name) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name && expressions_match,
+        _ => expressions_match,
+    }
+}
+
+/// Compare two SQL indexes and return whether they only differ by name.
 fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
-    first.columns == second.columns && first.tpe == second.tpe
+    first.columns == second.columns
+        && first.tpe == second.tpe
+        && first.sort_orders == second.sort_orders
+        && first.expression == second.expression
+        && first.predicate == second.predicate
+        && first.algorithm == second.algorithm
 }
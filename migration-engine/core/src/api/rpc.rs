@@ -1,6 +1,8 @@
 use super::{GenericApi, MigrationApi};
 use crate::commands::*;
+use datamodel::ast::SourceMap;
 use datamodel::configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
+use datamodel::Configuration;
 use futures::{FutureExt, TryFutureExt};
 use jsonrpc_core::types::error::Error as JsonRpcError;
 use jsonrpc_core::{IoHandler, Params};
@@ -54,8 +56,15 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
 
 impl RpcApi {
     pub async fn new(datamodel: &str) -> crate::Result<Self> {
-        let config = datamodel::parse_configuration(datamodel)?;
+        Self::with_configuration(datamodel::parse_configuration(datamodel)?).await
+    }
+
+    /// Creates the API for a datamodel split across several files.
+    pub async fn new_from_files(files: &SourceMap) -> crate::Result<Self> {
+        Self::with_configuration(datamodel::parse_configuration_files(files)?).await
+    }
 
+    async fn with_configuration(config: Configuration) -> crate::Result<Self> {
         let source = config.datasources.first().ok_or(CommandError::DataModelErrors {
             errors: vec!["There is no datasource in the configuration.".to_string()],
         })?;
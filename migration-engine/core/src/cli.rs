@@ -3,6 +3,7 @@ use itertools::Itertools;
 use migration_connector::*;
 use quaint::prelude::SqlFamily;
 use sql_migration_connector::SqlMigrationConnector;
+use sql_schema_describer::SqlSchema;
 use std::collections::HashMap;
 use thiserror::Error;
 use url::Url;
@@ -102,6 +103,107 @@ pub async fn run(matches: &ArgMatches<'_>, datasource: &str) -> std::result::Res
     }
 }
 
+/// Computes the migration between two datamodels in memory and renders its SQL, without a database.
+///
+/// `--from` is optional, and can be a datamodel or a `SqlSchema` serialized as JSON. When it is
+/// missing, the migration starts from an empty database.
+pub fn diff(matches: &ArgMatches<'_>) -> std::result::Result<String, CliError> {
+    let from = match matches.value_of("from") {
+        Some(path) => Some(read_file(path)?),
+        None => None,
+    };
+    let to = read_file(matches.value_of("to").unwrap())?;
+
+    render_diff(
+        from.as_deref(),
+        &to,
+        matches.value_of("dialect"),
+        matches.value_of("schema"),
+    )
+}
+
+fn render_diff(
+    from: Option<&str>,
+    to: &str,
+    dialect: Option<&str>,
+    schema_name: Option<&str>,
+) -> std::result::Result<String, CliError> {
+    let next = parse_diff_datamodel(to)?;
+
+    let sql_family = match dialect {
+        Some(dialect) => sql_family_for_provider(dialect)?,
+        None => {
+            let config = datamodel::parse_configuration(to).map_err(|err| CliError::Other(format!("{:?}", err)))?;
+            let provider = config
+                .datasources
+                .first()
+                .map(|source| source.connector_type())
+                .ok_or_else(|| {
+                    CliError::Other("No `--dialect` given, and the datamodel does not have a datasource.".to_owned())
+                })?;
+
+            sql_family_for_provider(provider)?
+        }
+    };
+
+    let schema_name = match (schema_name, sql_family) {
+        (Some(schema_name), _) => schema_name,
+        (None, SqlFamily::Postgres) => "public",
+        (None, SqlFamily::Sqlite) => "main",
+        (None, SqlFamily::Mysql) => {
+            return Err(CliError::Other(
+                "The `--schema` argument is required for MySQL.".to_owned(),
+            ))
+        }
+    };
+
+    let previous = match from {
+        // Datamodels never start with a brace, so this must be a serialized `SqlSchema`.
+        Some(from) if from.trim_start().starts_with('{') => serde_json::from_str::<SqlSchema>(from)
+            .map_err(|err| CliError::Other(format!("Could not parse the `--from` schema: {}", err)))?,
+        Some(from) => {
+            sql_migration_connector::calculate_sql_schema(&parse_diff_datamodel(from)?, schema_name, sql_family)
+                .map_err(|err| CliError::Other(format!("{}", err)))?
+        }
+        None => SqlSchema::empty(),
+    };
+
+    let next = sql_migration_connector::calculate_sql_schema(&next, schema_name, sql_family)
+        .map_err(|err| CliError::Other(format!("{}", err)))?;
+    let migration = sql_migration_connector::infer_sql_migration(&previous, &next, schema_name, sql_family)
+        .map_err(|err| CliError::Other(format!("{}", err)))?;
+
+    let statements: Vec<String> = sql_migration_connector::render_migration_sql(&migration, sql_family, schema_name)
+        .into_iter()
+        .map(|statement| {
+            if statement.ends_with(';') {
+                statement
+            } else {
+                format!("{};", statement)
+            }
+        })
+        .collect();
+
+    Ok(statements.join("\n\n"))
+}
+
+fn parse_diff_datamodel(datamodel: &str) -> std::result::Result<datamodel::Datamodel, CliError> {
+    datamodel::parse_datamodel_or_pretty_error(datamodel, "datamodel file, line").map_err(CliError::Other)
+}
+
+fn sql_family_for_provider(provider: &str) -> std::result::Result<SqlFamily, CliError> {
+    match provider {
+        "postgresql" | "postgres" => Ok(SqlFamily::Postgres),
+        "mysql" => Ok(SqlFamily::Mysql),
+        "sqlite" => Ok(SqlFamily::Sqlite),
+        other => Err(CliError::Other(format!("Unsupported dialect `{}`.", other))),
+    }
+}
+
+fn read_file(path: &str) -> std::result::Result<String, CliError> {
+    std::fs::read_to_string(path).map_err(|err| CliError::Other(format!("Could not read `{}`: {}", path, err)))
+}
+
 fn fetch_db_name(url: &Url, default: &str) -> String {
     let result = match url.path_segments() {
         Some(mut segments) => segments.next().unwrap_or(default),
@@ -196,7 +298,7 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                 .short("d")
                 .long("datamodel")
                 .value_name("FILE")
-                .help("Path to the datamodel.")
+                .help("Path to the datamodel, or to a directory of `.prisma` files it is split across.")
                 .takes_value(true)
                 .required(false),
         )
@@ -241,6 +343,41 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                         .required(false),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("diff")
+                .about("Prints the SQL of the migration between two datamodels, without connecting to a database.")
+                .arg(
+                    Arg::with_name("from")
+                        .long("from")
+                        .value_name("FILE")
+                        .help("The previous datamodel, or a database schema as JSON. Defaults to an empty database.")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("to")
+                        .long("to")
+                        .value_name("FILE")
+                        .help("The next datamodel.")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("dialect")
+                        .long("dialect")
+                        .help("The SQL dialect. Defaults to the datasource provider of the next datamodel.")
+                        .possible_values(&["postgresql", "mysql", "sqlite"])
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("schema")
+                        .long("schema")
+                        .help("The database schema. Defaults to `public` on Postgres and `main` on SQLite.")
+                        .takes_value(true)
+                        .required(false),
+                ),
+        )
 }
 
 pub fn render_error(cli_error: CliError) -> user_facing_errors::Error {
@@ -469,4 +606,81 @@ mod tests {
         let db_name = super::fetch_db_name(&url, "postgres");
         assert_eq!(db_name, "pgres");
     }
+
+    #[test]
+    fn test_diff_from_an_empty_database() {
+        let to = r#"
+            model Cat {
+                id   Int    @id
+                name String
+            }
+        "#;
+
+        let sql = super::render_diff(None, to, Some("postgresql"), None).unwrap();
+
+        assert!(sql.starts_with(r#"CREATE TABLE "public"."Cat" ("#), "{}", sql);
+    }
+
+    #[test]
+    fn test_diff_between_two_datamodels_uses_the_datasource_provider() {
+        let from = r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgresql://localhost/prisma"
+            }
+
+            model Cat {
+                id Int @id
+            }
+        "#;
+        let to = r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgresql://localhost/prisma"
+            }
+
+            model Cat {
+                id   Int     @id
+                name String?
+            }
+        "#;
+
+        let sql = super::render_diff(Some(from), to, None, Some("zoo")).unwrap();
+
+        assert!(
+            sql.starts_with(r#"ALTER TABLE "zoo"."Cat" ADD COLUMN "name" text"#),
+            "{}",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_diff_from_a_serialized_sql_schema() {
+        let from = r#"{ "tables": [], "enums": [], "sequences": [] }"#;
+        let to = r#"
+            model Cat {
+                id Int @id
+            }
+        "#;
+
+        let sql = super::render_diff(Some(from), to, Some("sqlite"), None).unwrap();
+
+        assert!(sql.starts_with(r#"CREATE TABLE "main"."Cat" ("#), "{}", sql);
+    }
+
+    #[test]
+    fn test_diff_requires_a_dialect() {
+        let to = r#"
+            model Cat {
+                id Int @id
+            }
+        "#;
+
+        let err = super::render_diff(None, to, None, None).unwrap_err();
+
+        assert_eq!(
+            "Unknown error occured: No `--dialect` given, and the datamodel does not have a datasource.",
+            err.to_string()
+        );
+    }
 }
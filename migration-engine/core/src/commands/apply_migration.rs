@@ -131,9 +131,15 @@ impl<'a> ApplyMigrationCommand<'a> {
             .check(&database_migration)
             .await?;
 
-        match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
+        match (
+            diagnostics.has_errors(),
+            diagnostics.has_warnings(),
+            self.input.force.unwrap_or(false),
+        ) {
+            // We have errors, the migration can never be applied.
+            (true, _, _) => tracing::info!("The migration has errors, it will not be applied."),
             // We have no warnings, or the force flag is passed.
-            (false, _) | (true, true) => {
+            (false, false, _) | (false, true, true) => {
                 tracing::debug!("Applying the migration");
                 let saved_migration = migration_persistence.create(migration).await?;
 
@@ -145,7 +151,7 @@ impl<'a> ApplyMigrationCommand<'a> {
                 tracing::debug!("Migration applied");
             }
             // We have warnings, but no force flag was passed.
-            (true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
+            (false, true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
         }
 
         let DestructiveChangeDiagnostics { warnings, errors } = diagnostics;
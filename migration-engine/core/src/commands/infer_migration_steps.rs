@@ -47,7 +47,7 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)
             .await?;
 
-        let DestructiveChangeDiagnostics { warnings, errors: _ } = connector
+        let DestructiveChangeDiagnostics { warnings, errors } = connector
             .destructive_changes_checker()
             .check(&database_migration)
             .await?;
@@ -89,7 +89,7 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             datamodel: datamodel::render_datamodel_to_string(&next_datamodel).unwrap(),
             datamodel_steps: returned_datamodel_steps,
             database_steps: serde_json::Value::Array(returned_database_migration),
-            errors: vec![],
+            errors,
             warnings,
             general_errors: vec![],
         })
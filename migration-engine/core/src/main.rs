@@ -7,9 +7,9 @@ pub mod migration_engine;
 
 use crate::api::RpcApi;
 use commands::*;
-use datamodel::{self, error::ErrorCollection, Datamodel};
+use datamodel::{self, ast::SourceMap, error::ErrorCollection, Datamodel};
 use futures::FutureExt;
-use std::{fs, io, io::Read};
+use std::{io, path::Path};
 #[cfg(test)]
 mod tests;
 
@@ -23,13 +23,11 @@ pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
     result.map_err(|e| CommandError::DataModelErrors { errors: vec![e] })
 }
 
-pub(crate) fn pretty_print_errors(errors: ErrorCollection, datamodel: &str) {
-    let file_name = "schema.prisma".to_string();
-
+pub(crate) fn pretty_print_errors(errors: ErrorCollection, files: &SourceMap) {
     for error in errors.to_iter() {
         println!();
         error
-            .pretty_print(&mut io::stderr().lock(), &file_name, datamodel)
+            .pretty_print_in_files(&mut io::stderr().lock(), files)
             .expect("Failed to write errors to stderr");
     }
 }
@@ -72,24 +70,35 @@ async fn main() {
                 std::process::exit(255);
             }
         }
+    } else if let Some(matches) = matches.subcommand_matches("diff") {
+        match cli::diff(&matches) {
+            Ok(sql) => {
+                println!("{}", sql);
+                std::process::exit(0);
+            }
+            Err(error) => {
+                tracing::error!("{}", error);
+                let exit_code = error.exit_code();
+                serde_json::to_writer(std::io::stdout(), &cli::render_error(error)).expect("failed to write to stdout");
+                println!();
+                std::process::exit(exit_code);
+            }
+        }
     } else {
         tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
         let dml_loc = matches.value_of("datamodel_location").unwrap();
-        let mut file = fs::File::open(&dml_loc).unwrap();
-
-        let mut datamodel = String::new();
-        file.read_to_string(&mut datamodel).unwrap();
+        let files = SourceMap::load(Path::new(dml_loc)).unwrap();
 
         if matches.is_present("single_cmd") {
-            let api = RpcApi::new(&datamodel).await.unwrap();
+            let api = RpcApi::new_from_files(&files).await.unwrap();
             let response = api.handle().unwrap();
 
             println!("{}", response);
         } else {
-            match RpcApi::new(&datamodel).await {
+            match RpcApi::new_from_files(&files).await {
                 Ok(api) => api.start_server().await,
                 Err(Error::DatamodelError(errors)) => {
-                    pretty_print_errors(errors, &datamodel);
+                    pretty_print_errors(errors, &files);
                     std::process::exit(1);
                 }
                 Err(e) => {
@@ -171,6 +171,7 @@ fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField)
         span: new_span(),
         directives: Vec::new(),
         default_value: None,
+        is_commented_out: false,
     };
     model.fields.push(field);
 
@@ -192,6 +193,8 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         span: new_span(),
         fields: vec![],
         directives: vec![],
+        is_view: false,
+        is_composite_type: step.is_composite_type,
     };
 
     datamodel.tops.push(ast::Top::Model(model));
@@ -479,6 +482,7 @@ fn apply_create_type_alias(
         arity: step.arity.into(),
         directives: vec![],
         field_type: new_ident(step.r#type.clone()),
+        is_commented_out: false,
     };
 
     datamodel.tops.push(ast::Top::Type(type_alias));
@@ -224,6 +224,7 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
         let create_model_step = steps::CreateModel {
             model: created_model.name.name.clone(),
+            is_composite_type: created_model.is_composite_type,
         };
 
         steps.push(MigrationStep::CreateModel(create_model_step));
@@ -193,6 +193,7 @@ fn creating_a_model_that_already_exists_must_error() {
 
     let steps = &[MigrationStep::CreateModel(CreateModel {
         model: "Test".to_string(),
+        is_composite_type: false,
     })];
 
     assert_eq!(
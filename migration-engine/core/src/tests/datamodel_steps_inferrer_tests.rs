@@ -20,6 +20,7 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
     let expected = &[
         MigrationStep::CreateModel(CreateModel {
             model: "Test".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -1333,6 +1334,7 @@ fn infer_CreateTypeAlias() {
         }),
         MigrationStep::CreateModel(CreateModel {
             model: "User".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "User".to_string(),
@@ -1,5 +1,5 @@
 use super::test_harness::*;
-use migration_connector::MigrationWarning;
+use migration_connector::{MigrationError, MigrationWarning};
 use pretty_assertions::assert_eq;
 use quaint::ast::*;
 
@@ -238,6 +238,169 @@ async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) {
     );
 }
 
+#[test_each_connector]
+async fn making_an_optional_column_required_with_null_values_should_error(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Int?
+        }
+    "#;
+
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    let insert = Insert::multi_into((api.schema_name(), "Test"), vec!["id"])
+        .values(vec!["a"])
+        .values(vec!["b"]);
+
+    api.database().execute(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Int
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm2).await;
+
+    // The schema should not change because the migration should not run if there are errors.
+    assert_eq!(original_database_schema, result.sql_schema);
+
+    assert_eq!(
+        result.migration_output.errors,
+        &[MigrationError {
+            tpe: "NullValuesInRequiredColumn".to_owned(),
+            description:
+                "You are about to make the column `age` on the `Test` table required, but it contains 2 null values."
+                    .to_owned(),
+            field: Some("age".to_owned()),
+        }]
+    );
+}
+
+#[test_each_connector]
+async fn narrowing_the_type_of_a_column_with_non_null_values_should_warn(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Float?
+        }
+    "#;
+
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    let insert = Insert::multi_into((api.schema_name(), "Test"), vec!["id", "age"])
+        .values(("a", 12.5))
+        .values(("b", 22.5));
+
+    api.database().execute(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Int?
+        }
+    "#;
+
+    let InferAndApplyOutput {
+        migration_output,
+        sql_schema: final_database_schema,
+    } = api.infer_and_apply(dm2).await;
+
+    // Warnings prevent the migration from being applied without the force flag.
+    assert_eq!(original_database_schema, final_database_schema);
+
+    assert_eq!(
+        migration_output.warnings,
+        &[MigrationWarning {
+            description: "You are about to change the type of the column `age` on the `Test` table from float to int, but it contains 2 non-null values that may not be convertible.".to_owned(),
+        }]
+    );
+}
+
+#[test_each_connector]
+async fn adding_a_unique_constraint_on_a_column_with_duplicate_values_should_error(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            name String
+        }
+    "#;
+
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    let insert = Insert::multi_into((api.schema_name(), "Test"), vec!["id", "name"])
+        .values(("a", "Max"))
+        .values(("b", "Max"))
+        .values(("c", "Moritz"));
+
+    api.database().execute(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            name String @unique
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm2).await;
+
+    // The schema should not change because the migration should not run if there are errors.
+    assert_eq!(original_database_schema, result.sql_schema);
+
+    assert_eq!(
+        result.migration_output.errors,
+        &[MigrationError {
+            tpe: "DuplicateValuesInUniqueIndex".to_owned(),
+            description: "You are about to add a unique constraint on the columns (name) of the `Test` table, but there are 1 duplicate values.".to_owned(),
+            field: Some("name".to_owned()),
+        }]
+    );
+}
+
+// MySQL only enforces check constraints from 8.0.16 on.
+#[test_each_connector(ignore = "mysql")]
+async fn adding_a_check_constraint_violated_by_existing_rows_should_error(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            price Int
+        }
+    "#;
+
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    let insert = Insert::multi_into((api.schema_name(), "Test"), vec!["id", "price"])
+        .values(("a", -1))
+        .values(("b", 10));
+
+    api.database().execute(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            price Int
+
+            @@check("price >= 0", name: "price_is_positive")
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm2).await;
+
+    // The schema should not change because the migration should not run if there are errors.
+    assert_eq!(original_database_schema, result.sql_schema);
+
+    assert_eq!(
+        result.migration_output.errors,
+        &[MigrationError {
+            tpe: "ExistingRowsViolateCheck".to_owned(),
+            description: "You are about to add the check constraint `price >= 0` to the `Test` table, but 1 existing rows violate it.".to_owned(),
+            field: Some("price_is_positive".to_owned()),
+        }]
+    );
+}
+
 #[test_each_connector]
 async fn dropping_a_table_referenced_by_foreign_keys_must_work(api: &TestApi) {
     use quaint::ast::*;
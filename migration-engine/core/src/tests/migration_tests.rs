@@ -257,6 +257,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -285,6 +286,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -340,6 +342,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -402,6 +405,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -443,6 +447,7 @@ async fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_t
                 },
                 columns: vec![aColumn.name.clone()],
                 referenced_table: "A".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             },
@@ -454,6 +459,7 @@ async fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_t
                 },
                 columns: vec![bColumn.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             },
@@ -494,6 +500,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![aColumn.name.clone()],
                 referenced_table: "A".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             },
@@ -505,6 +512,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![bColumn.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             }
@@ -512,6 +520,43 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
     );
 }
 
+#[test_each_connector]
+async fn adding_a_many_to_many_relation_with_custom_join_table_must_work(api: &TestApi) {
+    let dm1 = r#"
+            model User {
+                id Int @id
+                roles Role[] @relation("user_roles", table: "user_roles", column: "user_id")
+            }
+            model Role {
+                id Int @id
+                users User[] @relation("user_roles", table: "user_roles", column: "role_id")
+            }
+        "#;
+
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let relation_table = result.table_bang("user_roles");
+    assert_eq!(relation_table.columns.len(), 2);
+    assert_eq!(relation_table.column_bang("user_id").tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(relation_table.column_bang("role_id").tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(
+        relation_table.primary_key.as_ref().map(|pk| pk.columns.clone()),
+        Some(vec!["role_id".to_string(), "user_id".to_string()])
+    );
+    assert!(relation_table.indices.is_empty());
+
+    let mut referenced_tables: Vec<(&str, &str)> = relation_table
+        .foreign_keys
+        .iter()
+        .map(|fk| (fk.columns[0].as_str(), fk.referenced_table.as_str()))
+        .collect();
+    referenced_tables.sort();
+    assert_eq!(referenced_tables, vec![("role_id", "Role"), ("user_id", "User")]);
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm1).await;
+    assert!(output.sql_migration().is_empty());
+}
+
 #[test]
 #[ignore]
 fn adding_a_many_to_many_relation_for_exotic_id_types_must_work() {
@@ -580,6 +625,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Restrict, // required relations can't set ON DELETE SET NULL
             },
@@ -591,6 +637,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
             }
@@ -624,6 +671,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -656,6 +704,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -716,6 +765,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) {
             },
             columns: vec!["b".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -743,6 +793,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) {
             },
             columns: vec!["a".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -1066,6 +1117,7 @@ async fn index_renaming_must_work(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            namespace: None,
             index_new_name: "customNameA".into(),
             index_name: "customName".into(),
         })];
@@ -1117,6 +1169,7 @@ async fn index_renaming_must_work_when_renaming_to_default(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            namespace: None,
             index_new_name: "A.field_secondField".into(),
             index_name: "customName".into(),
         })];
@@ -1168,6 +1221,7 @@ async fn index_renaming_must_work_when_renaming_to_custom(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            namespace: None,
             index_name: "A.field_secondField".into(),
             index_new_name: "somethingCustom".into(),
         })];
@@ -1219,14 +1273,20 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
         let expected_steps = vec![
             SqlMigrationStep::DropIndex(DropIndex {
                 table: "A".into(),
+                namespace: None,
                 name: "customName".into(),
             }),
             SqlMigrationStep::CreateIndex(CreateIndex {
                 table: "A".into(),
+                namespace: None,
                 index: Index {
                     name: "customNameA".into(),
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
+                    sort_orders: vec![],
+                    expression: None,
+                    predicate: None,
+                    algorithm: None,
                 },
             }),
         ];
@@ -1290,6 +1350,107 @@ async fn adding_a_scalar_list_for_a_modelwith_id_type_int_must_work(api: &TestAp
     assert_eq!(enum_column.tpe.arity, ColumnArity::List);
 }
 
+#[test_one_connector(connector = "postgres")]
+async fn models_in_other_schemas_and_cross_schema_foreign_keys_must_work(api: &TestApi) {
+    let billing_schema = "cross_schema_fks_billing";
+    api.database()
+        .query_raw(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", billing_schema), &[])
+        .await
+        .unwrap();
+
+    let dm = format!(
+        r#"
+            datasource pg {{
+                provider = "postgres"
+                url = "postgres://localhost:5432"
+                schemas = ["{billing}"]
+            }}
+
+            model User {{
+                id       Int       @id
+                invoices Invoice[]
+            }}
+
+            model Invoice {{
+                id   Int  @id
+                user User
+
+                @@schema("{billing}")
+            }}
+        "#,
+        billing = billing_schema
+    );
+    api.infer_and_apply(&dm).await;
+
+    let result = api
+        .describer()
+        .describe_with_namespaces(api.schema_name(), &[billing_schema.to_string()])
+        .await
+        .unwrap();
+
+    let user_table = result.table_bang("User");
+    assert_eq!(user_table.namespace, None);
+
+    let invoice_table = result.table_bang("Invoice");
+    assert_eq!(invoice_table.namespace, Some(billing_schema.to_string()));
+    assert_eq!(invoice_table.foreign_keys.len(), 1);
+    assert_eq!(invoice_table.foreign_keys[0].referenced_table, "User");
+    assert_eq!(invoice_table.foreign_keys[0].referenced_schema, None);
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(&dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn tables_with_the_same_name_in_different_schemas_must_work(api: &TestApi) {
+    let archive_schema = "same_table_names_archive";
+    api.database()
+        .query_raw(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", archive_schema), &[])
+        .await
+        .unwrap();
+
+    let dm = format!(
+        r#"
+            datasource pg {{
+                provider = "postgres"
+                url = "postgres://localhost:5432"
+                schemas = ["{archive}"]
+            }}
+
+            model Invoice {{
+                id     Int @id
+                amount Int
+            }}
+
+            model ArchivedInvoice {{
+                id Int @id
+
+                @@map("Invoice")
+                @@schema("{archive}")
+            }}
+        "#,
+        archive = archive_schema
+    );
+    api.infer_and_apply(&dm).await;
+
+    let result = api
+        .describer()
+        .describe_with_namespaces(api.schema_name(), &[archive_schema.to_string()])
+        .await
+        .unwrap();
+
+    let invoice_table = result.get_table_in_namespace(None, "Invoice").unwrap();
+    assert!(invoice_table.column("amount").is_some());
+
+    let archived_invoice_table = result.get_table_in_namespace(Some(archive_schema), "Invoice").unwrap();
+    assert!(archived_invoice_table.column("amount").is_none());
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(&dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
 #[test_each_connector]
 async fn reserved_sql_key_words_must_work(api: &TestApi) {
     // Group is a reserved keyword
@@ -1314,6 +1475,7 @@ async fn reserved_sql_key_words_must_work(api: &TestApi) {
             },
             columns: vec!["parent".to_string()],
             referenced_table: "Group".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::SetNull,
         }]
@@ -1474,6 +1636,147 @@ async fn model_with_multiple_indexes_works(api: &TestApi) {
     assert_eq!(like_indexes_count, expected_indexes_count);
 }
 
+#[test_each_connector(ignore = "mysql")]
+async fn partial_and_sorted_indexes_must_be_migrated_and_not_recreated(api: &TestApi) {
+    let dm = r#"
+        model User {
+            id         Int       @id
+            email      String
+            name       String
+            deleted_at DateTime?
+
+            @@unique([email], name: "active_email", where: "deleted_at IS NULL")
+            @@index([name, email], sort: [DESC, ASC], name: "name_and_email")
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(dm).await.sql_schema;
+    let table = sql_schema.table_bang("User");
+
+    let active_email = table.indices.iter().find(|index| index.name == "active_email").unwrap();
+    assert_eq!(active_email.columns, vec!["email".to_string()]);
+    assert_eq!(active_email.tpe, IndexType::Unique);
+    assert!(active_email.predicate.is_some());
+
+    let name_and_email = table
+        .indices
+        .iter()
+        .find(|index| index.name == "name_and_email")
+        .unwrap();
+    assert_eq!(name_and_email.columns, vec!["name".to_string(), "email".to_string()]);
+    assert_eq!(name_and_email.sort_orders, vec![SortOrder::Desc, SortOrder::Asc]);
+
+    // Applying the same datamodel again must not recreate the indexes.
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "mysql")]
+async fn changing_the_condition_of_a_partial_index_must_recreate_it(api: &TestApi) {
+    let dm1 = r#"
+        model User {
+            id         Int       @id
+            email      String
+            deleted_at DateTime?
+
+            @@unique([email], name: "active_email", where: "deleted_at IS NULL")
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let dm2 = r#"
+        model User {
+            id         Int       @id
+            email      String
+            deleted_at DateTime?
+
+            @@unique([email], name: "active_email", where: "deleted_at IS NOT NULL")
+        }
+    "#;
+
+    let output = api.infer_and_apply(dm2).await;
+    let steps = output.sql_migration();
+    assert!(steps.iter().any(|step| match step {
+        SqlMigrationStep::DropIndex(DropIndex { name, .. }) => name == "active_email",
+        _ => false,
+    }));
+    assert!(steps.iter().any(|step| match step {
+        SqlMigrationStep::CreateIndex(CreateIndex { index, .. }) => index.name == "active_email",
+        _ => false,
+    }));
+
+    // Applying the same datamodel again must not recreate the index.
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn native_types_must_be_migrated_to_their_exact_database_type(api: &TestApi) {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:5432"
+        }
+
+        model Blog {
+            id        Int      @id @db.BigInt
+            title     String   @db.VarChar(255)
+            price     Float    @db.Decimal(10, 2)
+            createdAt DateTime @db.Timestamptz
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(dm).await.sql_schema;
+    let table = sql_schema.table_bang("Blog");
+
+    assert_eq!(table.column_bang("id").tpe.raw, "int8");
+    assert_eq!(table.column_bang("title").tpe.raw, "varchar");
+    assert_eq!(table.column_bang("price").tpe.raw, "numeric");
+    assert_eq!(table.column_bang("createdAt").tpe.raw, "timestamptz");
+
+    // Applying the same datamodel again must not alter the columns.
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn changing_the_native_type_of_a_field_must_alter_the_column(api: &TestApi) {
+    let dm1 = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:5432"
+        }
+
+        model Blog {
+            id    Int @id
+            views Int
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(dm1).await.sql_schema;
+    assert_eq!(sql_schema.table_bang("Blog").column_bang("views").tpe.raw, "int4");
+
+    let dm2 = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:5432"
+        }
+
+        model Blog {
+            id    Int @id
+            views Int @db.BigInt
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(dm2).await.sql_schema;
+    assert_eq!(sql_schema.table_bang("Blog").column_bang("views").tpe.raw, "int8");
+
+    // Applying the same datamodel again must not alter the column.
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
 #[test_each_connector]
 async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(api: &TestApi) {
     let dm = r#"
@@ -1496,6 +1799,10 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat".into(),
         columns: vec!["cat".into()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expression: None,
+        predicate: None,
+        algorithm: None,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -1745,3 +2052,353 @@ async fn renaming_a_datasource_works(api: &TestApi) -> Result<(), anyhow::Error>
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn views_with_a_definition_must_be_created_and_dropped(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id Int @id
+        }
+
+        view Constant {
+            id Int @id
+
+            @@definition("SELECT 1 AS id")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    assert!(result.get_view("Constant").is_some());
+    assert!(result.get_table("Constant").is_none());
+
+    let dm2 = r#"
+        model Test {
+            id Int @id
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    assert!(result.get_view("Constant").is_none());
+}
+
+#[test_each_connector]
+async fn changing_the_definition_of_a_view_must_recreate_it(api: &TestApi) {
+    let dm1 = r#"
+        view Constant {
+            id Int @id
+
+            @@definition("SELECT 1 AS id")
+        }
+    "#;
+    api.infer_and_apply(dm1).await;
+
+    let dm2 = r#"
+        view Constant {
+            id Int @id
+
+            @@definition("SELECT 2 AS id")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let definition = result.get_view("Constant").and_then(|view| view.definition.clone());
+    assert!(definition.expect("view definition").contains('2'));
+
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn views_over_tables_must_not_be_recreated_when_unchanged(api: &TestApi) {
+    let dm1 = r#"
+        model User {
+            id   Int    @id
+            name String
+
+            @@map("users")
+        }
+
+        view UserName {
+            id   Int    @id
+            name String
+
+            @@definition("SELECT id, name FROM users")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    assert!(result.get_view("UserName").is_some());
+
+    // The database stores a rewritten definition, which must not count as a change.
+    let output = api.infer_and_apply(dm1).await;
+    assert!(output.sql_migration().is_empty());
+
+    let dm2 = r#"
+        model User {
+            id   Int    @id
+            name String
+
+            @@map("users")
+        }
+
+        view UserName {
+            id   Int    @id
+            name String
+
+            @@definition("SELECT id, name FROM users WHERE id > 10")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let definition = result.get_view("UserName").and_then(|view| view.definition.clone());
+    assert!(definition.expect("view definition").contains("10"));
+
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn composite_type_fields_must_become_columns_of_the_using_table(api: &TestApi) {
+    let dm = r#"
+        type Address {
+            street String
+            zip String?
+        }
+
+        model User {
+            id Int @id
+            address Address?
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    assert!(result.get_table("Address").is_none());
+
+    let column = result.table_bang("User").column_bang("address");
+    assert_eq!(column.tpe.arity, ColumnArity::Nullable);
+}
+
+// MySQL only enforces check constraints from 8.0.16 on.
+#[test_each_connector(ignore = "mysql")]
+async fn check_constraints_must_be_created_and_dropped(api: &TestApi) {
+    let dm1 = r#"
+        model Product {
+            id Int @id
+            price Float
+
+            @@check("price >= 0", name: "price_is_positive")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let checks = &result.table_bang("Product").checks;
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].name.as_deref(), Some("price_is_positive"));
+
+    let dm2 = r#"
+        model Product {
+            id Int @id
+            price Float
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    assert!(result.table_bang("Product").checks.is_empty());
+}
+
+// MySQL only enforces check constraints from 8.0.16 on.
+#[test_each_connector(ignore = "mysql")]
+async fn changing_the_expression_of_a_check_constraint_must_recreate_it(api: &TestApi) {
+    let dm1 = r#"
+        model Product {
+            id Int @id
+            price Float
+
+            @@check("price >= 0", name: "price_is_positive")
+            @@check("price < 1000")
+        }
+    "#;
+    api.infer_and_apply(dm1).await;
+
+    let output = api.infer_and_apply(dm1).await;
+    assert!(output.sql_migration().is_empty());
+
+    let dm2 = r#"
+        model Product {
+            id Int @id
+            price Float
+
+            @@check("price > 0", name: "price_is_positive")
+            @@check("price < 1000")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let checks = &result.table_bang("Product").checks;
+    assert_eq!(checks.len(), 2);
+    let check = checks
+        .iter()
+        .find(|check| check.name.as_deref() == Some("price_is_positive"))
+        .expect("price_is_positive check");
+    assert!(!check.expression.contains(">="));
+
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+// MySQL 5.7 parses check constraints and throws them away, so they must not be migrated.
+#[test_one_connector(connector = "mysql")]
+async fn check_constraints_must_not_be_migrated_on_mysql_5_7(api: &TestApi) {
+    let dm = r#"
+        model Product {
+            id Int @id
+            price Float
+
+            @@check("price >= 0", name: "price_is_positive")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    assert!(result.table_bang("Product").checks.is_empty());
+
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn documentation_comments_must_be_written_as_database_comments(api: &TestApi) {
+    let dm1 = r#"
+        /// All our users.
+        model User {
+            id Int @id
+            /// The full name.
+            name String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let table = result.table_bang("User");
+    assert_eq!(table.comment.as_deref(), Some("All our users."));
+    assert_eq!(table.column_bang("name").comment.as_deref(), Some("The full name."));
+    assert_eq!(table.column_bang("id").comment, None);
+
+    let dm2 = r#"
+        model User {
+            id Int @id
+            /// The user's full name.
+            name String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let table = result.table_bang("User");
+    assert_eq!(table.comment, None);
+    assert_eq!(
+        table.column_bang("name").comment.as_deref(),
+        Some("The user's full name.")
+    );
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn changing_a_column_comment_must_keep_the_column_default(api: &TestApi) {
+    let dm1 = r#"
+        model User {
+            id Int @id
+            /// The role.
+            role String @default("member")
+        }
+    "#;
+    api.infer_and_apply(dm1).await;
+
+    let dm2 = r#"
+        model User {
+            id Int @id
+            /// The role of the user.
+            role String @default("member")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let role = result.table_bang("User").column_bang("role");
+    assert_eq!(role.comment.as_deref(), Some("The role of the user."));
+    assert_eq!(role.default.as_deref(), Some("member"));
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn db_generated_defaults_must_be_migrated_as_raw_sql(api: &TestApi) {
+    let dm = r#"
+        model User {
+            id    String @id @default(dbgenerated("md5(random()::text)"))
+            level Int    @default(dbgenerated("1 + 1"))
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let table = result.table_bang("User");
+    assert!(table.column_bang("id").default.as_deref().unwrap().contains("md5"));
+    assert!(table.column_bang("level").default.is_some());
+
+    // The defaults are computed by the database.
+    api.database()
+        .query_raw(
+            &format!("INSERT INTO \"{}\".\"User\" DEFAULT VALUES", api.schema_name()),
+            &[],
+        )
+        .await
+        .unwrap();
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn now_defaults_must_be_migrated_as_the_current_timestamp(api: &TestApi) {
+    let dm = r#"
+        model User {
+            id        Int      @id
+            createdAt DateTime @default(now())
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let default = result.table_bang("User").column_bang("createdAt").default.clone();
+    assert!(default.unwrap().to_uppercase().contains("CURRENT_TIMESTAMP"));
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn enum_fields_must_be_migrated_to_database_enums(api: &TestApi) {
+    let dm = r#"
+        model User {
+            id Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            happy
+            sad
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let table = result.table_bang("User");
+
+    let enum_name = match api.sql_family() {
+        SqlFamily::Mysql => "User_mood",
+        _ => "Mood",
+    };
+    assert_eq!(
+        table.column_bang("mood").tpe.family,
+        ColumnTypeFamily::Enum(enum_name.to_string())
+    );
+    let values: Vec<&str> = result
+        .get_enum(enum_name)
+        .expect("get enum")
+        .values
+        .iter()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(values, vec!["happy", "sad"]);
+
+    // Applying the same datamodel again must not produce any changes.
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
@@ -194,7 +194,7 @@ impl TestApi {
         }
     }
 
-    fn describer(&self) -> Box<dyn SqlSchemaDescriberBackend> {
+    pub fn describer(&self) -> Box<dyn SqlSchemaDescriberBackend> {
         match self.api.connector_type() {
             "postgresql" => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::clone(
                 &self.database,
@@ -1,8 +1,10 @@
 use datamodel;
 use datamodel::error::DatamodelError;
+use datamodel::lint::{LintWarning, Severity};
 use std::{
     fs,
     io::{self, Read},
+    path::Path,
 };
 
 use clap::{App, Arg};
@@ -14,27 +16,28 @@ struct MiniError {
     start: usize,
     end: usize,
     text: String,
+    severity: Severity,
 }
 
 fn main() {
     let matches = App::new("Prisma Datamodel v2 formatter")
         .version("0.2")
         .author("Emanuel Jöbstl <emanuel.joebstl@gmail.com>")
-        .about("Formats or lints a datamodel v2 file and prints the result to standard output.")
+        .about("Formats, fixes, lints or draws a diagram of a datamodel v2 file and prints the result to standard output.")
         .arg(
             Arg::with_name("input")
                 .short("i")
                 .long("input")
                 .value_name("INPUT_FILE")
                 .required(false)
-                .help("Specifies the input file to use. If none is given, the input is read from stdin."),
+                .help("Specifies the input file to use. If none is given, the input is read from stdin. If a directory is given, all datamodel files in it are formatted in place."),
         )
         .arg(
             Arg::with_name("lint")
                 .short("l")
                 .long("lint")
                 .required(false)
-                .help("Specifies linter mode."),
+                .help("Specifies linter mode. Validation errors and the warnings of the rules configured in `linter` blocks are printed as JSON."),
         )
         .arg(
             Arg::with_name("no_env_errors")
@@ -58,6 +61,29 @@ fn main() {
                 .required(false)
                 .help("Specifies wich tab width to use when formaitting. Default is 2."),
         )
+        .arg(
+            Arg::with_name("canonical")
+                .short("c")
+                .long("canonical")
+                .required(false)
+                .help("Sorts and groups block directives, for a stable, diff friendly formatting."),
+        )
+        .arg(
+            Arg::with_name("fix")
+                .short("f")
+                .long("fix")
+                .required(false)
+                .help("Adds missing opposite relation fields, relation names and foreign key fields before formatting. Only supports single files."),
+        )
+        .arg(
+            Arg::with_name("diagram")
+                .short("d")
+                .long("diagram")
+                .value_name("FORMAT")
+                .possible_values(&["dot", "mermaid"])
+                .required(false)
+                .help("Prints an entity relationship diagram of the datamodel instead of formatting it, as Graphviz DOT or Mermaid."),
+        )
         .get_matches();
 
     if matches.is_present("lint") {
@@ -68,24 +94,73 @@ fn main() {
             .read_to_string(&mut datamodel_string)
             .expect("Unable to read from stdin.");
 
-        if let Err(err) = datamodel::parse_datamodel(&datamodel_string) {
-            let errs: Vec<MiniError> = err
+        let to_mini_error = |err: &DatamodelError| MiniError {
+            start: err.span().start,
+            end: err.span().end,
+            text: format!("{}", err),
+            severity: Severity::Error,
+        };
+
+        let mut errs: Vec<MiniError> = match datamodel::parse_datamodel(&datamodel_string) {
+            Err(err) => err
                 .errors
                 .iter()
                 .filter(|err: &&DatamodelError| match err {
                     DatamodelError::EnvironmentFunctionalEvaluationError { var_name: _, span: _ } => !skip_env_errors,
                     _ => true,
                 })
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
-            let json = serde_json::to_string(&errs).expect("Failed to render JSON");
-            print!("{}", json)
-        } else {
-            print!("[]");
+                .map(to_mini_error)
+                .collect(),
+            Ok(_) => Vec::new(),
+        };
+
+        // Lint warnings are only meaningful for otherwise valid datamodels. Errors in the `linter`
+        // blocks are not validation errors, so they only show up here.
+        if errs.is_empty() {
+            match datamodel::lint_datamodel(&datamodel_string) {
+                Ok(warnings) => errs.extend(warnings.iter().map(|warning: &LintWarning| MiniError {
+                    start: warning.span.start,
+                    end: warning.span.end,
+                    text: warning.message.clone(),
+                    severity: warning.severity,
+                })),
+                Err(err) => errs.extend(err.errors.iter().map(to_mini_error)),
+            }
+        }
+
+        let json = serde_json::to_string(&errs).expect("Failed to render JSON");
+        print!("{}", json);
+
+        std::process::exit(0);
+    } else if let Some(format) = matches.value_of("diagram") {
+        // Diagram
+        let datamodel = match matches.value_of("input") {
+            Some(directory) if Path::new(directory).is_dir() => {
+                let files = datamodel::ast::SourceMap::load(Path::new(directory))
+                    .expect(&format!("Unable to read directory {}", directory));
+                datamodel::parse_datamodel_files_or_pretty_error(&files)
+            }
+            Some(file_name) => {
+                let datamodel_string =
+                    fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name));
+                datamodel::parse_datamodel_or_pretty_error(&datamodel_string, file_name)
+            }
+            None => {
+                let mut datamodel_string = String::new();
+                io::stdin()
+                    .read_to_string(&mut datamodel_string)
+                    .expect("Unable to read from stdin.");
+                datamodel::parse_datamodel_or_pretty_error(&datamodel_string, "stdin")
+            }
+        };
+
+        match datamodel {
+            Ok(datamodel) if format == "dot" => print!("{}", datamodel::diagram::render_to_dot(&datamodel)),
+            Ok(datamodel) => print!("{}", datamodel::diagram::render_to_mermaid(&datamodel)),
+            Err(errors) => {
+                eprintln!("{}", errors);
+                std::process::exit(1);
+            }
         }
 
         std::process::exit(0);
@@ -97,6 +172,18 @@ fn main() {
             .unwrap_or("2")
             .parse::<usize>()
             .expect("Error while parsing tab width.");
+        let canonical = matches.is_present("canonical");
+        let fix = matches.is_present("fix");
+
+        if let Some(directory) = file_name.map(Path::new).filter(|path| path.is_dir()) {
+            if fix {
+                eprintln!("Fix mode only supports single files.");
+                std::process::exit(1);
+            }
+
+            format_directory(directory, tab_width, canonical);
+            std::process::exit(0);
+        }
 
         // TODO: This is really ugly, clean it up.
         let datamodel_string: String = if let Some(file_name) = file_name {
@@ -109,19 +196,67 @@ fn main() {
             buf
         };
 
+        let print_errors = |errors: datamodel::error::ErrorCollection, datamodel_string: &str| {
+            for error in errors.to_iter() {
+                error
+                    .pretty_print(&mut io::stderr().lock(), file_name.unwrap_or("stdin"), datamodel_string)
+                    .expect("Failed to write errors to stderr");
+            }
+        };
+
+        let datamodel_string = if fix {
+            match datamodel::fix_datamodel(&datamodel_string) {
+                // Not everything can be fixed, so the fixed datamodel is only written if it is valid.
+                Ok(fixed) => match datamodel::parse_datamodel(&fixed) {
+                    Ok(_) => fixed,
+                    Err(errors) => {
+                        print_errors(errors, &fixed);
+                        std::process::exit(1);
+                    }
+                },
+                Err(errors) => {
+                    print_errors(errors, &datamodel_string);
+                    std::process::exit(1);
+                }
+            }
+        } else {
+            datamodel_string
+        };
+
         let file_name = matches.value_of("output");
 
         if let Some(file_name) = file_name {
-            let file = std::fs::File::open(file_name).expect(&format!("Unable to open file {}", file_name));
-            let mut stream = std::io::BufWriter::new(file);
-            datamodel::ast::reformat::Reformatter::reformat_to(&datamodel_string, &mut stream, tab_width);
+            let mut formatted = Vec::new();
+            reformat(&datamodel_string, &mut formatted, tab_width, canonical);
+
+            if let Err(err) = fs::write(file_name, formatted) {
+                eprintln!("Unable to write file {}: {}", file_name, err);
+                std::process::exit(1);
+            }
         } else {
-            datamodel::ast::reformat::Reformatter::reformat_to(
-                &datamodel_string,
-                &mut std::io::stdout().lock(),
-                tab_width,
-            );
+            reformat(&datamodel_string, &mut std::io::stdout().lock(), tab_width, canonical);
         }
         std::process::exit(0);
     }
 }
+
+/// Formats each file of a datamodel split across several files, in place.
+fn format_directory(directory: &Path, tab_width: usize, canonical: bool) {
+    let files =
+        datamodel::ast::SourceMap::load(directory).expect(&format!("Unable to read directory {}", directory.display()));
+
+    for file in files.files() {
+        let mut formatted = Vec::new();
+        reformat(&file.text, &mut formatted, tab_width, canonical);
+
+        fs::write(&file.name, formatted).expect(&format!("Unable to write file {}", file.name));
+    }
+}
+
+fn reformat(input: &str, output: &mut dyn std::io::Write, tab_width: usize, canonical: bool) {
+    if canonical {
+        datamodel::ast::reformat::Reformatter::reformat_canonical_to(input, output, tab_width);
+    } else {
+        datamodel::ast::reformat::Reformatter::reformat_to(input, output, tab_width);
+    }
+}
@@ -0,0 +1,154 @@
+use datamodel::ast::{self, Span, WithIdentifier, WithSpan};
+
+/// A name in the datamodel the user points at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Symbol<'a> {
+    /// The name in the declaration of a model, enum or type alias.
+    Top(&'a ast::Top),
+    /// The name in the declaration of a field.
+    Field(&'a ast::Model, &'a ast::Field),
+    /// The type of a field or type alias. Refers to a model, enum, type alias or scalar type.
+    TypeReference(&'a ast::Identifier),
+    /// A field named in a directive argument, like `@@index([email])`.
+    FieldReference(FieldReference<'a>),
+}
+
+/// A field named in a directive argument.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldReference<'a> {
+    /// The name of the model the referenced field belongs to.
+    pub model: &'a str,
+    /// The name of the referenced field.
+    pub field: &'a str,
+    pub span: Span,
+}
+
+/// Finds the symbol at the given byte offset.
+pub fn symbol_at(ast: &ast::SchemaAst, offset: usize) -> Option<Symbol<'_>> {
+    for top in ast.tops.iter().filter(|top| contains(*top.span(), offset)) {
+        if contains(top.identifier().span, offset) {
+            return Some(Symbol::Top(top));
+        }
+
+        match top {
+            ast::Top::Model(model) => {
+                for field in &model.fields {
+                    if contains(field.name.span, offset) {
+                        return Some(Symbol::Field(model, field));
+                    }
+
+                    if contains(type_name_span(&field.field_type), offset) {
+                        return Some(Symbol::TypeReference(&field.field_type));
+                    }
+                }
+            }
+            ast::Top::Type(type_alias) if contains(type_name_span(&type_alias.field_type), offset) => {
+                return Some(Symbol::TypeReference(&type_alias.field_type));
+            }
+            _ => (),
+        }
+    }
+
+    field_references(ast)
+        .into_iter()
+        .find(|reference| contains(reference.span, offset))
+        .map(Symbol::FieldReference)
+}
+
+/// The span of the declaration the symbol refers to. Scalar types have no declaration.
+pub fn definition_span(ast: &ast::SchemaAst, symbol: Symbol<'_>) -> Option<Span> {
+    match symbol {
+        Symbol::Top(top) => Some(top.identifier().span),
+        Symbol::Field(_, field) => Some(field.name.span),
+        Symbol::TypeReference(type_name) => find_type(ast, &type_name.name).map(|top| top.identifier().span),
+        Symbol::FieldReference(reference) => ast
+            .find_field(reference.model, reference.field)
+            .map(|field| field.name.span),
+    }
+}
+
+/// Finds the model, enum or type alias of the given name.
+pub fn find_type<'a>(ast: &'a ast::SchemaAst, name: &str) -> Option<&'a ast::Top> {
+    ast.tops.iter().find(|top| match top {
+        ast::Top::Model(_) | ast::Top::Enum(_) | ast::Top::Type(_) => top.name() == name,
+        _ => false,
+    })
+}
+
+/// All types of fields and type aliases.
+pub fn type_references(ast: &ast::SchemaAst) -> Vec<&ast::Identifier> {
+    let field_types = ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .map(|field| &field.field_type);
+    let aliased_types = ast.types().into_iter().map(|type_alias| &type_alias.field_type);
+
+    field_types.chain(aliased_types).collect()
+}
+
+/// All fields named in the arguments of `@@id`, `@@unique`, `@@index` and `@relation`.
+pub fn field_references(ast: &ast::SchemaAst) -> Vec<FieldReference<'_>> {
+    let mut references = Vec::new();
+
+    for model in ast.models() {
+        for directive in &model.directives {
+            if let "id" | "unique" | "index" = directive.name.name.as_str() {
+                for argument in directive.arguments.iter().filter(|arg| is_argument(arg, "fields")) {
+                    references.extend(constant_values(&argument.value).into_iter().map(|(field, span)| {
+                        FieldReference {
+                            model: &model.name.name,
+                            field,
+                            span,
+                        }
+                    }));
+                }
+            }
+        }
+
+        for field in &model.fields {
+            for directive in field.directives.iter().filter(|dir| dir.name.name == "relation") {
+                for argument in &directive.arguments {
+                    // `fields` are on this model, `references` on the related one.
+                    let referenced_model = match argument.name.name.as_str() {
+                        "fields" => &model.name.name,
+                        "references" => &field.field_type.name,
+                        _ => continue,
+                    };
+
+                    references.extend(constant_values(&argument.value).into_iter().map(|(field, span)| {
+                        FieldReference {
+                            model: referenced_model,
+                            field,
+                            span,
+                        }
+                    }));
+                }
+            }
+        }
+    }
+
+    references
+}
+
+/// The span of the type name, without the `?` or `[]` of the arity.
+pub fn type_name_span(field_type: &ast::Identifier) -> Span {
+    Span::new(field_type.span.start, field_type.span.start + field_type.name.len())
+}
+
+/// Whether the argument has the given name, or is the unnamed argument in its place.
+fn is_argument(argument: &ast::Argument, name: &str) -> bool {
+    argument.name.name == name || argument.name.name.is_empty()
+}
+
+fn constant_values(expression: &ast::Expression) -> Vec<(&str, Span)> {
+    match expression {
+        ast::Expression::ConstantValue(value, span) => vec![(value.as_str(), *span)],
+        ast::Expression::Array(values, _) => values.iter().flat_map(constant_values).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
@@ -0,0 +1,218 @@
+use crate::document::Document;
+use datamodel::ast;
+use lsp_types::{CompletionItem, CompletionItemKind, Position};
+
+const SCALAR_TYPES: &[&str] = &["String", "Boolean", "Int", "Float", "DateTime"];
+
+const TOP_LEVEL_KEYWORDS: &[&str] = &["model", "view", "enum", "type", "datasource", "generator", "linter"];
+
+/// Field directives, with their documentation.
+const FIELD_DIRECTIVES: &[(&str, &str)] = &[
+    ("id", "Defines the primary key."),
+    ("unique", "Defines a unique constraint."),
+    ("default", "Defines a default value."),
+    ("relation", "Defines the relation to another model."),
+    ("map", "Maps the field to a column of another name."),
+    (
+        "updatedAt",
+        "Sets the field to the current time whenever the record is updated.",
+    ),
+    ("sequence", "Generates the field's values from a sequence."),
+];
+
+/// Model directives, with their documentation.
+const MODEL_DIRECTIVES: &[(&str, &str)] = &[
+    ("id", "Defines a primary key over several fields."),
+    ("unique", "Defines a unique constraint over several fields."),
+    ("index", "Defines an index."),
+    ("map", "Maps the model to a table of another name."),
+    ("check", "Defines a check constraint."),
+    ("schema", "Places the model in another database schema."),
+    ("embedded", "Marks the model as embedded."),
+    ("definition", "Defines the model as a view over an SQL query."),
+];
+
+/// The named arguments of each directive.
+const DIRECTIVE_ARGUMENTS: &[(&str, &[&str])] = &[
+    (
+        "relation",
+        &["name", "fields", "references", "onDelete", "table", "column"],
+    ),
+    ("default", &["value"]),
+    ("map", &["name"]),
+    ("sequence", &["name", "allocationSize", "initialValue"]),
+    ("id", &["fields"]),
+    ("unique", &["fields", "name", "sort", "where", "type", "expression"]),
+    ("index", &["fields", "name", "sort", "where", "type", "expression"]),
+    ("check", &["expression", "name"]),
+    ("schema", &["name"]),
+    ("definition", &["sql"]),
+];
+
+/// Completes the word at the given position, depending on where it is written.
+pub fn completions(document: &Document, position: Position) -> Vec<CompletionItem> {
+    let prefix = document.line_prefix(position);
+    let declared_types = all_declared_types(document);
+
+    match enclosing_block(document, position) {
+        // Views and composite types have fields just like models.
+        Some("model") | Some("view") | Some("type") => {
+            if let Some(directive) = directive_at_argument_name(prefix) {
+                directive_arguments(directive)
+            } else if prefix.trim_end_matches(is_word_char).ends_with("@@") {
+                directives(MODEL_DIRECTIVES)
+            } else if prefix.trim_end_matches(is_word_char).ends_with('@') {
+                directives(FIELD_DIRECTIVES)
+            } else if is_second_word(prefix) {
+                types(&declared_types)
+            } else {
+                Vec::new()
+            }
+        }
+        Some(_) => Vec::new(),
+        None if is_first_word(prefix) => keywords(TOP_LEVEL_KEYWORDS),
+        None if is_aliased_type(prefix) => types(&declared_types),
+        None => Vec::new(),
+    }
+}
+
+/// The keyword of the block the position is in, like `model` or `enum`.
+fn enclosing_block(document: &Document, position: Position) -> Option<&str> {
+    let text = &document.text()[..document.offset_at(position)];
+    let mut block = None;
+
+    for line in text.lines().map(str::trim) {
+        if line.ends_with('{') {
+            block = line.split_whitespace().next();
+        } else if line.starts_with('}') {
+            block = None;
+        }
+    }
+
+    block
+}
+
+/// The name of the directive, if the prefix ends where the name of one of its arguments goes.
+fn directive_at_argument_name(prefix: &str) -> Option<&str> {
+    let open_paren = prefix.rfind('(')?;
+    let arguments = &prefix[open_paren..];
+    let is_in_list = arguments.matches('[').count() > arguments.matches(']').count();
+    let current_argument = arguments.rsplit(&['(', ','][..]).next().unwrap_or("");
+
+    if arguments.contains(')') || is_in_list || !current_argument.trim_start().chars().all(is_word_char) {
+        return None;
+    }
+
+    let directive = &prefix[..open_paren];
+    let name_start = directive.rfind('@')?;
+
+    Some(directive[name_start..].trim_start_matches('@'))
+}
+
+/// The types declared in the document, along with the ones of the last valid text that are
+/// missing because their declaration is malformed right now.
+fn all_declared_types(document: &Document) -> Vec<(String, CompletionItemKind)> {
+    let mut types = declared_types(document.ast());
+
+    for (name, kind) in document.last_valid_ast().map(declared_types).unwrap_or_default() {
+        if !types.iter().any(|(declared_name, _)| *declared_name == name) {
+            types.push((name, kind));
+        }
+    }
+
+    types
+}
+
+/// The models, enums and type aliases of the datamodel.
+fn declared_types(ast: &ast::SchemaAst) -> Vec<(String, CompletionItemKind)> {
+    ast.tops
+        .iter()
+        .filter_map(|top| match top {
+            ast::Top::Model(model) => Some((model.name.name.clone(), CompletionItemKind::Class)),
+            ast::Top::Enum(r#enum) => Some((r#enum.name.name.clone(), CompletionItemKind::Enum)),
+            ast::Top::Type(type_alias) => Some((type_alias.name.name.clone(), CompletionItemKind::TypeParameter)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn keywords(keywords: &[&str]) -> Vec<CompletionItem> {
+    keywords
+        .iter()
+        .map(|keyword| completion_item(keyword, CompletionItemKind::Keyword, None))
+        .collect()
+}
+
+fn directives(directives: &[(&str, &str)]) -> Vec<CompletionItem> {
+    directives
+        .iter()
+        .map(|(name, documentation)| completion_item(name, CompletionItemKind::Property, Some(documentation)))
+        .collect()
+}
+
+fn directive_arguments(directive: &str) -> Vec<CompletionItem> {
+    DIRECTIVE_ARGUMENTS
+        .iter()
+        .filter(|(name, _)| *name == directive)
+        .flat_map(|(_, arguments)| arguments.iter())
+        .map(|argument| CompletionItem {
+            insert_text: Some(format!("{}: ", argument)),
+            ..completion_item(argument, CompletionItemKind::Field, None)
+        })
+        .collect()
+}
+
+fn types(declared_types: &[(String, CompletionItemKind)]) -> Vec<CompletionItem> {
+    let scalar_types = SCALAR_TYPES
+        .iter()
+        .map(|name| completion_item(name, CompletionItemKind::TypeParameter, None));
+    let declared_types = declared_types
+        .iter()
+        .map(|(name, kind)| completion_item(name, *kind, None));
+
+    scalar_types.chain(declared_types).collect()
+}
+
+fn completion_item(label: &str, kind: CompletionItemKind, detail: Option<&str>) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail: detail.map(String::from),
+        ..CompletionItem::default()
+    }
+}
+
+/// Whether the prefix ends in the type of a type alias, like `type Email = Str`.
+fn is_aliased_type(prefix: &str) -> bool {
+    let mut parts = prefix.split('=');
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(declaration), Some(aliased_type), None) => {
+            declaration.trim_start().starts_with("type ") && aliased_type.trim_start().chars().all(is_word_char)
+        }
+        _ => false,
+    }
+}
+
+/// Whether the prefix only holds the start of the first word of the line.
+fn is_first_word(prefix: &str) -> bool {
+    prefix.trim_start().chars().all(is_word_char)
+}
+
+/// Whether the prefix holds one word and the start of a second one, like a field name and its type.
+fn is_second_word(prefix: &str) -> bool {
+    let mut words = prefix.split_whitespace();
+    let ends_in_whitespace = prefix.ends_with(char::is_whitespace);
+
+    match (words.next(), words.next(), words.next()) {
+        (Some(first), None, None) => ends_in_whitespace && first.chars().all(is_word_char),
+        (Some(first), Some(second), None) => {
+            !ends_in_whitespace && first.chars().all(is_word_char) && second.chars().all(is_word_char)
+        }
+        _ => false,
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,41 @@
+use crate::document::Document;
+use datamodel::ast::{SourceFile, SourceMap};
+use datamodel::error::DatamodelError;
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+
+/// Validates the document and reports all errors.
+///
+/// Missing environment variables are not reported, since the editor usually does not run
+/// in the environment the datamodel is used in.
+pub fn diagnostics(document: &Document) -> Vec<Diagnostic> {
+    diagnostics_in_files(document, &[])
+}
+
+/// Validates the document together with the other files of a datamodel split across several files,
+/// so types declared in those files resolve. Only the errors in the document itself are reported.
+pub fn diagnostics_in_files(document: &Document, other_files: &[String]) -> Vec<Diagnostic> {
+    let files = std::iter::once(document.text())
+        .chain(other_files.iter().map(String::as_str))
+        .enumerate()
+        .map(|(file_id, text)| SourceFile::new(&file_id.to_string(), text))
+        .collect();
+
+    let errors = match datamodel::parse_datamodel_files(&SourceMap::new(files)) {
+        Ok(_) => return Vec::new(),
+        Err(errors) => errors,
+    };
+
+    errors
+        .errors
+        .iter()
+        .filter(|err| err.span().file_id == 0)
+        .filter(|err| !matches!(err, DatamodelError::EnvironmentFunctionalEvaluationError { .. }))
+        .map(|err| Diagnostic {
+            range: document.range(err.span()),
+            severity: Some(DiagnosticSeverity::Error),
+            source: Some(String::from("prisma")),
+            message: format!("{}", err),
+            ..Diagnostic::default()
+        })
+        .collect()
+}
@@ -0,0 +1,127 @@
+use datamodel::ast;
+use lsp_types::{Position, Range};
+
+/// A datamodel file opened in the editor.
+///
+/// Spans in the AST are byte offsets, while LSP positions are lines and UTF-16 columns.
+/// The document keeps the line starts needed to convert between both.
+pub struct Document {
+    text: String,
+    line_starts: Vec<usize>,
+    /// The AST of the well-formed declarations of the current text. Malformed declarations
+    /// are left out, so a typo in one of them does not hide the others.
+    ast: ast::SchemaAst,
+    has_syntax_errors: bool,
+    /// The AST of the last syntactically valid text. The declaration the user is typing
+    /// is mostly malformed, but completions still need its name.
+    last_valid_ast: Option<ast::SchemaAst>,
+}
+
+impl Document {
+    pub fn new(text: String) -> Document {
+        let mut document = Document {
+            text: String::new(),
+            line_starts: vec![0],
+            ast: ast::SchemaAst { tops: Vec::new() },
+            has_syntax_errors: false,
+            last_valid_ast: None,
+        };
+        document.update(text);
+
+        document
+    }
+
+    /// Replaces the whole text of the document.
+    pub fn update(&mut self, text: String) {
+        self.line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+        let (ast, errors) = datamodel::parse_schema_ast_with_recovery(&text);
+        self.ast = ast;
+        self.has_syntax_errors = errors.has_errors();
+        self.text = text;
+
+        if !self.has_syntax_errors {
+            self.last_valid_ast = Some(self.ast.clone());
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn ast(&self) -> &ast::SchemaAst {
+        &self.ast
+    }
+
+    /// Whether some declarations are malformed and missing from the AST.
+    pub fn has_syntax_errors(&self) -> bool {
+        self.has_syntax_errors
+    }
+
+    pub fn last_valid_ast(&self) -> Option<&ast::SchemaAst> {
+        self.last_valid_ast.as_ref()
+    }
+
+    /// The byte offset of the given position. Positions past the end of a line or
+    /// of the document are clamped.
+    pub fn offset_at(&self, position: Position) -> usize {
+        let line = position.line as usize;
+
+        if line >= self.line_starts.len() {
+            return self.text.len();
+        }
+
+        let line_start = self.line_starts[line];
+        let line_end = self.line_end(line);
+        let mut column = 0;
+
+        for (idx, character) in self.text[line_start..line_end].char_indices() {
+            if column >= position.character as usize {
+                return line_start + idx;
+            }
+
+            column += character.len_utf16();
+        }
+
+        line_end
+    }
+
+    /// The position of the given byte offset.
+    pub fn position_at(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+        let line_start = self.line_starts[line];
+        let character: usize = self.text[line_start..offset].chars().map(char::len_utf16).sum();
+
+        Position::new(line as u64, character as u64)
+    }
+
+    pub fn range(&self, span: ast::Span) -> Range {
+        Range::new(self.position_at(span.start), self.position_at(span.end))
+    }
+
+    /// The text of the line at the given position, up to that position.
+    pub fn line_prefix(&self, position: Position) -> &str {
+        let line = (position.line as usize).min(self.line_starts.len() - 1);
+
+        &self.text[self.line_starts[line]..self.offset_at(position)]
+    }
+
+    /// The line end, excluding the line break.
+    fn line_end(&self, line: usize) -> usize {
+        let end = match self.line_starts.get(line + 1) {
+            Some(next_line_start) => next_line_start - 1,
+            None => self.text.len(),
+        };
+
+        if self.text[..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        }
+    }
+}
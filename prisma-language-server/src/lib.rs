@@ -0,0 +1,10 @@
+//! The language features of the datamodel, in terms of the Language Server Protocol.
+//!
+//! The server binary only keeps track of the open documents and dispatches requests to these modules.
+
+pub mod analysis;
+pub mod completion;
+pub mod diagnostics;
+pub mod document;
+pub mod navigation;
+pub mod rename;
@@ -0,0 +1,273 @@
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, GotoDefinition, HoverRequest, Rename, Request as _};
+use lsp_types::*;
+use prisma_language_server::{completion, diagnostics, document::Document, navigation, rename};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+type ServerResult<T> = Result<T, Box<dyn Error + Sync + Send>>;
+
+/// Serves the language features over stdin and stdout.
+fn main() -> ServerResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::Full)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![String::from("@")]),
+            ..CompletionOptions::default()
+        }),
+        hover_provider: Some(true),
+        definition_provider: Some(true),
+        rename_provider: Some(RenameProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    Server::new(&connection).run()?;
+    io_threads.join()?;
+
+    Ok(())
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Url, Document>,
+}
+
+impl<'a> Server<'a> {
+    fn new(connection: &'a Connection) -> Self {
+        Server {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    fn run(&mut self) -> ServerResult<()> {
+        for message in &self.connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> ServerResult<()> {
+        let Request { id, method, params } = request;
+        // Requests with invalid parameters are answered with an error, instead of stopping the server.
+        let response = self.response(id, &method, params).unwrap_or_else(|error| error);
+
+        self.connection.sender.send(Message::Response(response))?;
+
+        Ok(())
+    }
+
+    fn response(&self, id: RequestId, method: &str, params: serde_json::Value) -> Result<Response, Response> {
+        let response = match method {
+            Completion::METHOD => {
+                let params: CompletionParams = request_params(&id, params)?;
+                let position = params.text_document_position;
+
+                self.respond_with(id, &position.text_document.uri, |document| {
+                    completion::completions(document, position.position)
+                })
+            }
+            HoverRequest::METHOD => {
+                let params: HoverParams = request_params(&id, params)?;
+                let position = params.text_document_position_params;
+
+                self.respond_with(id, &position.text_document.uri, |document| {
+                    navigation::hover(document, position.position)
+                })
+            }
+            GotoDefinition::METHOD => {
+                let params: GotoDefinitionParams = request_params(&id, params)?;
+                let position = params.text_document_position_params;
+                let uri = position.text_document.uri;
+
+                self.respond_with(id, &uri, |document| {
+                    navigation::definition(document, position.position)
+                        .map(|range| GotoDefinitionResponse::Scalar(Location::new(uri.clone(), range)))
+                })
+            }
+            Rename::METHOD => {
+                let params: RenameParams = request_params(&id, params)?;
+                let position = params.text_document_position;
+                let uri = position.text_document.uri;
+
+                match self.documents.get(&uri) {
+                    Some(document) => match rename::rename(document, position.position, &params.new_name) {
+                        Ok(edits) => {
+                            let changes = std::iter::once((uri, edits)).collect();
+                            Response::new_ok(id, WorkspaceEdit::new(changes))
+                        }
+                        Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string()),
+                    },
+                    None => Response::new_ok(id, serde_json::Value::Null),
+                }
+            }
+            method => Response::new_err(
+                id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request: {}", method),
+            ),
+        };
+
+        Ok(response)
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> ServerResult<()> {
+        let Notification { method, params } = notification;
+
+        match method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = match notification_params(&method, params) {
+                    Some(params) => params,
+                    None => return Ok(()),
+                };
+                let uri = params.text_document.uri;
+
+                self.documents
+                    .insert(uri.clone(), Document::new(params.text_document.text));
+                self.publish_directory_diagnostics(&uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let mut params: DidChangeTextDocumentParams = match notification_params(&method, params) {
+                    Some(params) => params,
+                    None => return Ok(()),
+                };
+                let uri = params.text_document.uri;
+
+                // With full synchronization, the last change holds the whole text.
+                if let (Some(change), Some(document)) = (params.content_changes.pop(), self.documents.get_mut(&uri)) {
+                    document.update(change.text);
+                    self.publish_directory_diagnostics(&uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = match notification_params(&method, params) {
+                    Some(params) => params,
+                    None => return Ok(()),
+                };
+
+                let uri = params.text_document.uri;
+
+                // The editor keeps showing the last diagnostics of a closed document until they are cleared.
+                self.documents.remove(&uri);
+                self.send_diagnostics(uri.clone(), Vec::new())?;
+                self.publish_directory_diagnostics(&uri)?;
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Publishes the diagnostics of all open documents in the directory of the document,
+    /// since the files of a directory are validated together.
+    fn publish_directory_diagnostics(&self, uri: &Url) -> ServerResult<()> {
+        let directory = uri.join(".").ok();
+        let uris: Vec<Url> = self
+            .documents
+            .keys()
+            .filter(|other| other.join(".").ok() == directory)
+            .cloned()
+            .collect();
+
+        for uri in uris {
+            self.publish_diagnostics(uri)?;
+        }
+
+        Ok(())
+    }
+
+    fn publish_diagnostics(&self, uri: Url) -> ServerResult<()> {
+        let diagnostics = match self.documents.get(&uri) {
+            Some(document) => diagnostics::diagnostics_in_files(document, &self.other_files(&uri)),
+            None => return Ok(()),
+        };
+
+        self.send_diagnostics(uri, diagnostics)
+    }
+
+    fn send_diagnostics(&self, uri: Url, diagnostics: Vec<Diagnostic>) -> ServerResult<()> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+
+        self.connection.sender.send(Message::Notification(notification))?;
+
+        Ok(())
+    }
+
+    /// The texts of the other `.prisma` files in the directory of the document, which the datamodel is split across.
+    /// Open documents are taken from the editor, since they may have unsaved changes.
+    fn other_files(&self, uri: &Url) -> Vec<String> {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return Vec::new(),
+        };
+        let entries = match path.parent().map(fs::read_dir) {
+            Some(Ok(entries)) => entries,
+            _ => return Vec::new(),
+        };
+
+        entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|other| other != &path && other.extension() == Some("prisma".as_ref()))
+            .filter_map(|other| {
+                let document = Url::from_file_path(&other)
+                    .ok()
+                    .and_then(|uri| self.documents.get(&uri));
+
+                match document {
+                    Some(document) => Some(document.text().to_owned()),
+                    None => fs::read_to_string(&other).ok(),
+                }
+            })
+            .collect()
+    }
+
+    /// Answers with the result for the document, or with `null` if the document is not open.
+    fn respond_with<T: serde::Serialize>(
+        &self,
+        id: RequestId,
+        uri: &Url,
+        handler: impl FnOnce(&Document) -> T,
+    ) -> Response {
+        match self.documents.get(uri) {
+            Some(document) => Response::new_ok(id, handler(document)),
+            None => Response::new_ok(id, serde_json::Value::Null),
+        }
+    }
+}
+
+/// Parses the parameters of a request, or answers it with an `InvalidParams` error.
+fn request_params<P: serde::de::DeserializeOwned>(id: &RequestId, params: serde_json::Value) -> Result<P, Response> {
+    serde_json::from_value(params)
+        .map_err(|err| Response::new_err(id.clone(), ErrorCode::InvalidParams as i32, err.to_string()))
+}
+
+/// Parses the parameters of a notification. Notifications can't be answered, so invalid
+/// parameters are only logged.
+fn notification_params<P: serde::de::DeserializeOwned>(method: &str, params: serde_json::Value) -> Option<P> {
+    match serde_json::from_value(params) {
+        Ok(params) => Some(params),
+        Err(err) => {
+            eprintln!("Invalid parameters for {}: {}", method, err);
+            None
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::analysis::{self, Symbol};
+use crate::document::Document;
+use datamodel::ast::{self, WithDocumentation};
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+
+/// The range of the declaration the name at the given position refers to.
+pub fn definition(document: &Document, position: Position) -> Option<Range> {
+    let ast = document.ast();
+    let symbol = analysis::symbol_at(ast, document.offset_at(position))?;
+
+    analysis::definition_span(ast, symbol).map(|span| document.range(span))
+}
+
+/// The declaration and the `///` documentation of the name at the given position.
+pub fn hover(document: &Document, position: Position) -> Option<Hover> {
+    let ast = document.ast();
+    let symbol = analysis::symbol_at(ast, document.offset_at(position))?;
+
+    let (declaration, documentation) = match symbol {
+        Symbol::Top(top) => describe_top(top),
+        Symbol::Field(model, field) => describe_field(model, field),
+        Symbol::TypeReference(type_name) => describe_top(analysis::find_type(ast, &type_name.name)?),
+        Symbol::FieldReference(reference) => {
+            let model = ast
+                .models()
+                .into_iter()
+                .find(|model| model.name.name == reference.model)?;
+            describe_field(model, ast.find_field(reference.model, reference.field)?)
+        }
+    };
+
+    let mut value = format!("```prisma\n{}\n```", declaration);
+
+    if let Some(documentation) = documentation {
+        value.push_str("\n___\n");
+        value.push_str(&documentation.text);
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: None,
+    })
+}
+
+fn describe_top(top: &ast::Top) -> (String, Option<&ast::Comment>) {
+    let declaration = match top {
+        ast::Top::Type(type_alias) => format!("type {} = {}", type_alias.name.name, type_alias.field_type.name),
+        ast::Top::Model(model) if model.is_view => format!("view {}", model.name.name),
+        ast::Top::Model(model) if model.is_composite_type => format!("type {}", model.name.name),
+        _ => format!("{} {}", top.get_type(), top.name()),
+    };
+    let documentation = match top {
+        ast::Top::Model(model) => model.documentation(),
+        ast::Top::Enum(r#enum) => r#enum.documentation(),
+        ast::Top::Type(type_alias) => type_alias.documentation(),
+        ast::Top::Source(source) => source.documentation(),
+        ast::Top::Generator(generator) => generator.documentation(),
+        ast::Top::Linter(linter) => linter.documentation(),
+    };
+
+    (declaration, documentation.as_ref())
+}
+
+fn describe_field<'a>(model: &ast::Model, field: &'a ast::Field) -> (String, Option<&'a ast::Comment>) {
+    let arity = match field.arity {
+        ast::FieldArity::Required => "",
+        ast::FieldArity::Optional => "?",
+        ast::FieldArity::List => "[]",
+    };
+    let declaration = format!(
+        "{}.{}: {}{}",
+        model.name.name, field.name.name, field.field_type.name, arity
+    );
+
+    (declaration, field.documentation().as_ref())
+}
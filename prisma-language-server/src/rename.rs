@@ -0,0 +1,88 @@
+use crate::analysis::{self, Symbol};
+use crate::document::Document;
+use datamodel::ast::{self, Span, WithIdentifier};
+use lsp_types::{Position, TextEdit};
+
+#[derive(Debug, PartialEq)]
+pub enum RenameError {
+    /// The new name is not a valid identifier.
+    InvalidName(String),
+    /// There is no model, enum, type alias or field at the position.
+    NothingToRename,
+    /// Malformed declarations are missing from the AST, so their references would not be renamed.
+    SyntaxErrors,
+}
+
+impl std::fmt::Display for RenameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RenameError::InvalidName(name) => write!(f, "\"{}\" is not a valid name.", name),
+            RenameError::NothingToRename => write!(f, "Only models, enums, type aliases and fields can be renamed."),
+            RenameError::SyntaxErrors => write!(f, "The datamodel has syntax errors. Fix them before renaming."),
+        }
+    }
+}
+
+/// Renames the model, enum, type alias or field at the given position, along with all references to it.
+pub fn rename(document: &Document, position: Position, new_name: &str) -> Result<Vec<TextEdit>, RenameError> {
+    if !is_valid_identifier(new_name) {
+        return Err(RenameError::InvalidName(new_name.to_string()));
+    }
+
+    if document.has_syntax_errors() {
+        return Err(RenameError::SyntaxErrors);
+    }
+
+    let ast = document.ast();
+    let symbol = analysis::symbol_at(ast, document.offset_at(position)).ok_or(RenameError::NothingToRename)?;
+
+    let spans = match symbol {
+        Symbol::Top(top) => type_spans(ast, top)?,
+        Symbol::TypeReference(type_name) => type_spans(
+            ast,
+            analysis::find_type(ast, &type_name.name).ok_or(RenameError::NothingToRename)?,
+        )?,
+        Symbol::Field(model, field) => field_spans(ast, &model.name.name, &field.name.name)?,
+        Symbol::FieldReference(reference) => field_spans(ast, reference.model, reference.field)?,
+    };
+
+    Ok(spans
+        .into_iter()
+        .map(|span| TextEdit::new(document.range(span), new_name.to_string()))
+        .collect())
+}
+
+/// The declaration of the type and all fields and type aliases using it.
+fn type_spans(ast: &ast::SchemaAst, top: &ast::Top) -> Result<Vec<Span>, RenameError> {
+    match top {
+        ast::Top::Model(_) | ast::Top::Enum(_) | ast::Top::Type(_) => (),
+        _ => return Err(RenameError::NothingToRename),
+    }
+
+    let references = analysis::type_references(ast)
+        .into_iter()
+        .filter(|type_name| type_name.name == top.name())
+        .map(analysis::type_name_span);
+
+    Ok(std::iter::once(top.identifier().span).chain(references).collect())
+}
+
+/// The declaration of the field and all directive arguments naming it.
+fn field_spans(ast: &ast::SchemaAst, model: &str, field: &str) -> Result<Vec<Span>, RenameError> {
+    let declaration = ast.find_field(model, field).ok_or(RenameError::NothingToRename)?;
+    let references = analysis::field_references(ast)
+        .into_iter()
+        .filter(|reference| reference.model == model && reference.field == field)
+        .map(|reference| reference.span);
+
+    Ok(std::iter::once(declaration.name.span).chain(references).collect())
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
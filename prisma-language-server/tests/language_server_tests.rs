@@ -0,0 +1,207 @@
+use lsp_types::{HoverContents, Position, Range};
+use prisma_language_server::{completion, diagnostics, document::Document, navigation, rename};
+
+const DATAMODEL: &str = r#"/// Someone who writes posts.
+model User {
+  id    Int    @id
+  email String @unique
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+
+  @@index([authorId])
+}
+"#;
+
+#[test]
+fn positions_must_be_converted_to_offsets_and_back() {
+    let document = Document::new(String::from("model User {\n  ü Int @id\n}"));
+    let position = Position::new(1, 4);
+    let offset = document.offset_at(position);
+
+    assert_eq!(&document.text()[offset..offset + 3], "Int");
+    assert_eq!(document.position_at(offset), position);
+}
+
+#[test]
+fn validation_errors_must_be_reported_as_diagnostics() {
+    let document = Document::new(String::from("model User {\n  id Int @id\n  post Post\n}"));
+    let diagnostics = diagnostics::diagnostics(&document);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range.start, Position::new(2, 7));
+}
+
+#[test]
+fn types_declared_in_other_files_must_resolve_in_diagnostics() {
+    let document = Document::new(String::from("model User {\n  id Int @id\n  posts Post[]\n}"));
+    let other_files = vec![String::from(
+        "model Post {\n  id Int @id\n  author User\n  tag Tag\n}\n",
+    )];
+
+    assert_eq!(diagnostics::diagnostics(&document).len(), 1);
+    assert!(diagnostics::diagnostics_in_files(&document, &other_files).is_empty());
+}
+
+#[test]
+fn relation_types_must_lead_to_the_model() {
+    let document = Document::new(String::from(DATAMODEL));
+    let definition = navigation::definition(&document, Position::new(10, 12));
+
+    assert_eq!(definition, Some(Range::new(Position::new(1, 6), Position::new(1, 10))));
+}
+
+#[test]
+fn field_references_must_lead_to_the_field() {
+    let document = Document::new(String::from(DATAMODEL));
+    let definition = navigation::definition(&document, Position::new(10, 60));
+
+    assert_eq!(definition, Some(Range::new(Position::new(2, 2), Position::new(2, 4))));
+}
+
+#[test]
+fn hover_must_show_the_documentation() {
+    let document = Document::new(String::from(DATAMODEL));
+    let hover = navigation::hover(&document, Position::new(10, 12)).unwrap();
+
+    match hover.contents {
+        HoverContents::Markup(content) => {
+            assert_eq!(
+                content.value,
+                "```prisma\nmodel User\n```\n___\nSomeone who writes posts."
+            )
+        }
+        contents => panic!("Expected markdown, got {:?}", contents),
+    }
+}
+
+#[test]
+fn field_types_must_be_completed_with_declared_types() {
+    let document = Document::new(format!("{}\nmodel Comment {{\n  post P", DATAMODEL));
+    let labels: Vec<String> = completion::completions(&document, Position::new(16, 8))
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+
+    assert!(labels.contains(&String::from("String")));
+    assert!(labels.contains(&String::from("Post")));
+}
+
+#[test]
+fn directive_arguments_must_be_completed() {
+    let document = Document::new(String::from("model User {\n  id Int @relation("));
+    let labels: Vec<String> = completion::completions(&document, Position::new(1, 19))
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+
+    assert!(labels.contains(&String::from("fields")));
+    assert!(labels.contains(&String::from("references")));
+}
+
+#[test]
+fn renaming_a_model_must_rename_its_uses() {
+    let document = Document::new(String::from(DATAMODEL));
+    let edits = rename::rename(&document, Position::new(7, 8), "Article").unwrap();
+    let ranges: Vec<Range> = edits.into_iter().map(|edit| edit.range).collect();
+
+    assert_eq!(
+        ranges,
+        vec![
+            Range::new(Position::new(7, 6), Position::new(7, 10)),
+            Range::new(Position::new(4, 8), Position::new(4, 12)),
+        ]
+    );
+}
+
+#[test]
+fn renaming_a_field_must_rename_directive_arguments() {
+    let document = Document::new(String::from(DATAMODEL));
+    let edits = rename::rename(&document, Position::new(9, 4), "writerId").unwrap();
+    let ranges: Vec<Range> = edits.into_iter().map(|edit| edit.range).collect();
+
+    assert_eq!(
+        ranges,
+        vec![
+            Range::new(Position::new(9, 2), Position::new(9, 10)),
+            Range::new(Position::new(12, 11), Position::new(12, 19)),
+            Range::new(Position::new(10, 35), Position::new(10, 43)),
+        ]
+    );
+}
+
+#[test]
+fn renaming_to_an_invalid_name_must_fail() {
+    let document = Document::new(String::from(DATAMODEL));
+
+    assert_eq!(
+        rename::rename(&document, Position::new(7, 8), "1Post"),
+        Err(rename::RenameError::InvalidName(String::from("1Post")))
+    );
+}
+
+#[test]
+fn a_syntax_error_must_not_hide_the_other_diagnostics_and_symbols() {
+    let document = Document::new(format!(
+        "{}\nmodel Broken {{\n  id Int @id\n  name String @@\n}}\n\nmodel Comment {{\n  id Int @id\n  tag Tag\n}}\n",
+        DATAMODEL
+    ));
+    let diagnostics = diagnostics::diagnostics(&document);
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].range.start, Position::new(18, 0));
+    assert_eq!(diagnostics[1].range.start, Position::new(22, 6));
+
+    let definition = navigation::definition(&document, Position::new(10, 12));
+    assert_eq!(definition, Some(Range::new(Position::new(1, 6), Position::new(1, 10))));
+    assert_eq!(
+        rename::rename(&document, Position::new(7, 8), "Article"),
+        Err(rename::RenameError::SyntaxErrors)
+    );
+}
+
+#[test]
+fn keywords_must_be_completed_at_the_top_level() {
+    let document = Document::new(format!("{}\nvi", DATAMODEL));
+    let labels: Vec<String> = completion::completions(&document, Position::new(16, 2))
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+
+    assert!(labels.contains(&String::from("model")));
+    assert!(labels.contains(&String::from("view")));
+}
+
+#[test]
+fn field_types_and_directives_must_be_completed_in_views() {
+    let document = Document::new(format!("{}\nview UserInfo {{\n  id Int @\n  name S", DATAMODEL));
+    let directives: Vec<String> = completion::completions(&document, Position::new(16, 10))
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+    let types: Vec<String> = completion::completions(&document, Position::new(17, 8))
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+
+    assert!(directives.contains(&String::from("id")));
+    assert!(types.contains(&String::from("String")));
+    assert!(types.contains(&String::from("User")));
+}
+
+#[test]
+fn hover_must_name_views_as_views() {
+    let document = Document::new(String::from(
+        "view UserInfo {\n  id Int @id\n\n  @@definition(\"SELECT 1 AS id\")\n}\n",
+    ));
+    let hover = navigation::hover(&document, Position::new(0, 7)).unwrap();
+
+    match hover.contents {
+        HoverContents::Markup(content) => assert_eq!(content.value, "```prisma\nview UserInfo\n```"),
+        contents => panic!("Expected markdown, got {:?}", contents),
+    }
+}
@@ -29,6 +29,9 @@ pub enum ErrorKind {
     #[fail(display = "Null constraint failed: {}", field_name)]
     NullConstraintViolation { field_name: String },
 
+    #[fail(display = "Check constraint failed: {}", constraint_name)]
+    CheckConstraintViolation { constraint_name: String },
+
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
 
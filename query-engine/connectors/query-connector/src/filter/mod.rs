@@ -110,6 +110,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             behaviour: None,
             default_value: None,
             internal_enum: None,
+            composite_type: None,
         }),
         FieldTemplate::Scalar(ScalarFieldTemplate {
             name: "name".to_owned(),
@@ -123,6 +124,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             behaviour: None,
             default_value: None,
             internal_enum: None,
+            composite_type: None,
         }),
         FieldTemplate::Relation(RelationFieldTemplate {
             name: "sites".to_owned(),
@@ -150,12 +152,15 @@ pub fn test_data_model() -> InternalDataModelRef {
         behaviour: None,
         default_value: None,
         internal_enum: None,
+        composite_type: None,
     })];
 
     let model_templates = vec![
         ModelTemplate {
             name: "User".to_owned(),
             is_embedded: false,
+            is_view: false,
+            schema: None,
             fields: user_field_templates,
             manifestation: None,
             indexes: vec![],
@@ -163,6 +168,8 @@ pub fn test_data_model() -> InternalDataModelRef {
         ModelTemplate {
             name: "Site".to_owned(),
             is_embedded: false,
+            is_view: false,
+            schema: None,
             fields: site_field_templates,
             manifestation: None,
             indexes: vec![],
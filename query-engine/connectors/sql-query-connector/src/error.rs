@@ -12,6 +12,9 @@ pub enum SqlError {
     #[fail(display = "Null constraint failed: {}", field_name)]
     NullConstraintViolation { field_name: String },
 
+    #[fail(display = "Check constraint failed: {}", constraint_name)]
+    CheckConstraintViolation { constraint_name: String },
+
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
 
@@ -77,6 +80,15 @@ impl SqlError {
             SqlError::NullConstraintViolation { field_name } => {
                 ConnectorError::from_kind(ErrorKind::NullConstraintViolation { field_name })
             }
+            SqlError::CheckConstraintViolation { constraint_name } => ConnectorError {
+                user_facing_error: user_facing_errors::KnownError::new(
+                    user_facing_errors::query_engine::CheckConstraintViolation {
+                        constraint_name: constraint_name.clone(),
+                    },
+                )
+                .ok(),
+                kind: ErrorKind::CheckConstraintViolation { constraint_name },
+            },
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
             SqlError::ColumnDoesNotExist => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist),
             SqlError::ConnectionError(e) => ConnectorError {
@@ -116,7 +128,10 @@ impl SqlError {
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
         match e {
-            quaint::error::Error::QueryError(e) => Self::QueryError(e.into()),
+            quaint::error::Error::QueryError(e) => match check_constraint_name(&e.to_string()) {
+                Some(constraint_name) => Self::CheckConstraintViolation { constraint_name },
+                None => Self::QueryError(e.into()),
+            },
             quaint::error::Error::IoError(_) => Self::ConnectionError(e),
             quaint::error::Error::NotFound => Self::RecordDoesNotExist,
             quaint::error::Error::UniqueConstraintViolation { field_name } => {
@@ -147,6 +162,32 @@ impl From<quaint::error::Error> for SqlError {
     }
 }
 
+/// Extracts the name of the violated check constraint from a database error message. The
+/// drivers don't expose check violations as a separate error kind.
+fn check_constraint_name(message: &str) -> Option<String> {
+    // Postgres: new row for relation "Product" violates check constraint "price_is_positive"
+    if let Some(idx) = message.find("violates check constraint \"") {
+        let rest = &message[idx + "violates check constraint \"".len()..];
+        return rest.find('"').map(|end| rest[..end].to_string());
+    }
+
+    // MySQL: Check constraint 'price_is_positive' is violated.
+    if let Some(idx) = message.find("Check constraint '") {
+        let rest = &message[idx + "Check constraint '".len()..];
+        if let Some(end) = rest.find("' is violated") {
+            return Some(rest[..end].to_string());
+        }
+    }
+
+    // SQLite: CHECK constraint failed: price_is_positive
+    if let Some(idx) = message.find("CHECK constraint failed: ") {
+        let rest = message[idx + "CHECK constraint failed: ".len()..].trim();
+        return Some(rest.to_string());
+    }
+
+    None
+}
+
 impl From<DomainError> for SqlError {
     fn from(e: DomainError) -> SqlError {
         SqlError::DomainError(e)
@@ -183,3 +224,25 @@ impl From<FromUtf8Error> for SqlError {
         SqlError::ColumnReadFailure(e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_constraint_names_are_extracted_from_error_messages() {
+        assert_eq!(
+            check_constraint_name(r#"new row for relation "Product" violates check constraint "price_check""#),
+            Some("price_check".to_string())
+        );
+        assert_eq!(
+            check_constraint_name("Check constraint 'price_check' is violated."),
+            Some("price_check".to_string())
+        );
+        assert_eq!(
+            check_constraint_name("CHECK constraint failed: price_check"),
+            Some("price_check".to_string())
+        );
+        assert_eq!(check_constraint_name("UNIQUE constraint failed: Product.name"), None);
+    }
+}
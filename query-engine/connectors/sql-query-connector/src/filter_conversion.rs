@@ -146,8 +146,8 @@ impl AliasedCondition for ScalarFilter {
         let condition = match self.condition {
             ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
             ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
+            ScalarCondition::Equals(value) => column.equals(self.field.column_value(value)),
+            ScalarCondition::NotEquals(value) => column.not_equals(self.field.column_value(value)),
             ScalarCondition::Contains(value) => column.like(format!("{}", value)),
             ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
             ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
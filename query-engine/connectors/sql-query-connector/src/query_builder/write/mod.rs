@@ -27,9 +27,10 @@ pub fn create_record(model: &ModelRef, mut args: PrismaArgs) -> (Insert<'static>
         .filter(|field| args.has_arg_for(&field.name()))
         .collect();
 
-    let fields = fields
-        .iter()
-        .map(|field| (field.db_name(), args.take_field_value(field.name()).unwrap()));
+    let fields = fields.iter().map(|field| {
+        let value = args.take_field_value(field.name()).unwrap();
+        (field.db_name(), field.column_value(value))
+    });
 
     let base = Insert::single_into(model.as_table());
 
@@ -104,7 +105,7 @@ pub fn update_many(model: &ModelRef, ids: &[&GraphqlId], args: &PrismaArgs) -> c
             });
         }
 
-        query = query.set(field.db_name().to_string(), value.clone());
+        query = query.set(field.db_name().to_string(), field.column_value(value.clone()));
     }
 
     let result: Vec<Update> = ids
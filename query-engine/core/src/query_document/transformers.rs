@@ -30,6 +30,29 @@ impl TryInto<PrismaValue> for ParsedInputValue {
     }
 }
 
+impl TryInto<serde_json::Value> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<serde_json::Value> {
+        match self {
+            ParsedInputValue::Single(val) => serde_json::to_value(val)
+                .map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err))),
+
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .map(|val| val.try_into())
+                .collect::<QueryParserResult<Vec<serde_json::Value>>>()
+                .map(serde_json::Value::Array),
+
+            ParsedInputValue::Map(map) => map
+                .into_iter()
+                .map(|(k, val)| val.try_into().map(|json| (k, json)))
+                .collect::<QueryParserResult<serde_json::Map<String, serde_json::Value>>>()
+                .map(serde_json::Value::Object),
+        }
+    }
+}
+
 impl TryInto<ParsedInputMap> for ParsedInputValue {
     type Error = QueryParserError;
 
@@ -160,7 +160,18 @@ fn handle_scalar_field(
     value: ParsedInputValue,
     op: &FilterOp,
 ) -> QueryGraphBuilderResult<Filter> {
-    let value: PrismaValue = value.try_into()?;
+    let value: PrismaValue = if field.is_composite() {
+        // Composite values are compared as whole (serialized) documents.
+        let json: serde_json::Value = value.try_into()?;
+
+        match json {
+            serde_json::Value::Null => PrismaValue::Null,
+            json => PrismaValue::String(json.to_string()),
+        }
+    } else {
+        value.try_into()?
+    };
+
     Ok(match op {
         FilterOp::In => field.is_in(PrismaListValue::try_from(value)?),
         FilterOp::NotIn => field.not_in(PrismaListValue::try_from(value)?),
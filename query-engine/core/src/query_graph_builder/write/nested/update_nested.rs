@@ -100,7 +100,7 @@ pub fn connect_nested_update_many(
         let where_map: ParsedInputMap = where_arg.try_into()?;
 
         let filter = extract_filter(where_map, child_model, true)?;
-        let update_args = WriteArguments::from(&child_model, data_map)?;
+        let update_args = WriteArguments::from_update(child_model, data_map)?;
 
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter.clone())?;
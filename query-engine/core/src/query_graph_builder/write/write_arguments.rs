@@ -13,6 +13,15 @@ impl WriteArguments {
     /// Creates a new set of WriteArguments. Expects the parsed input map from the respective data key, not the enclosing map.
     /// E.g.: { data: { THIS MAP } } from the `data` argument of a write query.
     pub fn from(model: &ModelRef, data_map: ParsedInputMap) -> QueryGraphBuilderResult<Self> {
+        Self::from_map(model, data_map, false)
+    }
+
+    /// Same as `from`, but for the data of update operations, where composite values are wrapped in a `set` input.
+    pub fn from_update(model: &ModelRef, data_map: ParsedInputMap) -> QueryGraphBuilderResult<Self> {
+        Self::from_map(model, data_map, true)
+    }
+
+    fn from_map(model: &ModelRef, data_map: ParsedInputMap, is_update: bool) -> QueryGraphBuilderResult<Self> {
         data_map.into_iter().try_fold(
             WriteArguments::default(),
             |mut args, (k, v): (String, ParsedInputValue)| {
@@ -26,6 +35,20 @@ impl WriteArguments {
                         args.args.insert(sf.name.clone(), set_value)
                     }
 
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let document = if is_update {
+                            let mut vals: ParsedInputMap = v.try_into()?;
+                            vals.remove("set")
+                        } else {
+                            Some(v)
+                        };
+
+                        // An update without `set` leaves the composite value untouched.
+                        if let Some(document) = document {
+                            args.args.insert(sf.name.clone(), composite_value(document)?)
+                        }
+                    }
+
                     Field::Scalar(sf) => {
                         let value: PrismaValue = v.try_into()?;
                         args.args.insert(sf.name.clone(), value)
@@ -41,3 +64,13 @@ impl WriteArguments {
         )
     }
 }
+
+/// Composite values are written as serialized JSON documents.
+fn composite_value(document: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
+    let json: serde_json::Value = document.try_into()?;
+
+    Ok(match json {
+        serde_json::Value::Null => PrismaValue::Null,
+        json => PrismaValue::String(json.to_string()),
+    })
+}
@@ -305,6 +305,13 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 arc_type
             ))),
         },
+        (PrismaValue::String(s), OutputType::Scalar(ScalarType::Json)) => {
+            let json = serde_json::from_str(s).map_err(|err| {
+                CoreError::SerializationError(format!("Attempted to serialize invalid JSON '{}': {}", s, err))
+            })?;
+
+            convert_json_to_item(json)
+        }
         (_, OutputType::Scalar(st)) => Ok(Item::Value(convert_prisma_value(value, st)?)),
         (pv, ot) => Err(CoreError::SerializationError(format!(
             "Attempted to serialize scalar '{}' with non-scalar compatible type '{:?}'",
@@ -313,6 +320,33 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
     }
 }
 
+/// Converts a JSON document (e.g. a composite value) into an item tree, rendering it as a nested object.
+fn convert_json_to_item(value: serde_json::Value) -> CoreResult<Item> {
+    let item = match value {
+        serde_json::Value::Null => Item::Value(PrismaValue::Null),
+        serde_json::Value::Bool(b) => Item::Value(PrismaValue::Boolean(b)),
+        serde_json::Value::String(s) => Item::Value(PrismaValue::String(s)),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Item::Value(PrismaValue::Int(i)),
+            None => Item::Value(PrismaValue::try_from(n.as_f64().unwrap())?),
+        },
+        serde_json::Value::Array(values) => Item::List(
+            values
+                .into_iter()
+                .map(convert_json_to_item)
+                .collect::<CoreResult<List>>()?,
+        ),
+        serde_json::Value::Object(fields) => Item::Map(
+            fields
+                .into_iter()
+                .map(|(k, v)| convert_json_to_item(v).map(|item| (k, item)))
+                .collect::<CoreResult<Map>>()?,
+        ),
+    };
+
+    Ok(item)
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -71,6 +71,8 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            // Composite values can only be compared as whole documents.
+            TypeIdentifier::Json if is_composite(field) => vec![&args.base],
             TypeIdentifier::Json => vec![],
             TypeIdentifier::Relation => vec![&args.one_relation],
         };
@@ -82,3 +84,10 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             .collect()
     }
 }
+
+fn is_composite(field: &ModelField) -> bool {
+    match field {
+        ModelField::Scalar(sf) => sf.is_composite(),
+        ModelField::Relation(_) => false,
+    }
+}
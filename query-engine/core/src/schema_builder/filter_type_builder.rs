@@ -124,12 +124,49 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         unimplemented!()
     }
 
+    /// Builds "<x>CreateInput" for a composite type. The same input object is used to write composite values
+    /// and to filter on them, as composite values are always handled as whole documents.
+    pub fn composite_input_object_type(&self, composite_type: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}CreateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| {
+                let typ = self.map_composite_or_scalar_input_type(Arc::clone(&f));
+
+                if f.is_required && f.default_value.is_none() {
+                    input_field(f.name.clone(), typ, None)
+                } else {
+                    input_field(f.name.clone(), InputType::opt(typ), f.default_value.clone())
+                }
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Maps composite type fields to their composite input object, all other fields to their scalar input type.
+    pub fn map_composite_or_scalar_input_type(&self, field: ScalarFieldRef) -> InputType {
+        match field.composite_type() {
+            Some(composite_type) => InputType::object(self.composite_input_object_type(composite_type)),
+            None => self.map_required_input_type(field),
+        }
+    }
+
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
         get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
             .into_iter()
             .map(|arg| {
                 let field_name = format!("{}{}", field.name, arg.suffix);
-                let mapped = self.map_required_input_type(Arc::clone(&field));
+                let mapped = self.map_composite_or_scalar_input_type(Arc::clone(&field));
 
                 if arg.is_list {
                     input_field(field_name, InputType::opt(InputType::list(mapped)), None)
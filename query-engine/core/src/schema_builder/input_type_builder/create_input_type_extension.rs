@@ -31,7 +31,10 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             |f: ScalarFieldRef| {
                 let required_and_none = f.is_required && f.default_value.is_none();
 
-                if required_and_none && f.is_id() {
+                if f.is_composite() {
+                    let typ = self.get_filter_object_builder().map_composite_or_scalar_input_type(f);
+                    if required_and_none { typ } else { InputType::opt(typ) }
+                } else if required_and_none && f.is_id() {
                     match (f.behaviour.as_ref(), f.type_identifier) {
                         (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::UUID)      => self.map_optional_input_type(f),
                         (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::GraphQLID) => self.map_optional_input_type(f),
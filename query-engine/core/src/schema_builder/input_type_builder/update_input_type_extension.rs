@@ -37,11 +37,39 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
             model.name.clone(),
             "Update",
             model.fields().scalar(),
-            |f: ScalarFieldRef| self.map_optional_input_type(f),
+            |f: ScalarFieldRef| {
+                if f.is_composite() {
+                    InputType::opt(InputType::object(self.composite_update_input_object_type(f)))
+                } else {
+                    self.map_optional_input_type(f)
+                }
+            },
             false,
         )
     }
 
+    /// Builds "<x>UpdateInput" for composite types. Composite values are replaced as a whole via "set".
+    fn composite_update_input_object_type(&self, field: ScalarFieldRef) -> InputObjectTypeRef {
+        let composite_type = field.composite_type().expect("Expected a composite type field.");
+        let name = format!("{}UpdateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let create_input_object = self
+            .get_filter_object_builder()
+            .composite_input_object_type(composite_type);
+
+        let set_fields = vec![input_field(
+            "set",
+            InputType::opt(InputType::object(create_input_object)),
+            None,
+        )];
+
+        let input_object = Arc::new(input_object_type(name.clone(), set_fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
     /// For update input types only. Compute input fields for relational fields.
     /// This recurses into create_input_type (via nested_create_input_field).
     /// Todo: This code is fairly similar to "create" relation computation. Let's see if we can dry it up.
@@ -150,8 +150,8 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds the root mutation type.
     fn build_mutation_type(&self) -> (OutputType, ObjectTypeStrongRef) {
-        let non_embedded_models = self.non_embedded_models();
-        let fields = non_embedded_models
+        let writable_models = self.writable_models();
+        let fields = writable_models
             .into_iter()
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
@@ -183,6 +183,11 @@ impl<'a> QuerySchemaBuilder<'a> {
             .collect()
     }
 
+    /// Views are read-only, so they only get query fields.
+    fn writable_models(&self) -> Vec<ModelRef> {
+        self.non_embedded_models().into_iter().filter(|m| !m.is_view).collect()
+    }
+
     /// Builds a "single" query arity item field (e.g. "user", "post" ...) for given model.
     fn single_item_field(&self, model: ModelRef) -> Option<Field> {
         self.argument_builder
@@ -1,5 +1,5 @@
 use crate::{
-    data_model_loader::{load_configuration, load_data_model_components},
+    data_model_loader::{load_configuration, load_data_model_components, single_file},
     dmmf, PrismaResult,
 };
 use clap::ArgMatches;
@@ -22,6 +22,8 @@ pub enum CliCommand {
     Dmmf(BuildMode),
     DmmfToDml(DmmfToDmlInput),
     GetConfig(String),
+    JsonSchema,
+    GraphQlSdl,
 }
 
 impl CliCommand {
@@ -47,6 +49,10 @@ impl CliCommand {
             file.read_to_string(&mut datamodel).expect("Couldn't read file");
 
             Some(Self::GetConfig(datamodel))
+        } else if matches.is_present("json_schema") {
+            Some(Self::JsonSchema)
+        } else if matches.is_present("graphql_sdl") {
+            Some(Self::GraphQlSdl)
         } else {
             None
         }
@@ -57,6 +63,8 @@ impl CliCommand {
             CliCommand::Dmmf(build_mode) => Self::dmmf(build_mode),
             CliCommand::DmmfToDml(input) => Self::dmmf_to_dml(input),
             CliCommand::GetConfig(input) => Self::get_config(input),
+            CliCommand::JsonSchema => Self::json_schema(),
+            CliCommand::GraphQlSdl => Self::graphql_sdl(),
         }
     }
 
@@ -89,7 +97,7 @@ impl CliCommand {
     }
 
     fn get_config(input: String) -> PrismaResult<()> {
-        let config = load_configuration(&input)?;
+        let config = load_configuration(&single_file(&input))?;
         let json = datamodel::json::mcf::config_to_mcf_json_value(&config);
         let serialized = serde_json::to_string(&json)?;
 
@@ -97,4 +105,22 @@ impl CliCommand {
 
         Ok(())
     }
+
+    fn json_schema() -> PrismaResult<()> {
+        let (v2components, _) = load_data_model_components()?;
+        let serialized = datamodel::json::json_schema::render_to_json_schema(&v2components.datamodel);
+
+        println!("{}", serialized);
+
+        Ok(())
+    }
+
+    fn graphql_sdl() -> PrismaResult<()> {
+        let (v2components, _) = load_data_model_components()?;
+        let sdl = datamodel::graphql::render_to_sdl(&v2components.datamodel);
+
+        print!("{}", sdl);
+
+        Ok(())
+    }
 }
@@ -1,13 +1,17 @@
-use std::{fs::File, io::Read};
+use std::path::Path;
 
 use serde::Deserialize;
 use serde_json;
 
+use datamodel::ast::{SourceFile, SourceMap};
 use datamodel::{Datamodel, Source};
 use prisma_models::{DatamodelConverter, InternalDataModelTemplate};
 
 use crate::{utilities, PrismaError, PrismaResult};
 
+/// The file name shown in errors of a datamodel that was not loaded from a file.
+const SCHEMA_FILE_NAME: &str = "schema.prisma";
+
 /// Wrapper type to unclutter the interface
 pub struct DatamodelV2Components {
     pub datamodel: Datamodel,
@@ -92,9 +96,9 @@ pub fn load_data_model_components() -> PrismaResult<(DatamodelV2Components, Inte
 fn load_datamodel_v2() -> PrismaResult<Option<DatamodelV2Components>> {
     debug!("Trying to load v2 data model...");
 
-    load_v2_dml_string().inner_map(|dml_string| match datamodel::parse_datamodel(&dml_string) {
-        Err(errors) => Err(PrismaError::ConversionError(errors, dml_string.clone())),
-        Ok(dm) => load_configuration(&dml_string).map(|configuration| {
+    load_v2_dml_files().inner_map(|files| match datamodel::parse_datamodel_files(&files) {
+        Err(errors) => Err(PrismaError::ConversionError(errors, files.clone())),
+        Ok(dm) => load_configuration(&files).map(|configuration| {
             debug!("Loaded Prisma v2 data model.");
             Some(DatamodelV2Components {
                 datamodel: dm,
@@ -104,13 +108,13 @@ fn load_datamodel_v2() -> PrismaResult<Option<DatamodelV2Components>> {
     })
 }
 
-pub fn load_configuration(dml_string: &str) -> PrismaResult<datamodel::Configuration> {
+pub fn load_configuration(files: &SourceMap) -> PrismaResult<datamodel::Configuration> {
     let datasource_overwrites_string =
         load_string_from_env("OVERWRITE_DATASOURCES")?.unwrap_or_else(|| r#"[]"#.to_string());
     let datasource_overwrites: Vec<SourceOverride> = serde_json::from_str(&datasource_overwrites_string)?;
 
-    match datamodel::parse_configuration(&dml_string) {
-        Err(errors) => Err(PrismaError::ConversionError(errors, dml_string.to_string())),
+    match datamodel::parse_configuration_files(files) {
+        Err(errors) => Err(PrismaError::ConversionError(errors, files.clone())),
         Ok(mut configuration) => {
             for datasource_override in datasource_overwrites {
                 for datasource in &mut configuration.datasources {
@@ -134,10 +138,12 @@ struct SourceOverride {
     url: String,
 }
 
-/// Attempts to load a Prisma DML (datamodel v2) string from either env or file.
+/// Attempts to load the Prisma DML (datamodel v2) files from either env or file.
 /// Env has precedence over file.
-fn load_v2_dml_string() -> PrismaResult<Option<String>> {
-    load_v2_string_from_env().inner_or_else(load_v2_dml_from_file)
+fn load_v2_dml_files() -> PrismaResult<Option<SourceMap>> {
+    load_v2_string_from_env()
+        .inner_map(|dml_string| Ok(Some(single_file(&dml_string))))
+        .inner_or_else(load_v2_dml_from_file)
 }
 
 /// Attempts to load a Prisma DML (datamodel v2) string from env.
@@ -146,8 +152,8 @@ fn load_v2_string_from_env() -> PrismaResult<Option<String>> {
     load_string_from_env("PRISMA_DML").on_success(|| debug!("Loaded Prisma v2 DML from env."))
 }
 
-/// Attempts to load a Prisma DML (datamodel v2) string from file.
-fn load_v2_dml_from_file() -> PrismaResult<Option<String>> {
+/// Attempts to load the Prisma DML (datamodel v2) files from a file or a directory.
+fn load_v2_dml_from_file() -> PrismaResult<Option<SourceMap>> {
     debug!("Trying to load Prisma v2 Datamodel from file...");
     load_from_file("PRISMA_DML_PATH").on_success(|| debug!("Loaded Prisma v2 DML from file."))
 }
@@ -180,22 +186,25 @@ fn load_string_from_env(env_var: &str) -> PrismaResult<Option<String>> {
     }
 }
 
-/// Attempts to load a string from a file pointed to by given env var.
-/// Returns: File contents as string.
-///     Err      If the env var was found, but loading the file failed.
-///     Ok(Some) If the env var was found and the file was successfully read.
+/// Attempts to load the datamodel files pointed to by given env var.
+/// The env var can point to a single file, or to a directory of `.prisma` files.
+/// Returns: The loaded files.
+///     Err      If the env var was found, but loading the files failed.
+///     Ok(Some) If the env var was found and the files were successfully read.
 ///     Ok(None) If the env var was not found.
-fn load_from_file(env_var: &str) -> PrismaResult<Option<String>> {
+fn load_from_file(env_var: &str) -> PrismaResult<Option<SourceMap>> {
     match utilities::get_env(env_var).ok() {
         Some(path) => {
-            let mut f = File::open(&path)?;
-            let mut sdl = String::new();
-
-            f.read_to_string(&mut sdl)?;
+            let files = SourceMap::load(Path::new(&path))?;
             trace!("Successfully loaded contents of {}", path);
 
-            Ok(Some(sdl))
+            Ok(Some(files))
         }
         None => Ok(None),
     }
 }
+
+/// Wraps a datamodel that was not loaded from a file.
+pub fn single_file(dml_string: &str) -> SourceMap {
+    SourceMap::new(vec![SourceFile::new(SCHEMA_FILE_NAME, dml_string)])
+}
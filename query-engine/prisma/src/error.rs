@@ -1,4 +1,4 @@
-use datamodel::error::ErrorCollection;
+use datamodel::{ast::SourceMap, error::ErrorCollection};
 use failure::{Error, Fail};
 use graphql_parser::query::ParseError as GqlParseError;
 use query_core::{response_ir, CoreError};
@@ -22,7 +22,7 @@ pub enum PrismaError {
     ConfigurationError(String),
 
     #[fail(display = "{}", _0)]
-    ConversionError(ErrorCollection, String),
+    ConversionError(ErrorCollection, SourceMap),
 
     #[fail(display = "{}", _0)]
     IOError(Error),
@@ -75,13 +75,11 @@ pub trait PrettyPrint {
 impl PrettyPrint for PrismaError {
     fn pretty_print(&self) {
         match self {
-            PrismaError::ConversionError(errors, dml_string) => {
-                let file_name = "schema.prisma".to_string();
-
+            PrismaError::ConversionError(errors, files) => {
                 for error in errors.to_iter() {
                     println!();
                     error
-                        .pretty_print(&mut std::io::stderr().lock(), &file_name, &dml_string)
+                        .pretty_print_in_files(&mut std::io::stderr().lock(), files)
                         .expect("Failed to write errors to stderr");
                 }
             }
@@ -92,6 +92,20 @@ async fn main() -> Result<(), AnyError> {
                         .help("Get the configuration from the given data model")
                         .takes_value(true)
                         .required(false),
+                )
+                .arg(
+                    Arg::with_name("json_schema")
+                        .long("json_schema")
+                        .help("Output a JSON Schema of the loaded data model, with create and update shapes per model.")
+                        .takes_value(false)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("graphql_sdl")
+                        .long("graphql_sdl")
+                        .help("Output the loaded data model as GraphQL SDL.")
+                        .takes_value(false)
+                        .required(false),
                 ),
         )
         .get_matches();